@@ -0,0 +1,374 @@
+use std::{
+    cell::RefCell,       // Interior mutability
+    io::{stdout, Write}, // Terminal output
+    time::Duration,
+};
+#[cfg(test)]
+use std::{
+    collections::VecDeque, // Scripted input queue
+    rc::Rc,                // Shared access to the headless grid
+};
+
+use crossterm::{
+    cursor,
+    event::{
+        DisableMouseCapture, EnableMouseCapture, Event, EventStream, KeyCode, KeyEvent,
+        KeyModifiers, MouseButton, MouseEventKind,
+    },
+    execute, queue,
+    terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::{executor::block_on, future::FutureExt, StreamExt};
+use futures_timer::Delay;
+
+use crate::event_loop::EventData;
+use crate::tui_engine::{Glyph, Style};
+
+// -----------------------------
+// Input
+// -----------------------------
+
+/// Input delivered by a backend, already mapped to EventData
+#[derive(Clone, Debug)]
+pub enum Input {
+    Key(EventData),   // key pressed
+    Move(EventData),  // mouse moved
+    Click(EventData), // left button released or dragged
+    Eof,              // no more input, the loop should exit
+}
+
+#[cfg(test)] // Used to script the headless backend
+impl Input {
+    /// Key press without modifiers, e.g. "a", "enter", "delete"
+    pub fn key(key: &str) -> Self {
+        Input::Key(EventData {
+            key: Some(key.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Left click at (x, y)
+    pub fn click(x: u16, y: u16) -> Self {
+        Input::Click(EventData {
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+}
+
+// -----------------------------
+// Backend
+// -----------------------------
+
+/// Everything the engine needs from a terminal
+pub trait Backend {
+    /// Prepare for drawing and input
+    fn setup(&mut self);
+    /// Restore whatever setup changed
+    fn teardown(&mut self);
+    /// Current size as (columns, rows)
+    fn size(&self) -> (u16, u16);
+    /// Forget what is on screen, called after a resize
+    fn clear(&mut self);
+    /// Write changed cells, each at (x, y)
+    fn draw(&mut self, changes: &[(u16, u16, Glyph)]);
+    /// Wait up to `timeout` for input, None when the wait timed out
+    fn poll(&mut self, timeout: Duration) -> Option<Input>;
+    /// Put text on the clipboard
+    fn copy(&mut self, text: &str);
+}
+
+thread_local! {
+    static BACKEND: RefCell<Box<dyn Backend>> = RefCell::new(Box::new(CrosstermBackend::new()));
+}
+
+/// Replaces the backend used by the engine (crossterm by default)
+#[cfg(test)] // Used to run the UI headless
+pub fn install<B: Backend + 'static>(backend: B) {
+    BACKEND.with(|b| *b.borrow_mut() = Box::new(backend));
+}
+
+/// Runs `f` with the installed backend
+pub fn with<R>(f: impl FnOnce(&mut dyn Backend) -> R) -> R {
+    BACKEND.with(|b| f(b.borrow_mut().as_mut()))
+}
+
+// -----------------------------
+// Crossterm Backend
+// -----------------------------
+
+/// Converts KeyModifiers into human-friendly string representation
+fn modifiers_map(modifiers: KeyModifiers) -> Vec<String> {
+    let mut result = Vec::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        result.push("ctrl".to_string());
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        result.push("shift".to_string());
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        result.push("alt".to_string());
+    }
+    if modifiers.contains(KeyModifiers::META) {
+        result.push("meta".to_string());
+    }
+    result
+}
+
+/// Converts raw crossterm KeyEvent into human-friendly string
+fn key_map(key: &KeyEvent) -> Option<String> {
+    use KeyCode::*;
+    match key.code {
+        Enter => Some("enter".into()),
+        Up => Some("up".into()),
+        Down => Some("down".into()),
+        Left => Some("left".into()),
+        Right => Some("right".into()),
+        Esc => Some("escape".into()),
+        Backspace => Some("delete".into()),
+        Tab => Some("tab".into()),
+        F(n @ 1..=12) => Some(format!("f{}", n)), // function keys
+        Char(c) => Some(c.to_string()),           // any typed char
+        _ => None,
+    }
+}
+
+/// Real terminal on stdout
+pub struct CrosstermBackend {
+    events: Option<EventStream>, // created on setup
+}
+
+impl CrosstermBackend {
+    pub fn new() -> Self {
+        Self { events: None }
+    }
+}
+
+impl Default for CrosstermBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Backend for CrosstermBackend {
+    /// Configure terminal for raw mode + mouse tracking
+    fn setup(&mut self) {
+        enable_raw_mode().expect("Failed to enable raw mode");
+        let mut stdout = stdout();
+
+        execute!(
+            stdout,
+            cursor::Hide,         // hide cursor
+            cursor::MoveTo(0, 0), // reset cursor
+            EnableMouseCapture    // enable mouse events
+        )
+        .expect("Failed to initialize terminal");
+
+        self.events = Some(EventStream::new());
+    }
+
+    /// Restore terminal to normal state
+    fn teardown(&mut self) {
+        let mut stdout = stdout();
+
+        execute!(
+            stdout,
+            crossterm::style::ResetColor, // reset text color
+            cursor::Show,                 // show cursor
+            Clear(ClearType::All),        // clear screen
+            cursor::MoveTo(0, 0),         // move cursor to top-left
+            DisableMouseCapture           // disable mouse events
+        )
+        .expect("Failed to reset terminal");
+
+        disable_raw_mode().ok();
+        self.events = None;
+    }
+
+    fn size(&self) -> (u16, u16) {
+        // an unknown size reads as too small to draw anything
+        size().unwrap_or((0, 0))
+    }
+
+    fn clear(&mut self) {
+        execute!(stdout(), Clear(ClearType::All)).ok();
+    }
+
+    fn draw(&mut self, changes: &[(u16, u16, Glyph)]) {
+        let mut out = stdout(); // Do not lock stdout. Needed for event capture.
+        let mut style = Style::default();
+        let mut next: Option<(u16, u16)> = None; // where the terminal cursor is
+
+        write!(out, "\x1b[0m").unwrap();
+
+        // styles become escape sequences here and nowhere else
+        for (x, y, glyph) in changes {
+            if next != Some((*x, *y)) {
+                queue!(out, cursor::MoveTo(*x, *y)).unwrap();
+            }
+            if glyph.style != style {
+                write!(out, "\x1b[0m{}", glyph.style.sgr()).unwrap();
+                style = glyph.style;
+            }
+            write!(out, "{}", glyph.ch).unwrap();
+            next = Some((x + 1, *y));
+        }
+
+        write!(out, "\x1b[0m").unwrap();
+        out.flush().unwrap();
+    }
+
+    fn poll(&mut self, timeout: Duration) -> Option<Input> {
+        let events = self.events.as_mut()?;
+
+        let maybe_event: Option<Result<Event, std::io::Error>> = block_on(async {
+            futures::select! {
+                event = events.next().fuse() => event,     // terminal event
+                _ = Delay::new(timeout).fuse() => None,   // timeout → just return None
+            }
+        });
+
+        match maybe_event? {
+            Ok(Event::Key(key_event)) => key_map(&key_event).map(|k| {
+                Input::Key(EventData {
+                    key: Some(k),
+                    modifiers: modifiers_map(key_event.modifiers),
+                    ..Default::default()
+                })
+            }),
+            Ok(Event::Mouse(mouse_event)) => {
+                let data = EventData {
+                    x: Some(mouse_event.column),
+                    y: Some(mouse_event.row),
+                    modifiers: modifiers_map(mouse_event.modifiers),
+                    ..Default::default()
+                };
+                match mouse_event.kind {
+                    // Trigger on_move ONLY when the mouse actually moved
+                    MouseEventKind::Moved => Some(Input::Move(data)),
+                    // Trigger on_click for left-up or drag
+                    MouseEventKind::Up(MouseButton::Left)
+                    | MouseEventKind::Drag(MouseButton::Left) => Some(Input::Click(data)),
+                    _ => None,
+                }
+            }
+            Ok(_) => None, // Ignore other events
+            // Treat IO errors from EventStream as EOF → exit gracefully
+            Err(_) => Some(Input::Eof),
+        }
+    }
+
+    /// OSC 52: the terminal sets its clipboard, works over SSH too
+    fn copy(&mut self, text: &str) {
+        let mut out = stdout();
+        write!(out, "\x1b]52;c;{}\x07", STANDARD.encode(text)).ok();
+        out.flush().ok();
+    }
+}
+
+// -----------------------------
+// Headless Backend
+// -----------------------------
+
+/// In-memory terminal: records drawn cells and replays scripted input.
+/// Once the script runs out the loop receives Eof and exits.
+#[cfg(test)] // Used to run the UI without a TTY
+pub struct HeadlessBackend {
+    width: u16,
+    height: u16,
+    grid: Rc<RefCell<Vec<Vec<Glyph>>>>,
+    clipboard: Rc<RefCell<String>>,
+    script: VecDeque<Option<Input>>, // None is a tick without input
+}
+
+#[cfg(test)]
+impl HeadlessBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            grid: Rc::new(RefCell::new(vec![
+                vec![Glyph::blank(); width as usize];
+                height as usize
+            ])),
+            clipboard: Rc::new(RefCell::new(String::new())),
+            script: VecDeque::new(),
+        }
+    }
+
+    /// Shared handle to the cell grid, readable after the backend is installed
+    pub fn grid(&self) -> Rc<RefCell<Vec<Vec<Glyph>>>> {
+        self.grid.clone()
+    }
+
+    /// Shared handle to the last copied text
+    pub fn clipboard(&self) -> Rc<RefCell<String>> {
+        self.clipboard.clone()
+    }
+
+    /// Queues input, None entries are ticks without input
+    pub fn script(&mut self, inputs: impl IntoIterator<Item = Option<Input>>) {
+        self.script.extend(inputs);
+    }
+
+    /// Queues one key press per character of `text`
+    pub fn type_text(&mut self, text: &str) {
+        self.script
+            .extend(text.chars().map(|c| Some(Input::key(&c.to_string()))));
+    }
+
+    /// Grid rows as plain text, styles dropped
+    pub fn text(grid: &[Vec<Glyph>]) -> Vec<String> {
+        grid.iter()
+            .map(|row| row.iter().map(|g| g.ch).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+impl Backend for HeadlessBackend {
+    fn setup(&mut self) {}
+
+    fn teardown(&mut self) {}
+
+    fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    fn clear(&mut self) {
+        for row in self.grid.borrow_mut().iter_mut() {
+            row.fill(Glyph::blank());
+        }
+    }
+
+    fn draw(&mut self, changes: &[(u16, u16, Glyph)]) {
+        let mut grid = self.grid.borrow_mut();
+        for (x, y, glyph) in changes {
+            if let Some(cell) = grid
+                .get_mut(*y as usize)
+                .and_then(|row| row.get_mut(*x as usize))
+            {
+                *cell = *glyph;
+            }
+        }
+    }
+
+    fn poll(&mut self, timeout: Duration) -> Option<Input> {
+        match self.script.pop_front() {
+            Some(Some(input)) => Some(input),
+            // a tick waits like the real loop so animations can play out
+            Some(None) => {
+                std::thread::sleep(timeout);
+                None
+            }
+            None => Some(Input::Eof),
+        }
+    }
+
+    fn copy(&mut self, text: &str) {
+        self.clipboard.replace(text.to_string());
+    }
+}
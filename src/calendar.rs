@@ -0,0 +1,141 @@
+// Dates of the words, Wordle published one a day starting with CIGAR
+
+use crate::history::now;
+
+static EPOCH: (i64, u32, u32) = (2021, 6, 19); // date of the first word
+
+// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12; // March is 0
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// The date `days` after 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn epoch_days() -> i64 {
+    days_from_civil(EPOCH.0, EPOCH.1, EPOCH.2)
+}
+
+// Publication date of the word at `index`, as YYYY-MM-DD
+pub fn date_of(index: usize) -> String {
+    let (year, month, day) = civil_from_days(epoch_days() + index as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// Index of the word published on a YYYY-MM-DD date,
+// None for malformed dates and dates before the first word
+pub fn index_of(date: &str) -> Option<usize> {
+    let mut parts = date.split('-').map(|p| p.parse::<u32>().ok());
+    let (Some(Some(year)), Some(Some(month)), Some(Some(day)), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+
+    // a date that does not survive the round trip does not exist, e.g. 2021-02-30
+    let days = days_from_civil(year as i64, month, day);
+    if civil_from_days(days) != (year as i64, month, day) {
+        return None;
+    }
+    usize::try_from(days - epoch_days()).ok()
+}
+
+// Days since the first word, by the UTC date
+pub fn today() -> usize {
+    let days = (now() / 86400) as i64;
+    usize::try_from(days - epoch_days()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::PLAY_WORDS;
+
+    #[test]
+    fn days_round_trip_to_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        for days in days_from_civil(1900, 1, 1)..days_from_civil(2500, 1, 1) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn february_29_is_only_in_leap_years() {
+        for year in [2024, 2028, 2400] {
+            assert!(index_of(&format!("{}-02-29", year)).is_some(), "{}", year);
+        }
+        for year in [2022, 2023, 2100, 2200] {
+            assert_eq!(index_of(&format!("{}-02-29", year)), None, "{}", year);
+        }
+        assert_eq!(date_of(index_of("2024-02-28").unwrap() + 1), "2024-02-29");
+        // before the first word
+        assert_eq!(
+            civil_from_days(days_from_civil(2000, 2, 28) + 1),
+            (2000, 2, 29)
+        );
+        assert_eq!(
+            civil_from_days(days_from_civil(1900, 2, 28) + 1),
+            (1900, 3, 1)
+        );
+        assert_eq!(date_of(index_of("2023-02-28").unwrap() + 1), "2023-03-01");
+    }
+
+    #[test]
+    fn words_follow_the_days_across_months_and_years() {
+        assert_eq!(date_of(0), "2021-06-19");
+        assert_eq!(index_of("2021-06-19"), Some(0));
+        assert_eq!(index_of("2021-06-30"), Some(11));
+        assert_eq!(index_of("2021-07-01"), Some(12));
+        assert_eq!(index_of("2021-12-31"), Some(195));
+        assert_eq!(index_of("2022-01-01"), Some(196));
+        assert_eq!(date_of(196), "2022-01-01");
+        assert_eq!(PLAY_WORDS[196], "REBUS");
+        for index in 0..PLAY_WORDS.len() {
+            assert_eq!(index_of(&date_of(index)), Some(index));
+        }
+    }
+
+    #[test]
+    fn impossible_dates_have_no_word() {
+        for date in [
+            "2021-06-18",
+            "2021-13-01",
+            "2021-04-31",
+            "2021-06-00",
+            "2021-06",
+            "2021-06-19-1",
+            "today",
+        ] {
+            assert_eq!(index_of(date), None, "{}", date);
+        }
+    }
+
+    #[test]
+    fn today_is_the_word_of_the_utc_date() {
+        let index = today();
+        let (year, month, day) = civil_from_days((now() / 86400) as i64);
+        let date = format!("{:04}-{:02}-{:02}", year, month, day);
+        assert_eq!(date_of(index), date);
+        assert_eq!(index_of(&date), Some(index));
+    }
+}
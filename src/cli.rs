@@ -0,0 +1,490 @@
+use std::fs::remove_file;
+use std::io::{stdin, stdout, ErrorKind, Write};
+use std::path::PathBuf;
+
+use crate::game::{boards_name, ATTEMPTS, BOARDS, MAX_ATTEMPTS, MIN_ATTEMPTS};
+use crate::history::history_path;
+use crate::language::language;
+use crate::solver::{code, rank, remaining};
+use crate::storage::{save, save_path, SaveData};
+use crate::words::{is_valid, LETTERS, MAX_LETTERS, MIN_LETTERS, PLAY_WORDS};
+
+pub static HELP: &str = "\
+Rewordle lets you play all the Wordle words from the beginning in the terminal.
+
+Usage: rewordle [OPTIONS]
+       rewordle solve [--letters <N>] [--answers <PATH>] [--dictionary <PATH>]
+
+Commands:
+  solve               Suggest guesses for a game played elsewhere, from the
+                      colors each guess got
+
+Options:
+  --word <N>          Continue the progression from Wordle #N
+  --practice          Play random words, kept out of results and streak
+  --seed <N>          Practice the words of seed N, the same for every player
+  --daily             Play the word of today's date (UTC), once a day
+  --absurdle          Play against a game that keeps changing its answer
+  --letters <N>       Play words of N letters, 4 to 8 (default 5)
+  --attempts <N>      Allow N guesses per word, 6 to 10 (default 6)
+  --boards <N>        Solve 2, 4 or 8 words at once with the same guesses,
+                      1 goes back to a single board
+  --answers <PATH>    Play the words of PATH, one per line, results kept apart
+  --dictionary <PATH> Accept only the words of PATH as guesses besides them,
+                      it must have every answer
+  --stats             Print results and streak, then exit
+  --share             Print the share grid of the last finished game
+  --reset             Clear results, streak, progress and history (asks first)
+  --save-file <PATH>  Read and write the save file at PATH
+  --version           Print version
+  --help              Print this help
+
+The save file defaults to rewordle/save.json in the platform data
+directory ($XDG_DATA_HOME on Linux), REWORDLE_SAVE_FILE overrides it.
+";
+
+// What the binary does once options are read
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Play,
+    Stats,
+    Share,
+    Reset,
+    Solve,
+    Version,
+    Help,
+}
+
+#[derive(Debug)]
+pub struct Cli {
+    pub action: Action,
+    pub word: Option<usize>, // index into PLAY_WORDS
+    pub practice: bool,
+    pub seed: Option<u64>, // implies practice
+    pub daily: bool,
+    pub absurdle: bool,
+    pub letters: Option<usize>,
+    pub attempts: Option<usize>,
+    pub boards: Option<usize>,
+    pub answers: Option<PathBuf>,
+    pub dictionary: Option<PathBuf>,
+    pub save_file: Option<PathBuf>,
+}
+
+// Accepts both `--flag value` and `--flag=value`
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Cli, String> {
+    let mut cli = Cli {
+        action: Action::Play,
+        word: None,
+        practice: false,
+        seed: None,
+        daily: false,
+        absurdle: false,
+        letters: None,
+        attempts: None,
+        boards: None,
+        answers: None,
+        dictionary: None,
+        save_file: None,
+    };
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let mut value = |name: &str| {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or(format!("{} needs a value", name))
+        };
+
+        match flag.as_str() {
+            "--word" => {
+                let n: usize = value("--word")?
+                    .parse()
+                    .map_err(|_| "--word needs a number".to_string())?;
+                if n == 0 || n > PLAY_WORDS.len() {
+                    return Err(format!("--word must be from 1 to {}", PLAY_WORDS.len()));
+                }
+                cli.word = Some(n - 1);
+            }
+            "--practice" => cli.practice = true,
+            "--daily" => cli.daily = true,
+            "--absurdle" => cli.absurdle = true,
+            "--seed" => {
+                let seed = value("--seed")?
+                    .parse()
+                    .map_err(|_| "--seed needs a number".to_string())?;
+                cli.seed = Some(seed);
+                cli.practice = true;
+            }
+            "--letters" => {
+                let n: usize = value("--letters")?
+                    .parse()
+                    .map_err(|_| "--letters needs a number".to_string())?;
+                if !(MIN_LETTERS..=MAX_LETTERS).contains(&n) {
+                    return Err(format!(
+                        "--letters must be from {} to {}",
+                        MIN_LETTERS, MAX_LETTERS
+                    ));
+                }
+                cli.letters = Some(n);
+            }
+            "--attempts" => {
+                let n: usize = value("--attempts")?
+                    .parse()
+                    .map_err(|_| "--attempts needs a number".to_string())?;
+                if !(MIN_ATTEMPTS..=MAX_ATTEMPTS).contains(&n) {
+                    return Err(format!(
+                        "--attempts must be from {} to {}",
+                        MIN_ATTEMPTS, MAX_ATTEMPTS
+                    ));
+                }
+                cli.attempts = Some(n);
+            }
+            "--boards" => {
+                let n: usize = value("--boards")?
+                    .parse()
+                    .map_err(|_| "--boards needs a number".to_string())?;
+                if !BOARDS.contains(&n) {
+                    return Err("--boards must be 1, 2, 4 or 8".to_string());
+                }
+                cli.boards = Some(n);
+            }
+            "--answers" => cli.answers = Some(PathBuf::from(value("--answers")?)),
+            "--dictionary" => cli.dictionary = Some(PathBuf::from(value("--dictionary")?)),
+            "--save-file" => cli.save_file = Some(PathBuf::from(value("--save-file")?)),
+            "--stats" => cli.action = Action::Stats,
+            "--share" => cli.action = Action::Share,
+            "--reset" => cli.action = Action::Reset,
+            "--version" | "-V" => cli.action = Action::Version,
+            "--help" | "-h" => cli.action = Action::Help,
+            "solve" => cli.action = Action::Solve,
+            _ => return Err(format!("unknown option {}", flag)),
+        }
+    }
+
+    if [cli.word.is_some(), cli.practice, cli.daily, cli.absurdle]
+        .iter()
+        .filter(|&&set| set)
+        .count()
+        > 1
+    {
+        return Err(
+            "only one of --word, --practice, --daily and --absurdle can be used".to_string(),
+        );
+    }
+    // the archive and today's word only exist in five letters, on one board
+    let to_archive = cli.word.is_some() || cli.daily;
+    if to_archive && cli.letters.is_some_and(|n| n != LETTERS) {
+        return Err("--word and --daily play five letter words".to_string());
+    }
+    if (to_archive || cli.absurdle) && cli.boards.is_some_and(|n| n > 1) {
+        return Err("--word, --daily and --absurdle play a single board".to_string());
+    }
+    // games of several boards have their own number of guesses
+    if cli.attempts.is_some() && cli.boards.is_some_and(|n| n > 1) {
+        return Err("--attempts can not be used with --boards".to_string());
+    }
+    if cli.dictionary.is_some() && cli.answers.is_none() {
+        return Err("--dictionary needs --answers".to_string());
+    }
+    // the solver only needs the words and their length
+    if cli.action == Action::Solve
+        && (cli.word.is_some()
+            || cli.practice
+            || cli.daily
+            || cli.absurdle
+            || cli.attempts.is_some()
+            || cli.boards.is_some())
+    {
+        return Err("solve only takes --letters, --answers and --dictionary".to_string());
+    }
+    Ok(cli)
+}
+
+// Results and streak as plain text, same figures as the in-game panel
+pub fn stats_text(saved: &SaveData) -> String {
+    let r = &saved.results;
+    let attempts = r.len() - 1;
+    let p: u32 = r.iter().sum();
+    let win_percent = if p > 0 {
+        (((p - r[attempts]) as f32 / p as f32) * 100.0).round() as u32
+    } else {
+        0
+    };
+
+    // stats are kept per configuration and word list, name the one that is
+    // not plain Wordle
+    let mut names = Vec::new();
+    if !saved.word_list.is_empty() {
+        names.push(format!("{} words", language().word_list()));
+    }
+    if saved.boards > 1 {
+        names.push(format!(
+            "{}, {} letters",
+            boards_name(saved.boards),
+            saved.letters
+        ));
+    } else if (saved.letters, saved.attempts) != (LETTERS, ATTEMPTS) {
+        names.push(format!(
+            "{} letters, {} attempts",
+            saved.letters, saved.attempts
+        ));
+    }
+    let mut text = String::new();
+    if !names.is_empty() {
+        text.push_str(&format!("{}\n\n", names.join(", ")));
+    }
+    text.push_str(&format!(
+        "Played: {}\nWin %: {}\nStreak: {}\nMax Streak: {}\nHard Wins: {}\n\n",
+        p, win_percent, saved.streak.0, saved.streak.1, saved.hard_wins
+    ));
+
+    // ignore losses (the last entry)
+    let max = r[..attempts].iter().cloned().max().unwrap_or(0);
+    for (index, &value) in r[..attempts].iter().enumerate() {
+        let len = if max > 0 {
+            ((value as f32 / max as f32) * 10.0).round() as usize
+        } else {
+            0
+        };
+        text.push_str(&format!(
+            "{:>width$} {} {}\n",
+            index + 1,
+            "█".repeat(len),
+            value,
+            width = attempts.to_string().len()
+        ));
+    }
+
+    text
+}
+
+// Asks on the terminal, anything but yes keeps the save and the history
+pub fn reset() -> Result<(), String> {
+    print!(
+        "Clear results, streak and progress in {} and the history in {}? [y/N] ",
+        save_path().display(),
+        history_path().display()
+    );
+    stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut answer = String::new();
+    stdin().read_line(&mut answer).map_err(|e| e.to_string())?;
+
+    if matches!(answer.trim(), "y" | "Y" | "yes") {
+        save(&SaveData::default()).map_err(|e| e.to_string())?;
+        match remove_file(history_path()) {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                return Err(format!(
+                    "{} could not be removed: {}",
+                    history_path().display(),
+                    e
+                ));
+            }
+            _ => println!("Cleared."),
+        }
+    } else {
+        println!("Nothing changed.");
+    }
+    Ok(())
+}
+
+// "g" or "2" green, "y" or "1" yellow, "b", "x", "0", "." or "-" grey
+fn parse_pattern(text: &str, letters: usize) -> Result<Vec<u8>, String> {
+    let pattern = text
+        .chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            'g' | '2' => Ok(2),
+            'y' | '1' => Ok(1),
+            'b' | 'x' | '0' | '.' | '-' => Ok(0),
+            _ => Err(format!("{} is not a color, use g, y or .", c)),
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    if pattern.len() != letters {
+        return Err(format!("the colors need {} letters", letters));
+    }
+    Ok(pattern)
+}
+
+// Suggests guesses from the colors typed back, until one word is left
+pub fn solve(letters: usize) -> Result<(), String> {
+    println!(
+        "Type a guess and its colors (CRANE gy..g), or only the colors to play the suggestion.\n\
+         g green, y yellow, . grey. An empty line or Ctrl-D quits."
+    );
+
+    let mut history: Vec<(String, usize)> = Vec::new();
+    loop {
+        let candidates = remaining(letters, &history);
+        match candidates.len() {
+            0 => {
+                println!("No word fits those colors.");
+                return Ok(());
+            }
+            1 => {
+                println!("The word is {}.", candidates[0]);
+                return Ok(());
+            }
+            n => println!("\n{} possible answers", n),
+        }
+        let ranked = rank(letters, &candidates, false, 5);
+        for r in &ranked {
+            println!("  {} {:.2} bits", r.word, r.bits);
+        }
+
+        let input = loop {
+            print!("> ");
+            stdout().flush().map_err(|e| e.to_string())?;
+            let mut line = String::new();
+            if stdin().read_line(&mut line).map_err(|e| e.to_string())? == 0 {
+                return Ok(());
+            }
+            let fields: Vec<String> = line.split_whitespace().map(str::to_uppercase).collect();
+            let (guess, colors) = match fields.as_slice() {
+                [] => return Ok(()),
+                [colors] => (ranked[0].word.to_string(), colors),
+                [guess, colors] => (guess.clone(), colors),
+                _ => {
+                    println!("Type a guess and its colors, or only the colors");
+                    continue;
+                }
+            };
+            if guess.chars().count() != letters || !is_valid(&guess) {
+                println!("{} is not in the word list", guess);
+                continue;
+            }
+            match parse_pattern(colors, letters) {
+                Ok(pattern) => break (guess, pattern),
+                Err(e) => println!("{}", e),
+            }
+        };
+
+        let (guess, pattern) = input;
+        if pattern.iter().all(|&s| s == 2) {
+            println!("Solved in {}.", history.len() + 1);
+            return Ok(());
+        }
+        history.push((guess, code(&pattern)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Cli, String> {
+        parse(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn no_option_plays() {
+        let cli = args("").unwrap();
+        assert_eq!(cli.action, Action::Play);
+        assert_eq!((cli.word, cli.letters, cli.boards), (None, None, None));
+        assert!(!cli.practice && !cli.daily && !cli.absurdle);
+    }
+
+    #[test]
+    fn values_follow_a_space_or_an_equal_sign() {
+        let cli = args("--word 5 --letters=5 --attempts 8 --save-file=/tmp/s.json").unwrap();
+        assert_eq!(cli.word, Some(4));
+        assert_eq!(cli.letters, Some(5));
+        assert_eq!(cli.attempts, Some(8));
+        assert_eq!(cli.save_file, Some(PathBuf::from("/tmp/s.json")));
+
+        let cli = args("--seed=42").unwrap();
+        assert_eq!(cli.seed, Some(42));
+        assert!(cli.practice);
+
+        assert_eq!(args("--stats").unwrap().action, Action::Stats);
+        assert_eq!(args("--share").unwrap().action, Action::Share);
+        assert_eq!(args("--reset").unwrap().action, Action::Reset);
+        assert_eq!(args("-V").unwrap().action, Action::Version);
+        assert_eq!(args("-h").unwrap().action, Action::Help);
+        assert_eq!(args("solve --letters 6").unwrap().action, Action::Solve);
+    }
+
+    #[test]
+    fn values_out_of_range_are_refused() {
+        for line in [
+            "--word",
+            "--word 0",
+            "--word x",
+            &format!("--word {}", PLAY_WORDS.len() + 1),
+            "--letters 3",
+            "--letters 9",
+            "--attempts 5",
+            "--attempts 11",
+            "--boards 3",
+            "--seed -1",
+            "--hard",
+            "play",
+        ] {
+            assert!(args(line).is_err(), "{} is accepted", line);
+        }
+        assert!(args(&format!("--word {}", PLAY_WORDS.len())).is_ok());
+    }
+
+    #[test]
+    fn one_game_mode_at_a_time() {
+        for line in [
+            "--word 3 --practice",
+            "--word 3 --daily",
+            "--practice --absurdle",
+            "--daily --absurdle",
+            "--seed 7 --daily",
+        ] {
+            assert!(args(line).is_err(), "{} is accepted", line);
+        }
+        for line in [
+            "--word 3",
+            "--practice",
+            "--daily",
+            "--absurdle",
+            "--seed 7",
+        ] {
+            assert!(args(line).is_ok(), "{} is refused", line);
+        }
+    }
+
+    #[test]
+    fn board_sizes_fit_the_mode() {
+        // the archive and today's word have five letters and one board
+        assert!(args("--word 3 --letters 6").is_err());
+        assert!(args("--daily --letters 4").is_err());
+        assert!(args("--word 3 --letters 5").is_ok());
+        assert!(args("--word 3 --boards 2").is_err());
+        assert!(args("--daily --boards 4").is_err());
+        assert!(args("--absurdle --boards 8").is_err());
+        assert!(args("--absurdle --boards 1 --letters 6").is_ok());
+        assert!(args("--practice --boards 4 --letters 6").is_ok());
+
+        // several boards have their own number of guesses
+        assert!(args("--boards 2 --attempts 8").is_err());
+        assert!(args("--boards 1 --attempts 8").is_ok());
+    }
+
+    #[test]
+    fn word_lists_and_solve_take_their_options() {
+        assert!(args("--dictionary words.txt").is_err());
+        assert!(args("--answers a.txt --dictionary words.txt").is_ok());
+
+        assert!(args("solve --letters 6 --answers a.txt --dictionary d.txt").is_ok());
+        for option in [
+            "--word 3",
+            "--practice",
+            "--daily",
+            "--absurdle",
+            "--attempts 8",
+            "--boards 2",
+        ] {
+            let line = format!("solve {}", option);
+            assert!(args(&line).is_err(), "{} is accepted", line);
+        }
+    }
+}
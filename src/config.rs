@@ -0,0 +1,486 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::keymap::{chord, Action, Keys, ACTIONS, MODIFIERS, NAMED_KEYS};
+use crate::layout::{Layout, LAYOUTS};
+use crate::theme::{self, Theme, COLOR_NAMES, THEMES};
+use crate::tui_engine::{Color, Style};
+use crate::Mode;
+
+static CONFIG_ENV: &str = "REWORDLE_CONFIG_FILE"; // overrides the config location
+
+// Names the config file knows, anything else is reported
+static SETTINGS: &[&str] = &[
+    "theme",
+    "layout",
+    "language",
+    "answers",
+    "dictionary",
+    "mode",
+    "tick_rate",
+    "animation",
+    "keys",
+    "themes",
+];
+static ANIMATION_SETTINGS: &[&str] = &["flip", "shake"];
+static THEME_SETTINGS: &[&str] = &["exact", "exists", "absent"];
+static STYLE_SETTINGS: &[&str] = &["fg", "bg", "bold", "underline"];
+
+static MAX_MILLIS: u64 = 5000; // slowest tick or animation step accepted
+
+// -----------------------------
+// Location
+// -----------------------------
+
+// The environment override, or rewordle/config.toml in the platform
+// config directory ($XDG_CONFIG_HOME on Linux)
+pub fn config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_ENV).filter(|p| !p.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    dirs::config_dir().map(|dir| dir.join("rewordle").join("config.toml"))
+}
+
+// -----------------------------
+// Config
+// -----------------------------
+
+// How long each step of an animation shows
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Timings {
+    pub flip: Duration,  // each half of a letter flipping over to its color
+    pub shake: Duration, // each step of the shake of a word not in the list
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Self {
+            flip: Duration::from_millis(300),
+            shake: Duration::from_millis(50),
+        }
+    }
+}
+
+// Settings read at startup, anything left out keeps its default
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub theme: Theme,                // how letter statuses are drawn
+    pub themes: Vec<Theme>,          // built-in and config themes, in the order of the theme key
+    pub layout: Layout,              // letters of the on-screen keyboard
+    pub language: String,            // "english" or a language pack, by name or path
+    pub answers: Option<PathBuf>,    // file of words to play in place of the language's
+    pub dictionary: Option<PathBuf>, // file of the words accepted with them
+    pub mode: Option<Mode>,          // mode to start in when the command line picks none
+    pub tick_rate: Duration,         // time between loop ticks
+    pub timings: Timings,            // animation speed
+    pub keys: Keys,                  // key of each action
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            themes: THEMES.to_vec(),
+            layout: Layout::default(),
+            language: "english".to_string(),
+            answers: None,
+            dictionary: None,
+            mode: None,
+            tick_rate: Duration::from_millis(33),
+            timings: Timings::default(),
+            keys: Keys::default(),
+        }
+    }
+}
+
+// The file as written, values still to be checked
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    theme: Option<String>,
+    layout: Option<String>,
+    language: Option<String>,
+    answers: Option<PathBuf>,
+    dictionary: Option<PathBuf>,
+    mode: Option<String>,
+    tick_rate: Option<u64>, // milliseconds
+    animation: AnimationFile,
+    keys: BTreeMap<String, KeyList>,     // by action name
+    themes: BTreeMap<String, ThemeFile>, // by theme name
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct AnimationFile {
+    flip: Option<u64>,  // milliseconds
+    shake: Option<u64>, // milliseconds
+}
+
+// A theme of the file, statuses left out are kept from the built-in theme
+// of that name, or from the default one
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ThemeFile {
+    exact: Option<StyleFile>,
+    exists: Option<StyleFile>,
+    absent: Option<StyleFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct StyleFile {
+    fg: Option<ColorValue>, // letter, the terminal's own when left out
+    bg: Option<ColorValue>, // tile, the terminal's own when left out
+    bold: bool,
+    underline: bool,
+}
+
+// A color index, or a color as text
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ColorValue {
+    Index(i64),
+    Text(String),
+}
+
+// One key, or every key of an action
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+// Milliseconds from 1 up to MAX_MILLIS, 0 as well for animations
+fn millis(name: &str, value: u64, min: u64) -> Result<Duration, ConfigError> {
+    if !(min..=MAX_MILLIS).contains(&value) {
+        return Err(ConfigError::Invalid(format!(
+            "{} must be from {} to {} milliseconds",
+            name, min, MAX_MILLIS
+        )));
+    }
+    Ok(Duration::from_millis(value))
+}
+
+// Keys bound to an action, each a single character, f1 to f12 or a named
+// key, after any modifiers. Actions that work while a guess is typed can
+// not take a plain letter, it would be typed instead.
+fn keys(action: Action, list: KeyList) -> Result<Vec<String>, ConfigError> {
+    let list = match list {
+        KeyList::One(key) => vec![key],
+        KeyList::Many(keys) => keys,
+    };
+    if list.is_empty() {
+        return Err(ConfigError::Invalid(format!(
+            "keys.{} needs at least one key",
+            action.name()
+        )));
+    }
+
+    let mut keys = Vec::new();
+    for value in list {
+        let Some((key, modifiers)) = chord(&value) else {
+            return Err(ConfigError::Invalid(format!(
+                "keys.{} \"{}\" is not a key, use a character, f1 to f12 or one of {}, \
+                 after any of {}",
+                action.name(),
+                value,
+                NAMED_KEYS.join(", "),
+                MODIFIERS
+                    .iter()
+                    .map(|m| format!("{}-", m))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        };
+        let letter = key.chars().count() == 1 && key.chars().all(|c| c.is_alphabetic());
+        if action.typing() && modifiers.is_empty() && letter {
+            return Err(ConfigError::Invalid(format!(
+                "keys.{} can not be the letter \"{}\", it would be typed into the guess",
+                action.name(),
+                key
+            )));
+        }
+        keys.push(value.to_lowercase());
+    }
+    Ok(keys)
+}
+
+// A color of a theme, e.g. themes.dusk.exact.bg
+fn color(name: &str, value: Option<ColorValue>) -> Result<Option<Color>, ConfigError> {
+    let text = match value {
+        None => return Ok(None),
+        Some(ColorValue::Index(index)) => index.to_string(),
+        Some(ColorValue::Text(text)) => text,
+    };
+    match theme::color(&text) {
+        Some(color) => Ok(Some(color)),
+        None => Err(ConfigError::Invalid(format!(
+            "{} \"{}\" is not a color, use 0 to 255, \"#rrggbb\", grey or one of {}, \
+             after any bright-",
+            name,
+            text,
+            COLOR_NAMES.join(", ")
+        ))),
+    }
+}
+
+fn style(name: &str, file: StyleFile) -> Result<Style, ConfigError> {
+    Ok(Style {
+        fg: color(&format!("{}.fg", name), file.fg)?,
+        bg: color(&format!("{}.bg", name), file.bg)?,
+        bold: file.bold,
+        underline: file.underline,
+        inverse: false,
+    })
+}
+
+// A theme of the file over the built-in theme of that name, or over the
+// default theme for a new name
+fn custom_theme(name: String, file: ThemeFile) -> Result<Theme, ConfigError> {
+    if name.trim().is_empty() {
+        return Err(ConfigError::Invalid(
+            "a theme name can not be empty".to_string(),
+        ));
+    }
+    let mut theme = match Theme::named(&name) {
+        Some(theme) => theme,
+        None => Theme {
+            name: Box::leak(name.clone().into_boxed_str()),
+            ..Theme::default()
+        },
+    };
+    let statuses = [
+        ("exact", file.exact, &mut theme.exact),
+        ("exists", file.exists, &mut theme.exists),
+        ("absent", file.absent, &mut theme.absent),
+    ];
+    for (status, file, slot) in statuses {
+        if let Some(file) = file {
+            *slot = style(&format!("themes.{}.{}", name, status), file)?;
+        }
+    }
+    Ok(theme)
+}
+
+impl ConfigFile {
+    fn validate(self) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+
+        // themes of the file take the place of built-ins of the same name,
+        // new ones go after them
+        for (name, file) in self.themes {
+            let theme = custom_theme(name, file)?;
+            match config.themes.iter_mut().find(|t| t.name == theme.name) {
+                Some(slot) => *slot = theme,
+                None => config.themes.push(theme),
+            }
+        }
+
+        if let Some(name) = self.theme {
+            let found = config.themes.iter().find(|theme| theme.name == name);
+            config.theme = found.copied().ok_or_else(|| {
+                let names: Vec<&str> = config.themes.iter().map(|theme| theme.name).collect();
+                ConfigError::Invalid(format!(
+                    "unknown theme \"{}\", pick one of {}",
+                    name,
+                    names.join(", ")
+                ))
+            })?;
+        }
+
+        if let Some(name) = self.layout {
+            config.layout = Layout::named(&name).ok_or_else(|| {
+                let names: Vec<&str> = LAYOUTS.iter().map(|layout| layout.name).collect();
+                ConfigError::Invalid(format!(
+                    "unknown layout \"{}\", pick one of {}",
+                    name,
+                    names.join(", ")
+                ))
+            })?;
+        }
+
+        if let Some(name) = self.language {
+            if name.trim().is_empty() {
+                return Err(ConfigError::Invalid(
+                    "language can not be empty".to_string(),
+                ));
+            }
+            config.language = name;
+        }
+
+        if self.dictionary.is_some() && self.answers.is_none() {
+            return Err(ConfigError::Invalid(
+                "dictionary needs answers to go with it".to_string(),
+            ));
+        }
+        config.answers = self.answers;
+        config.dictionary = self.dictionary;
+
+        config.mode = match self.mode.as_deref() {
+            None => None,
+            Some("archive") => Some(Mode::Archive),
+            Some("practice") => Some(Mode::Practice),
+            Some("daily") => Some(Mode::Daily),
+            Some("absurdle") => Some(Mode::Absurdle),
+            Some(other) => {
+                return Err(ConfigError::Invalid(format!(
+                    "unknown mode \"{}\", pick one of archive, practice, daily, absurdle",
+                    other
+                )))
+            }
+        };
+
+        if let Some(ms) = self.tick_rate {
+            config.tick_rate = millis("tick_rate", ms, 1)?;
+        }
+        if let Some(ms) = self.animation.flip {
+            config.timings.flip = millis("animation.flip", ms, 0)?;
+        }
+        if let Some(ms) = self.animation.shake {
+            config.timings.shake = millis("animation.shake", ms, 0)?;
+        }
+
+        // unknown actions were reported before
+        for (name, list) in self.keys {
+            if let Some(action) = Action::named(&name) {
+                config.keys.bind(action, keys(action, list)?);
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+// Every setting of the file the config does not know, e.g. "keys.jump"
+fn unknown_settings(table: &toml::Table) -> Vec<String> {
+    let actions: Vec<&str> = ACTIONS.iter().map(|action| action.name()).collect();
+    let mut unknown = Vec::new();
+    for (name, value) in table {
+        let known = match name.as_str() {
+            "animation" => ANIMATION_SETTINGS,
+            "keys" => &actions[..],
+            "themes" => {
+                unknown.extend(unknown_theme_settings(value));
+                continue;
+            }
+            _ => {
+                if !SETTINGS.contains(&name.as_str()) {
+                    unknown.push(name.clone());
+                }
+                continue;
+            }
+        };
+        if let Some(section) = value.as_table() {
+            unknown.extend(
+                section
+                    .keys()
+                    .filter(|key| !known.contains(&key.as_str()))
+                    .map(|key| format!("{}.{}", name, key)),
+            );
+        }
+    }
+    unknown
+}
+
+// Unknown settings of the themes, e.g. "themes.dusk.exact.colour"
+fn unknown_theme_settings(value: &toml::Value) -> Vec<String> {
+    let mut unknown = Vec::new();
+    let Some(themes) = value.as_table() else {
+        return unknown;
+    };
+    for (name, theme) in themes.iter() {
+        let Some(theme) = theme.as_table() else {
+            continue;
+        };
+        for (status, style) in theme {
+            if !THEME_SETTINGS.contains(&status.as_str()) {
+                unknown.push(format!("themes.{}.{}", name, status));
+                continue;
+            }
+            if let Some(style) = style.as_table() {
+                unknown.extend(
+                    style
+                        .keys()
+                        .filter(|key| !STYLE_SETTINGS.contains(&key.as_str()))
+                        .map(|key| format!("themes.{}.{}.{}", name, status, key)),
+                );
+            }
+        }
+    }
+    unknown
+}
+
+fn load(path: &Path) -> Result<Config, ConfigError> {
+    let content = read_to_string(path)?;
+    let table: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| ConfigError::Parse(e.to_string()))?;
+
+    let unknown = unknown_settings(&table);
+    if !unknown.is_empty() {
+        return Err(ConfigError::Unknown(unknown));
+    }
+
+    let file: ConfigFile = table
+        .try_into()
+        .map_err(|e: toml::de::Error| ConfigError::Parse(e.to_string()))?;
+    let mut config = file.validate()?;
+
+    // word files are found next to the config
+    let dir = path.parent().unwrap_or(Path::new(""));
+    config.answers = config.answers.map(|file| dir.join(file));
+    config.dictionary = config.dictionary.map(|file| dir.join(file));
+    Ok(config)
+}
+
+// No file means the defaults, anything unreadable is reported
+pub fn read() -> Result<Config, ConfigError> {
+    match config_path() {
+        Some(path) if path.exists() => load(&path).map_err(|e| e.at(&path)),
+        _ => Ok(Config::default()),
+    }
+}
+
+// -----------------------------
+// Errors
+// -----------------------------
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),            // file could not be read
+    Parse(String),                 // file is not valid TOML, or a value has the wrong type
+    Invalid(String),               // file parsed but holds a value out of range
+    Unknown(Vec<String>),          // settings the config does not have
+    At(PathBuf, Box<ConfigError>), // any of the above, in this file
+}
+
+impl ConfigError {
+    fn at(self, path: &Path) -> Self {
+        ConfigError::At(path.to_path_buf(), Box::new(self))
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "could not be read: {}", e),
+            ConfigError::Parse(e) => write!(f, "is malformed: {}", e.trim_end()),
+            ConfigError::Invalid(e) => write!(f, "is invalid: {}", e),
+            ConfigError::Unknown(names) => {
+                write!(f, "has unknown settings: {}", names.join(", "))
+            }
+            ConfigError::At(path, e) => write!(f, "{} {}", path.display(), e),
+        }
+    }
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(e: std::io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
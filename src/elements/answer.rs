@@ -1,72 +1,93 @@
+use crate::calendar::date_of;
+use crate::game::{boards_name, solved_on};
+use crate::language::language;
 use crate::tui_engine::*;
-use crate::{AppState, GameStatus};
+use crate::words::{LETTERS, PLAY_WORDS};
+use crate::{AppState, GameStatus, Mode};
 
 static X: u16 = 30;
 static Y: u16 = 2;
+static WIDTH: usize = 21;
+
+// "title word" in the word's style, padded so it erases a longer header
+fn header(title: &str, word: &str, style: Style) -> Vec<Glyph> {
+    let mut row = glyphs(&format!("{} ", title), Style::default());
+    row.extend(glyphs(word, style));
+    let padding = WIDTH.saturating_sub(row.len());
+    row.extend(glyphs(&" ".repeat(padding), Style::default()));
+    row
+}
 
 pub fn build<'a>() -> Element<'a, AppState> {
     let mut answer: Element<AppState> = Element::new(0, 0, Look::new());
 
     answer.on_state = Some(Box::new(|el, state| {
+        // practice words go by their place in the set, not the archive number
+        let title = match state.mode {
+            _ if state.boards > 1 => boards_name(state.boards).to_string(),
+            Mode::Absurdle => "Absurdle".to_string(),
+            Mode::Practice => format!("Practice {}", state.practice_games),
+            // other lengths and word lists have no archive number
+            _ if !language().has_archive() => language().word_list().to_string(),
+            _ if state.letters != LETTERS => format!("{} letters", state.letters),
+            Mode::Daily => format!("Daily {}", date_of(state.daily.day.unwrap_or(0))),
+            _ => format!("Wordle #{}", state.answer_index + 1),
+        };
+
+        // boards solved so far, the answers of the others show on their boards at the end
+        let solved = (0..state.answers.len())
+            .filter(|&b| solved_on(state, b).is_some())
+            .count();
+
+        let highlight = Style::default().inverse();
         match state.game {
-            GameStatus::Won => {
-                let index_in_play = state.in_play;
-                let word_index = state.word_index;
-                let mapped = match index_in_play {
-                    1 => format!(
-                        "Wordle #{} {}",
-                        word_index,
-                        terminal_style::format::inverse("Genius")
-                    ),
-                    2 => format!(
-                        "Wordle #{} {}",
-                        word_index,
-                        terminal_style::format::inverse("Magnificent")
-                    ),
-                    3 => format!(
-                        "Wordle #{} {}",
-                        word_index,
-                        terminal_style::format::inverse("Impressive")
-                    ),
-                    4 => format!(
-                        "Wordle #{} {}",
-                        word_index,
-                        terminal_style::format::inverse("Splendid")
-                    ),
-                    5 => format!(
-                        "Wordle #{} {}",
-                        word_index,
-                        terminal_style::format::inverse("Great")
-                    ),
-                    6 => format!(
-                        "Wordle #{} {}",
-                        word_index,
-                        terminal_style::format::inverse("Phew")
+            _ if state.complete => {
+                let mapped = format!("All {} words played", PLAY_WORDS.len());
+                el.look.update(vec![vec![mapped]]);
+            }
+            _ if state.boards > 1 => {
+                let row = match state.game {
+                    GameStatus::Won => header(
+                        &title,
+                        &format!("{} in {}", solved, state.in_play),
+                        highlight,
                     ),
-                    _ => format!(
-                        "Wordle #{} {}",
-                        word_index,
-                        terminal_style::format::inverse("???")
+                    GameStatus::Lost => {
+                        header(&title, &format!("{}/{}", solved, state.boards), highlight)
+                    }
+                    GameStatus::InPlay => header(
+                        &title,
+                        &format!("{}/{}", solved, state.boards),
+                        Style::default(),
                     ),
                 };
+                el.look.update(row);
+            }
+            // absurdle scores the guesses it took
+            GameStatus::Won if state.mode == Mode::Absurdle => {
+                let solved = format!("Solved in {} guesses", state.in_play);
+                el.look.update(header(&title, &solved, highlight));
+            }
+            GameStatus::Won => {
+                let praise = language().strings.praise(state.in_play);
 
-                el.look.update(vec![vec![mapped]]);
+                el.look.update(header(&title, praise, highlight));
 
                 // then show the actual answer
-                let final_mapped = format!("Wordle #{} {}", state.word_index, state.answer);
-                el.look.update(vec![vec![final_mapped]]);
+                el.look
+                    .update(header(&title, state.answer, Style::default()));
             }
             GameStatus::Lost => {
-                let mapped = format!(
-                    "Wordle #{} {}",
-                    state.word_index,
-                    terminal_style::format::inverse(state.answer)
-                );
-                el.look.update(vec![vec![mapped]]);
+                el.look.update(header(&title, state.answer, highlight));
+            }
+            GameStatus::InPlay if state.mode == Mode::Absurdle => {
+                // words the guesses so far still allow
+                let left = format!("{} left", state.candidates.len());
+                el.look.update(header(&title, &left, Style::default()));
             }
             GameStatus::InPlay => {
-                let mapped = format!("Wordle #{} {}", state.word_index + 1, "?????");
-                el.look.update(vec![vec![mapped]]);
+                let hidden = "?".repeat(state.letters);
+                el.look.update(header(&title, &hidden, Style::default()));
             }
         }
 
@@ -1,168 +1,114 @@
 use crate::elements::animators::*;
+use crate::elements::grid::Grid;
 use crate::game::*;
+use crate::history::{append, GameRecord};
+use crate::keymap::{pressed, ACTIONS};
+use crate::language::language;
+use crate::storage::save_state;
 use crate::tui_engine::*;
 use crate::{AppState, WordStatus};
 
 static X: u16 = 9;
 static Y: u16 = 4;
 
-// Maps letter status to background color
-fn status_to_ansi(status: u8) -> u8 {
-    match status {
-        2 => 2,
-        1 => 3,
-        _ => 8,
-    }
-}
-
 pub fn build<'a>() -> Element<'a, AppState> {
     let mut board: Element<AppState> = Element::new(0, 0, Look::new());
 
     // the board initiates animations based on state.
     // this has to be done in the loop because the state can not be mutated by the on_state callback
     board.on_loop = Some(Box::new(|el, state, _event| {
+        // several boards are tiled elsewhere, their guesses show at once
+        let single = state.boards == 1;
         if state.word_status == WordStatus::Invalid {
-            invalid_word_animator(el, state.in_play);
+            if single {
+                let grid = Grid::new(state.letters, state.status.len());
+                invalid_word_animator(el, &grid, state.in_play, &state.timings);
+            } else if state.message.is_none() {
+                state.message = Some(language().strings.not_in_list.clone());
+            }
             state.word_status = WordStatus::InPlay; // reset status
         }
         if state.word_status == WordStatus::Valid {
-            reveal_animator(el, state.in_play, &state.status.clone(), &state.answer);
-
+            if single {
+                let grid = Grid::new(state.letters, state.status.len());
+                reveal_animator(
+                    el,
+                    &grid,
+                    state.in_play,
+                    &state.status,
+                    state.answer,
+                    &state.theme,
+                    &state.timings,
+                );
+            }
+            state.word_status = WordStatus::Revealing;
+        }
+        // the word is scored once the reveal has played out (or was skipped)
+        if state.word_status == WordStatus::Revealing && !el.is_animating() {
             state.word_status = WordStatus::InPlay; // reset status
             mutate_state_new_word(state);
+            // every submitted guess is saved so the game can be resumed
+            save_state(state).ok();
+            if let Some(record) = GameRecord::from_state(state) {
+                append(&record).ok();
+            }
         }
     }));
     board.on_keypress = Some(Box::new(|_el, state, event| {
-        mutate_state_letter(state, &event.key.clone().unwrap_or_default());
+        for &action in ACTIONS {
+            if pressed(event, action) {
+                mutate_state_action(state, action);
+            }
+        }
+        // a letter held with ctrl or alt is a binding, not typing
+        if event.modifiers.iter().all(|m| m == "shift") {
+            mutate_state_letter(state, &event.key.clone().unwrap_or_default());
+        }
     }));
     board.on_state = Some(Box::new(|el, state| {
-        let empty_board = Look::from(vec![
-            vec![
-                "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌",
-                "─", "─", "─", "┐", "┌", "─", "─", "─", "┐",
-            ],
-            vec![
-                "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│",
-                " ", " ", " ", "│", "│", " ", " ", " ", "│",
-            ],
-            vec![
-                "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└",
-                "─", "─", "─", "┘", "└", "─", "─", "─", "┘",
-            ],
-            vec![
-                "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌",
-                "─", "─", "─", "┐", "┌", "─", "─", "─", "┐",
-            ],
-            vec![
-                "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│",
-                " ", " ", " ", "│", "│", " ", " ", " ", "│",
-            ],
-            vec![
-                "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└",
-                "─", "─", "─", "┘", "└", "─", "─", "─", "┘",
-            ],
-            vec![
-                "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌",
-                "─", "─", "─", "┐", "┌", "─", "─", "─", "┐",
-            ],
-            vec![
-                "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│",
-                " ", " ", " ", "│", "│", " ", " ", " ", "│",
-            ],
-            vec![
-                "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└",
-                "─", "─", "─", "┘", "└", "─", "─", "─", "┘",
-            ],
-            vec![
-                "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌",
-                "─", "─", "─", "┐", "┌", "─", "─", "─", "┐",
-            ],
-            vec![
-                "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│",
-                " ", " ", " ", "│", "│", " ", " ", " ", "│",
-            ],
-            vec![
-                "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└",
-                "─", "─", "─", "┘", "└", "─", "─", "─", "┘",
-            ],
-            vec![
-                "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌",
-                "─", "─", "─", "┐", "┌", "─", "─", "─", "┐",
-            ],
-            vec![
-                "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│",
-                " ", " ", " ", "│", "│", " ", " ", " ", "│",
-            ],
-            vec![
-                "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└",
-                "─", "─", "─", "┘", "└", "─", "─", "─", "┘",
-            ],
-            vec![
-                "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌", "─", "─", "─", "┐", "┌",
-                "─", "─", "─", "┐", "┌", "─", "─", "─", "┐",
-            ],
-            vec![
-                "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│", " ", " ", " ", "│", "│",
-                " ", " ", " ", "│", "│", " ", " ", " ", "│",
-            ],
-            vec![
-                "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└", "─", "─", "─", "┘", "└",
-                "─", "─", "─", "┘", "└", "─", "─", "─", "┘",
-            ],
-        ]);
+        if state.boards > 1 {
+            el.look.update(Look::new());
+            return;
+        }
 
-        let mapped = empty_board
-            .clone()
-            .cells()
-            .iter()
-            .enumerate()
-            .map(|(row_index, row)| {
-                row.iter()
-                    .enumerate()
-                    .map(|(col_index, item)| {
-                        let r = row_index / 3;
-                        let c = col_index / 5;
-                        let letter = state.status[r][c];
-                        let guess_arr = &state.status[r];
-                        let answer_arr: Vec<char> = state.answer.chars().collect();
+        // keep the frame in view, the animation owns the look until it ends
+        if el.is_animating() {
+            crate::elements::draw_relative(el, X, Y, state);
+            return;
+        }
+
+        let grid = Grid::new(state.letters, state.status.len());
+        let answer_arr: Vec<char> = state.answer.chars().collect();
+        let mut rows = grid.blank();
+
+        for (r, guess_arr) in state.status.iter().enumerate() {
+            for (c, &letter) in guess_arr.iter().enumerate() {
+                let mut cell = grid.cell(letter);
+
+                // previous guess: apply background, the row in play stays plain
+                if state.in_play != r && letter != ' ' {
+                    let letter_status = get_letter_status(c, guess_arr, &answer_arr);
+                    for item in cell.iter_mut().flatten() {
+                        item.style = item.style.patch(state.theme.status(letter_status));
+                    }
+                }
 
-                        if row_index % 3 == 1 && col_index % 5 == 2 {
-                            // center cell (letter slot)
-                            if state.in_play == r {
-                                // current attempt row
-                                letter.to_string()
-                            } else if letter == ' ' {
-                                letter.to_string()
-                            } else {
-                                // previous guess: apply background
-                                let letter_status = get_letter_status(c, guess_arr, &answer_arr);
-                                terminal_style::format::background(
-                                    status_to_ansi(letter_status),
-                                    &letter.to_string(),
-                                )
-                                .unwrap()
-                            }
-                        } else {
-                            // border / filler
-                            if state.in_play == r {
-                                item.clone()
-                            } else if letter == ' ' {
-                                item.clone()
-                            } else {
-                                let letter_status = get_letter_status(c, guess_arr, &answer_arr);
-                                terminal_style::format::background(
-                                    status_to_ansi(letter_status),
-                                    item,
-                                )
-                                .unwrap()
-                            }
-                        }
-                    })
-                    .collect::<Vec<String>>()
-            })
-            .collect::<Vec<Vec<String>>>();
+                grid.paint(&mut rows, r, c, cell);
+            }
+        }
+
+        // an absurdle board that grew packs its rows, blanks erase the board it started as
+        let first = Grid::new(state.letters, state.attempts);
+        let width = grid.width().max(first.width());
+        for row in rows.iter_mut() {
+            row.resize(width, Glyph::blank());
+        }
+        rows.resize(
+            grid.height().max(first.height()),
+            vec![Glyph::blank(); width],
+        );
 
-        el.look.update(mapped);
+        el.look.update(rows);
 
         crate::elements::draw_relative(el, X, Y, state);
     }));
@@ -0,0 +1,203 @@
+use crate::calendar::{date_of, index_of};
+use crate::elements::text_row;
+use crate::game::{mutate_state_pick, plays_archive};
+use crate::history::{self, Outcome};
+use crate::keymap::{pressed, Action};
+use crate::storage::save_state;
+use crate::tui_engine::*;
+use crate::words::PLAY_WORDS;
+use crate::{AppState, Browser, GameStatus, WordStatus};
+
+// Covers the board like the completion panel
+static X: u16 = 9;
+static Y: u16 = 4;
+static WIDTH: usize = 25;
+static HEIGHT: usize = 18;
+static LIST_TOP: usize = 2; // rows above the list: title and query
+static PAGE: usize = 16; // words in view
+
+impl Browser {
+    // moves the selection, scrolling it into view
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(PLAY_WORDS.len() - 1);
+        if self.selected < self.top {
+            self.top = self.selected;
+        } else if self.selected >= self.top + PAGE {
+            self.top = self.selected + 1 - PAGE;
+        }
+    }
+}
+
+// the browser opens between games so none is abandoned half played,
+// and only on the single board of five letter words the archive is made of
+pub(crate) fn can_browse(state: &AppState) -> bool {
+    plays_archive(state.letters, state.boards)
+        && state.word_status == WordStatus::InPlay
+        && (state.game != GameStatus::InPlay || state.in_play == 0)
+}
+
+// Opens on the next word of the progression
+pub(crate) fn open(state: &mut AppState) {
+    let mut browser = Browser {
+        selected: 0,
+        top: 0,
+        query: String::new(),
+        outcomes: history::outcomes(),
+    };
+    browser.select(state.word_index + PAGE / 2);
+    browser.select(state.word_index);
+    state.browser = Some(browser);
+    state.review = None;
+    state.message = None;
+}
+
+// A word number (#12 or 12) or a publication date (2021-06-30)
+fn find(query: &str) -> Result<usize, String> {
+    if query.contains('-') {
+        index_of(query)
+            .filter(|&i| i < PLAY_WORDS.len())
+            .ok_or(format!("No Wordle on {}", query))
+    } else {
+        query
+            .parse::<usize>()
+            .ok()
+            .filter(|n| (1..=PLAY_WORDS.len()).contains(n))
+            .map(|n| n - 1)
+            .ok_or(format!("No Wordle #{}", query))
+    }
+}
+
+fn pick(state: &mut AppState, index: usize) {
+    mutate_state_pick(state, index);
+    save_state(state).ok();
+}
+
+fn keypress(state: &mut AppState, event: &EventData) {
+    let key = event.key.as_deref().unwrap_or_default();
+    let closing = pressed(event, Action::Close) || pressed(event, Action::Archive);
+    let Some(browser) = state.browser.as_mut() else {
+        if pressed(event, Action::Archive) && can_browse(state) {
+            open(state);
+        }
+        return;
+    };
+
+    match key {
+        _ if closing => {
+            state.browser = None;
+            state.message = None;
+        }
+        "up" => browser.select(browser.selected.saturating_sub(1)),
+        "down" => browser.select(browser.selected + 1),
+        "left" => browser.select(browser.selected.saturating_sub(PAGE)),
+        "right" => browser.select(browser.selected + PAGE),
+        "delete" => {
+            browser.query.pop();
+        }
+        "enter" if browser.query.is_empty() => {
+            let index = browser.selected;
+            pick(state, index);
+        }
+        "enter" => {
+            match find(&browser.query) {
+                Ok(index) => {
+                    browser.select(index);
+                    state.message = None;
+                }
+                Err(message) => state.message = Some(message),
+            }
+            browser.query.clear();
+        }
+        k if k.len() == 1 && browser.query.len() < 10 => {
+            let ch = k.chars().next().unwrap_or(' ');
+            if ch.is_ascii_digit() || ch == '-' {
+                browser.query.push(ch);
+            }
+        }
+        _ => {}
+    }
+}
+
+// won, lost, skipped over by the progression, or up next
+fn mark(state: &AppState, outcome: Option<Outcome>, index: usize) -> &'static str {
+    match outcome {
+        Some(Outcome::Won) => "won",
+        Some(Outcome::Lost) => "lost",
+        None if index == state.word_index => "next",
+        None if index < state.word_index => "skipped",
+        None => "",
+    }
+}
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut browser: Element<AppState> = Element::new(0, 0, Look::new());
+
+    browser.on_keypress = Some(Box::new(|_el, state, event| {
+        keypress(state, event);
+    }));
+    browser.on_click = Some(Box::new(|el, state, event| {
+        if state.browser.is_none() || !mouse_over(el, event) {
+            return;
+        }
+        let row = event.y.unwrap_or(0).saturating_sub(el.y.get()) as usize;
+        let Some(browser) = state.browser.as_mut() else {
+            return;
+        };
+        if row < LIST_TOP || browser.top + row - LIST_TOP >= PLAY_WORDS.len() {
+            return;
+        }
+
+        // the first click selects, a click on the selection plays it
+        let index = browser.top + row - LIST_TOP;
+        if index == browser.selected {
+            pick(state, index);
+        } else {
+            browser.select(index);
+        }
+    }));
+    browser.on_state = Some(Box::new(|el, state| {
+        let Some(browser) = &state.browser else {
+            // the board draws over the list once closed
+            el.look.update(Look::new());
+            return;
+        };
+
+        let query = if browser.query.is_empty() {
+            " Go to: #N or YYYY-MM-DD".to_string()
+        } else {
+            format!(" Go to: {}_", browser.query)
+        };
+
+        let mut rows = vec![
+            text_row(
+                &format!(
+                    " Archive {:>16}",
+                    format!("{} to close", state.keys.label(Action::Close))
+                ),
+                WIDTH,
+            ),
+            text_row(&query, WIDTH),
+        ];
+        for index in browser.top..(browser.top + PAGE).min(PLAY_WORDS.len()) {
+            let line = format!(
+                " #{:<4} {} {}",
+                index + 1,
+                date_of(index),
+                mark(state, browser.outcomes[index], index)
+            );
+            let mut row = text_row(&line, WIDTH);
+            if index == browser.selected {
+                for cell in &mut row {
+                    cell.style = cell.style.inverse();
+                }
+            }
+            rows.push(row);
+        }
+        rows.resize(HEIGHT, text_row("", WIDTH));
+        el.look.update(rows);
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    browser
+}
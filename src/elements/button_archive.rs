@@ -0,0 +1,33 @@
+use crate::elements::browser::{can_browse, open};
+use crate::tui_engine::*;
+use crate::AppState;
+
+static X: u16 = 42;
+static Y: u16 = 22;
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut button_archive: Element<AppState> = Element::new(0, 0, Look::new());
+
+    button_archive.on_click = Some(Box::new(|el, state, event| {
+        if !mouse_over(el, event) {
+            return;
+        }
+        if state.browser.is_some() {
+            state.browser = None;
+        } else if can_browse(state) {
+            open(state);
+        }
+    }));
+    button_archive.on_state = Some(Box::new(|el, state| {
+        if can_browse(state) {
+            el.look
+                .update(Look::from("Archive").styled(Style::default().underline()));
+        } else {
+            el.look.update(Look::from("Archive"));
+        }
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    button_archive
+}
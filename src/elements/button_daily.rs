@@ -0,0 +1,49 @@
+use crate::game::{mutate_state_toggle_daily, plays_archive};
+use crate::storage::save_state;
+use crate::tui_engine::*;
+use crate::{AppState, GameStatus, Mode, WordStatus};
+
+static X: u16 = 52;
+static Y: u16 = 22;
+
+// before the first guess, or to leave today's finished board,
+// today's word is a single board of five letters
+fn can_toggle(state: &AppState) -> bool {
+    if !plays_archive(state.letters, state.boards) {
+        return false;
+    }
+    let fresh = state.game == GameStatus::InPlay
+        && state.in_play == 0
+        && state.word_status == WordStatus::InPlay;
+    let daily_done = state.mode == Mode::Daily && state.game != GameStatus::InPlay;
+    fresh || daily_done
+}
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut button_daily: Element<AppState> = Element::new(0, 0, Look::new());
+
+    button_daily.on_click = Some(Box::new(|el, state, event| {
+        if can_toggle(state) && mouse_over(el, event) {
+            mutate_state_toggle_daily(state);
+            save_state(state).ok();
+        }
+    }));
+    button_daily.on_state = Some(Box::new(|el, state| {
+        let label = if state.mode == Mode::Daily {
+            "Daily: On "
+        } else {
+            "Daily: Off"
+        };
+
+        if can_toggle(state) {
+            el.look
+                .update(Look::from(label).styled(Style::default().underline()));
+        } else {
+            el.look.update(Look::from(label));
+        }
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    button_daily
+}
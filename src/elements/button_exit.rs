@@ -1,12 +1,16 @@
+use crate::keymap::{pressed, Action};
 use crate::tui_engine::*;
 use crate::AppState;
 
 pub fn build<'a>() -> Element<'a, AppState> {
-    let mut button_exit: Element<AppState> =
-        Element::new(0, 0, terminal_style::format::underline(Look::from("Exit")));
+    let mut button_exit: Element<AppState> = Element::new(
+        0,
+        0,
+        Look::from("Exit").styled(Style::default().underline()),
+    );
 
     button_exit.on_keypress = Some(Box::new(|_el, state, event| {
-        if event.key == Some("c".to_string()) && event.modifiers.contains(&"ctrl".to_string()) {
+        if pressed(event, Action::Quit) {
             state.exit_flag = true;
         }
     }));
@@ -16,7 +20,7 @@ pub fn build<'a>() -> Element<'a, AppState> {
         }
     }));
     button_exit.on_state = Some(Box::new(|el, _state| {
-        el.x.set(columns() - 4);
+        el.x.set(columns().saturating_sub(4));
         draw(el);
     }));
 
@@ -0,0 +1,41 @@
+use crate::storage::save_state;
+use crate::tui_engine::*;
+use crate::{AppState, GameStatus};
+
+static X: u16 = 9;
+static Y: u16 = 22;
+
+// hard mode can only be switched before the first guess of a game
+fn can_toggle(state: &AppState) -> bool {
+    state.game == GameStatus::InPlay && state.in_play == 0
+}
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut button_hard: Element<AppState> = Element::new(0, 0, Look::new());
+
+    button_hard.on_click = Some(Box::new(|el, state, event| {
+        if can_toggle(state) && mouse_over(el, event) {
+            state.hard_mode = !state.hard_mode;
+            // the choice sticks between sessions
+            save_state(state).ok();
+        }
+    }));
+    button_hard.on_state = Some(Box::new(|el, state| {
+        let label = if state.hard_mode {
+            "Hard Mode: On "
+        } else {
+            "Hard Mode: Off"
+        };
+
+        if can_toggle(state) {
+            el.look
+                .update(Look::from(label).styled(Style::default().underline()));
+        } else {
+            el.look.update(Look::from(label));
+        }
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    button_hard
+}
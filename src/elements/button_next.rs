@@ -1,5 +1,5 @@
 use crate::game::mutate_state_new_game;
-use crate::storage::save;
+use crate::storage::save_state;
 use crate::tui_engine::*;
 use crate::{AppState, GameStatus};
 
@@ -10,18 +10,16 @@ pub fn build<'a>() -> Element<'a, AppState> {
     let mut button_next: Element<AppState> = Element::new(0, 0, Look::new());
 
     button_next.on_click = Some(Box::new(|el, state, event| {
-        if state.game != GameStatus::InPlay {
-            if mouse_over(el, event) {
-                mutate_state_new_game(state);
-            }
+        if state.game != GameStatus::InPlay && mouse_over(el, event) {
+            mutate_state_new_game(state);
         }
     }));
     button_next.on_state = Some(Box::new(|el, state| {
         if state.game != GameStatus::InPlay {
             el.look
-                .update(terminal_style::format::underline(Look::from("Next")));
+                .update(Look::from("Next").styled(Style::default().underline()));
             // once we move to next word - save the status of the game
-            save(&state.results, state.streak, state.word_index).ok();
+            save_state(state).ok();
         } else {
             el.look.update(Look::from("    "));
         }
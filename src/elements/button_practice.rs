@@ -0,0 +1,41 @@
+use crate::game::mutate_state_toggle_practice;
+use crate::storage::save_state;
+use crate::tui_engine::*;
+use crate::{AppState, GameStatus, Mode};
+
+static X: u16 = 26;
+static Y: u16 = 22;
+
+// like hard mode, only before the first guess so no game is abandoned half played
+fn can_toggle(state: &AppState) -> bool {
+    state.game == GameStatus::InPlay && state.in_play == 0
+}
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut button_practice: Element<AppState> = Element::new(0, 0, Look::new());
+
+    button_practice.on_click = Some(Box::new(|el, state, event| {
+        if can_toggle(state) && mouse_over(el, event) {
+            mutate_state_toggle_practice(state);
+            save_state(state).ok();
+        }
+    }));
+    button_practice.on_state = Some(Box::new(|el, state| {
+        let label = if state.mode == Mode::Practice {
+            "Practice: On "
+        } else {
+            "Practice: Off"
+        };
+
+        if can_toggle(state) {
+            el.look
+                .update(Look::from(label).styled(Style::default().underline()));
+        } else {
+            el.look.update(Look::from(label));
+        }
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    button_practice
+}
@@ -0,0 +1,45 @@
+use crate::keymap::{pressed, Action};
+use crate::share;
+use crate::tui_engine::*;
+use crate::{AppState, GameStatus};
+
+pub(crate) static X: u16 = 68;
+pub(crate) static Y: u16 = 22;
+
+// copies the share grid and says so
+fn share(state: &mut AppState) {
+    if let Some(text) = share::from_state(state) {
+        copy_to_clipboard(&text);
+        state.message = Some("Copied to clipboard".to_string());
+    }
+}
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut button_share: Element<AppState> = Element::new(0, 0, Look::new());
+
+    button_share.on_keypress = Some(Box::new(|_el, state, event| {
+        if state.game != GameStatus::InPlay
+            && state.browser.is_none()
+            && pressed(event, Action::Share)
+        {
+            share(state);
+        }
+    }));
+    button_share.on_click = Some(Box::new(|el, state, event| {
+        if state.game != GameStatus::InPlay && mouse_over(el, event) {
+            share(state);
+        }
+    }));
+    button_share.on_state = Some(Box::new(|el, state| {
+        if state.game != GameStatus::InPlay {
+            el.look
+                .update(Look::from("Share").styled(Style::default().underline()));
+        } else {
+            el.look.update(Look::from("     "));
+        }
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    button_share
+}
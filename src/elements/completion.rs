@@ -0,0 +1,87 @@
+use crate::elements::text_row;
+use crate::game::mutate_state_choose_mode;
+use crate::storage::save_state;
+use crate::tui_engine::*;
+use crate::{AppState, Mode};
+
+// Covers the board while there is nothing left to play
+static X: u16 = 9;
+static Y: u16 = 4;
+static WIDTH: usize = 25;
+static HEIGHT: usize = 18;
+
+// Rows of the options, relative to the panel
+static ARCHIVE_ROW: usize = 8;
+static PRACTICE_ROW: usize = 10;
+static MISSED_ROW: usize = 12;
+
+// "  1 Label", the label underlined when it can be picked
+fn option_row(key: &str, label: &str, enabled: bool) -> Vec<Glyph> {
+    let mut row = text_row(&format!("  {} {}", key, label), WIDTH);
+    if enabled {
+        for cell in &mut row[4..4 + label.chars().count()] {
+            cell.style = cell.style.underline();
+        }
+    }
+    row
+}
+
+fn choose(state: &mut AppState, mode: Mode) {
+    mutate_state_choose_mode(state, mode);
+    save_state(state).ok();
+}
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut completion: Element<AppState> = Element::new(0, 0, Look::new());
+
+    completion.on_keypress = Some(Box::new(|_el, state, event| {
+        if !state.complete || state.browser.is_some() {
+            return;
+        }
+        match event.key.as_deref() {
+            Some("1") => choose(state, Mode::Archive),
+            Some("2") => choose(state, Mode::Practice),
+            Some("3") => choose(state, Mode::Missed),
+            _ => {}
+        }
+    }));
+    completion.on_click = Some(Box::new(|el, state, event| {
+        if !state.complete || state.browser.is_some() || !mouse_over(el, event) {
+            return;
+        }
+        let row = event.y.unwrap_or(0).saturating_sub(el.y.get()) as usize;
+        match row {
+            r if r == ARCHIVE_ROW => choose(state, Mode::Archive),
+            r if r == PRACTICE_ROW => choose(state, Mode::Practice),
+            r if r == MISSED_ROW => choose(state, Mode::Missed),
+            _ => {}
+        }
+    }));
+    completion.on_state = Some(Box::new(|el, state| {
+        if !state.complete {
+            // the board draws over the panel once play resumes
+            el.look.update(Look::new());
+            return;
+        }
+
+        let title = match state.mode {
+            Mode::Missed => "  No missed words left".to_string(),
+            _ => format!("  Pass {} complete!", state.passes + 1),
+        };
+        let missed = match state.missed.len() {
+            0 => option_row("3", "No missed words", false),
+            n => option_row("3", &format!("Replay missed ({})", n), true),
+        };
+
+        let mut rows = vec![text_row("", WIDTH); HEIGHT];
+        rows[4] = text_row(&title, WIDTH);
+        rows[ARCHIVE_ROW] = option_row("1", "Another pass", true);
+        rows[PRACTICE_ROW] = option_row("2", "Random practice", true);
+        rows[MISSED_ROW] = missed;
+        el.look.update(rows);
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    completion
+}
@@ -1,14 +1,15 @@
+use crate::elements::keyboard::key_origin;
 use crate::game::*;
+use crate::keymap::Action;
 use crate::tui_engine::*;
 use crate::AppState;
 
-static X: u16 = 66;
-static Y: u16 = 19;
-
-pub fn build<'a>() -> Element<'a, AppState> {
+// Right after the last letter of the bottom row
+pub fn build<'a>(bottom_keys: usize) -> Element<'a, AppState> {
+    let (x, y) = key_origin(2, bottom_keys);
     let mut delete_key: Element<AppState> = Element::new(
-        X,
-        Y,
+        x,
+        y,
         Look::from(vec![
             vec!["┌", "─", "─", "┐"],
             vec!["│", " ", "⌫", "│"],
@@ -18,11 +19,11 @@ pub fn build<'a>() -> Element<'a, AppState> {
 
     delete_key.on_click = Some(Box::new(|el, state, event| {
         if mouse_over(el, event) {
-            mutate_state_letter(state, &"delete".to_string());
+            mutate_state_action(state, Action::DeleteLetter);
         }
     }));
-    delete_key.on_state = Some(Box::new(|el, state| {
-        crate::elements::draw_relative(el, X, Y, state);
+    delete_key.on_state = Some(Box::new(move |el, state| {
+        crate::elements::draw_relative(el, x, y, state);
     }));
 
     delete_key
@@ -1,4 +1,5 @@
 use crate::game::*;
+use crate::keymap::Action;
 use crate::tui_engine::*;
 use crate::AppState;
 
@@ -17,7 +18,7 @@ pub fn build<'a>() -> Element<'a, AppState> {
     );
     enter_key.on_click = Some(Box::new(|el, state, event| {
         if mouse_over(el, event) {
-            mutate_state_letter(state, &"enter".to_string());
+            mutate_state_action(state, Action::Submit);
         }
     }));
     enter_key.on_state = Some(Box::new(|el, state: &AppState| {
@@ -1,14 +1,27 @@
+use crate::game::MAX_ATTEMPTS;
 use crate::tui_engine::*;
 use crate::AppState;
 
-fn create_bar_element(x: u16, y: u16, what: &str, num: usize) -> Element<AppState> {
+fn create_bar_element<'a>(slot: usize) -> Element<'a, AppState> {
     let mut el: Element<AppState> = Element::new(0, 0, Look::from(""));
 
     el.on_state = Some(Box::new(move |el, state| {
+        // one bar per attempt a game can be won on, several boards
+        // take at least one guess each
+        let bars = state.attempts - state.boards + 1;
+        if slot >= bars {
+            return;
+        }
+        let num = state.boards + slot;
         let index = num - 1;
+        let what = format!("{:>1$}", num, state.attempts.to_string().len());
 
-        // ignore losses (results[6])
-        let max = state.results[..6].iter().cloned().max().unwrap_or(0);
+        // ignore losses (results[attempts])
+        let max = state.results[..state.attempts]
+            .iter()
+            .cloned()
+            .max()
+            .unwrap_or(0);
         let value = state.results[index];
 
         let len = if max > 0 {
@@ -20,18 +33,18 @@ fn create_bar_element(x: u16, y: u16, what: &str, num: usize) -> Element<AppStat
         let bar: String = " ".repeat(len);
         let end: String = " ".repeat(11usize.saturating_sub(len));
 
-        let look_str = format!(
-            "{} {}{}{}{}",
-            what,
-            terminal_style::format::inverse(&bar),
-            terminal_style::format::inverse(&value.to_string()),
-            end,
-            "" // trailing padding if needed
-        );
+        let mut row = glyphs(&format!("{} ", what), Style::default());
+        row.extend(glyphs(
+            &format!("{}{}", bar, value),
+            Style::default().inverse(),
+        ));
+        row.extend(glyphs(&end, Style::default()));
 
-        el.look.update(vec![vec![look_str]]);
+        el.look.update(row);
 
-        crate::elements::draw_relative(el, x, y, state);
+        // moved up when the bars would reach the keyboard
+        let top = Y.min(KEYBOARD_Y - bars as u16);
+        crate::elements::draw_relative(el, X, top + slot as u16, state);
     }));
 
     el
@@ -39,14 +52,13 @@ fn create_bar_element(x: u16, y: u16, what: &str, num: usize) -> Element<AppStat
 
 static X: u16 = 57;
 static Y: u16 = 4;
+static KEYBOARD_Y: u16 = 13; // first row of the keyboard, below the bars
 
 pub fn build<'a>() -> Elements<'a, AppState> {
     let graphs = Elements::new();
 
-    let stats = vec!["1", "2", "3", "4", "5", "6"];
-    for (index, item) in stats.iter().enumerate() {
-        let bar_element = create_bar_element(X, Y + index as u16, item, index + 1);
-        graphs.push(bar_element);
+    for slot in 0..MAX_ATTEMPTS {
+        graphs.push(create_bar_element(slot));
     }
 
     graphs
@@ -0,0 +1,134 @@
+// Board geometry for any number of letters and attempts.
+// Boxed cells are used while they fit the board area, compact ones otherwise.
+
+use crate::tui_engine::{glyphs, Glyph, Style};
+
+static AREA_WIDTH: usize = 25; // columns the board may use
+static AREA_HEIGHT: usize = 18; // rows the board may use
+static TILED_WIDTH: usize = 30; // columns several boards may use together
+pub(crate) static TILE_GAP: usize = 2; // columns between boards side by side
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Grid {
+    pub letters: usize,
+    pub attempts: usize,
+    pub cell_width: usize,
+    pub cell_height: usize,
+    pub spacing: usize, // blank columns between letters
+    pub pitch: usize,   // rows from one attempt to the next
+}
+
+impl Grid {
+    pub fn new(letters: usize, attempts: usize) -> Self {
+        let (cell_width, cell_height, pitch) =
+            if letters * 5 <= AREA_WIDTH && attempts * 3 <= AREA_HEIGHT {
+                (5, 3, 3) // ┌───┐ boxes
+            } else if attempts * 2 - 1 <= AREA_HEIGHT {
+                (3, 1, 2) // [A] with a blank row between attempts
+            } else {
+                (3, 1, 1) // [A] packed
+            };
+
+        Self {
+            letters,
+            attempts,
+            cell_width,
+            cell_height,
+            spacing: 0,
+            pitch,
+        }
+    }
+
+    // One of several boards and how many go side by side. Each board
+    // shows up to `attempts` rows, fewer when they do not all fit.
+    pub fn tile(letters: usize, attempts: usize, boards: usize) -> (Self, usize) {
+        let fits = |columns: usize, (width, spacing): (usize, usize)| {
+            let board = letters * (width + spacing) - spacing;
+            columns * board + (columns - 1) * TILE_GAP <= TILED_WIDTH
+        };
+        // as many side by side as fit, [A] cells, then spaced letters, then packed ones
+        let (columns, (cell_width, spacing)) = [4, 2, 1]
+            .into_iter()
+            .filter(|&columns| columns <= boards)
+            .flat_map(|columns| [(3, 0), (1, 1), (1, 0)].map(|cells| (columns, cells)))
+            .find(|&(columns, cells)| fits(columns, cells))
+            .unwrap_or((1, (1, 0)));
+
+        let tile_rows = boards.div_ceil(columns);
+        let height = (AREA_HEIGHT + 1) / tile_rows - 1; // a blank row between tile rows
+        let pitch = if attempts * 2 - 1 <= height { 2 } else { 1 };
+
+        let grid = Self {
+            letters,
+            attempts: attempts.min(height.div_ceil(pitch)),
+            cell_width,
+            cell_height: 1,
+            spacing,
+            pitch,
+        };
+        (grid, columns)
+    }
+
+    pub fn width(&self) -> usize {
+        self.letters * (self.cell_width + self.spacing) - self.spacing
+    }
+
+    pub fn height(&self) -> usize {
+        (self.attempts - 1) * self.pitch + self.cell_height
+    }
+
+    // A blank board of the right size
+    pub fn blank(&self) -> Vec<Vec<Glyph>> {
+        vec![vec![Glyph::blank(); self.width()]; self.height()]
+    }
+
+    // The cells of one letter slot, before any color
+    pub fn cell(&self, letter: char) -> Vec<Vec<Glyph>> {
+        let rows: Vec<String> = match (self.cell_height, self.cell_width) {
+            (3, _) => vec!["┌───┐".into(), format!("│ {} │", letter), "└───┘".into()],
+            (_, 3) => vec![format!("[{}]", letter)],
+            _ => vec![letter.to_string()],
+        };
+        rows.iter()
+            .map(|row| glyphs(row, Style::default()))
+            .collect()
+    }
+
+    // A slot flipped edge on, half way through a reveal
+    pub fn flipped(&self) -> Vec<Vec<Glyph>> {
+        (0..self.cell_height)
+            .map(|row| {
+                let fill = if row == self.cell_height / 2 {
+                    '─'
+                } else {
+                    ' '
+                };
+                vec![Glyph::new(fill, Style::default()); self.cell_width]
+            })
+            .collect()
+    }
+
+    // Row and column of the top left corner of a slot
+    pub fn origin(&self, attempt: usize, index: usize) -> (usize, usize) {
+        (
+            attempt * self.pitch,
+            index * (self.cell_width + self.spacing),
+        )
+    }
+
+    // Writes the cells of a slot into board rows
+    pub fn paint(
+        &self,
+        rows: &mut [Vec<Glyph>],
+        attempt: usize,
+        index: usize,
+        cell: Vec<Vec<Glyph>>,
+    ) {
+        let (top, left) = self.origin(attempt, index);
+        for (dy, cell_row) in cell.into_iter().enumerate() {
+            for (dx, item) in cell_row.into_iter().enumerate() {
+                rows[top + dy][left + dx] = item;
+            }
+        }
+    }
+}
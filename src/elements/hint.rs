@@ -0,0 +1,79 @@
+use crate::game::wordle_highlight;
+use crate::keymap::{pressed, Action};
+use crate::solver::{best, grade, history, pattern, play, remaining};
+use crate::tui_engine::*;
+use crate::{AppState, GameStatus, Review, Step, WordStatus};
+
+// The player's rows graded against the best guesses, and how the solver
+// would have played the answer of the game just over
+fn review(state: &AppState) -> Review {
+    let answer_arr: Vec<char> = state.answer.chars().collect();
+    let rows = &state.status[..state.in_play];
+    let player = rows
+        .iter()
+        .zip(grade(state.letters, state.answer, rows, state.hard_mode))
+        .map(|(row, graded)| Step {
+            guess: row.iter().collect(),
+            pattern: wordle_highlight(row, &answer_arr),
+            left: graded.left,
+            rating: Some(graded.rating),
+            wasted: graded.wasted,
+        })
+        .collect();
+    let solver = play(state.letters, state.answer, state.attempts)
+        .into_iter()
+        .map(|(guess, code, left)| Step {
+            guess: guess.to_string(),
+            pattern: pattern(code, state.letters),
+            left,
+            rating: None,
+            wasted: vec![false; state.letters],
+        })
+        .collect();
+    Review {
+        answer: state.answer,
+        player,
+        solver,
+        solver_page: false,
+    }
+}
+
+// The hint key ("?") suggests a guess while playing. Once the game is over it opens the
+// review of the player's rows, then the solver's, then closes it.
+fn keypress(state: &mut AppState) {
+    if state.complete || state.browser.is_some() || state.word_status != WordStatus::InPlay {
+        return;
+    }
+    // the tiled boards have no single answer to narrow down
+    if state.boards > 1 {
+        state.message = Some("Hints need one board".to_string());
+        return;
+    }
+
+    match (&state.game, state.review.as_mut()) {
+        (GameStatus::InPlay, _) => {
+            let history = history(state);
+            let left = remaining(state.letters, &history).len();
+            state.message = match best(state.letters, &history, state.hard_mode) {
+                Some(word) => Some(format!("{} left: {}", left, word)),
+                None => Some("No word fits".to_string()),
+            };
+        }
+        (_, None) => state.review = Some(review(state)),
+        (_, Some(review)) if !review.solver_page => review.solver_page = true,
+        (_, Some(_)) => state.review = None,
+    }
+}
+
+// Key only, the suggestion shows as a message and the review in its panel
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut hint: Element<AppState> = Element::new(0, 0, Look::new());
+
+    hint.on_keypress = Some(Box::new(|_el, state, event| {
+        if pressed(event, Action::Hint) {
+            keypress(state);
+        }
+    }));
+
+    hint
+}
@@ -3,16 +3,39 @@ use crate::tui_engine::*;
 
 use crate::AppState;
 
-// Maps letter status to background color
-fn status_to_ansi(status: u8) -> u8 {
-    match status {
-        2 => 2,
-        1 => 3,
-        _ => 8,
+// Cells of a key colored by each board: all of it for a single board,
+// the sides for two, the corners for four, the border around the letter for eight
+fn key_segments(boards: usize) -> Vec<Vec<(usize, usize)>> {
+    match boards {
+        1 => vec![(0..3).flat_map(|r| (0..3).map(move |c| (r, c))).collect()],
+        2 => vec![vec![(0, 0), (1, 0), (2, 0)], vec![(0, 2), (1, 2), (2, 2)]],
+        4 => vec![vec![(0, 0)], vec![(0, 2)], vec![(2, 0)], vec![(2, 2)]],
+        _ => (0..3)
+            .flat_map(|r| (0..3).map(move |c| (r, c)))
+            .filter(|&cell| cell != (1, 1))
+            .map(|cell| vec![cell])
+            .collect(),
     }
 }
 
-/// Creates a single key element (A–Z)
+// Best status of a letter in the guesses a board has seen, None when it saw none
+fn key_status(state: &AppState, board: usize, letter: char) -> Option<u8> {
+    let answer_arr: Vec<char> = board_answer(state, board).chars().collect();
+    let rows = solved_on(state, board).map_or(state.in_play, |row| row + 1);
+
+    let mut final_status = None;
+    for guess_arr in &state.status[..rows] {
+        for (i, &item) in guess_arr.iter().enumerate() {
+            if item == letter {
+                let letter_status = get_letter_status(i, guess_arr, &answer_arr);
+                final_status = final_status.max(Some(letter_status));
+            }
+        }
+    }
+    final_status
+}
+
+/// Creates a single key element, one per letter of the alphabet
 pub fn create_key<'a>(x: u16, y: u16, letter: char) -> Element<'a, AppState> {
     let look = Look::from(vec![
         vec!["┌", "─", "┐"],
@@ -28,34 +51,26 @@ pub fn create_key<'a>(x: u16, y: u16, letter: char) -> Element<'a, AppState> {
         }
     }));
     el.on_state = Some(Box::new(move |el, state: &AppState| {
+        let mut cells = vec![
+            glyphs("┌─┐", Style::default()),
+            glyphs(&format!("│{}│", letter), Style::default()),
+            glyphs("└─┘", Style::default()),
+        ];
+
+        // each board colors its own part of the key
         if state.used.contains(&letter) {
-            let mut final_status = 0;
-
-            for guess_arr in &state.status {
-                let answer_arr: Vec<char> = state.answer.chars().collect();
-                for (i, &item) in guess_arr.iter().enumerate() {
-                    if item == letter {
-                        let letter_status = get_letter_status(i, guess_arr, &answer_arr);
-                        if letter_status > final_status {
-                            final_status = letter_status;
-                        }
+            let segments = key_segments(state.answers.len());
+            for (board, segment) in (0..state.answers.len()).zip(&segments) {
+                if let Some(status) = key_status(state, board, letter) {
+                    for &(r, c) in segment {
+                        cells[r][c].style = cells[r][c].style.patch(state.theme.status(status));
                     }
                 }
             }
-
-            let styled =
-                terminal_style::format::background(status_to_ansi(final_status), &el.look).unwrap();
-
-            el.look.update(styled);
-        } else {
-            // letter not used
-            el.look.update(Look::from(vec![
-                vec!["┌", "─", "┐"],
-                vec!["│", &letter.to_string(), "│"],
-                vec!["└", "─", "┘"],
-            ]));
         }
 
+        el.look.update(cells);
+
         crate::elements::draw_relative(el, x, y, state);
     }));
 
@@ -65,22 +80,22 @@ pub fn create_key<'a>(x: u16, y: u16, letter: char) -> Element<'a, AppState> {
 static X: u16 = 40;
 static Y: u16 = 13;
 
-pub fn build<'a>() -> Elements<'a, AppState> {
-    // Layout rows
-    let keys: Vec<Vec<char>> = vec![
-        vec!['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
-        vec!['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L'],
-        vec!['Z', 'X', 'C', 'V', 'B', 'N', 'M'],
-    ];
+// Where a key of the layout goes, each row a little further right
+pub(crate) fn key_origin(row: usize, column: usize) -> (u16, u16) {
+    let row = row as u16;
+    (X + row * 2 + row / 2 + column as u16 * 3, Y + row * 3)
+}
 
+// `rows` from Layout::keyboard, a space leaves a gap
+pub fn build<'a>(rows: &[Vec<char>]) -> Elements<'a, AppState> {
     let kb = Elements::new();
 
-    for (line_index, key_line) in keys.iter().enumerate() {
-        for (index, &letter) in key_line.iter().enumerate() {
-            let x = X + line_index as u16 * 2 + (line_index as u16 / 2) + (index as u16 * 3);
-            let y = Y + line_index as u16 * 3;
-
-            kb.push(create_key(x, y, letter));
+    for (row, keys) in rows.iter().enumerate() {
+        for (column, &letter) in keys.iter().enumerate() {
+            if letter != ' ' {
+                let (x, y) = key_origin(row, column);
+                kb.push(create_key(x, y, letter));
+            }
         }
     }
 
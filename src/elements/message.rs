@@ -0,0 +1,20 @@
+use crate::tui_engine::*;
+use crate::AppState;
+
+static X: u16 = 9;
+static Y: u16 = 2;
+static WIDTH: usize = 20;
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut message: Element<AppState> = Element::new(0, 0, Look::new());
+
+    message.on_state = Some(Box::new(|el, state| {
+        // pad so a shorter message erases a longer one
+        let text = state.message.clone().unwrap_or_default();
+        el.look.update(format!("{:<width$}", text, width = WIDTH));
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    message
+}
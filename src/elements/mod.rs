@@ -1,29 +1,50 @@
 // --- Single and Vectors of Elements
 pub(crate) mod answer;
 pub(crate) mod board;
+pub(crate) mod browser;
+pub(crate) mod button_archive;
+pub(crate) mod button_daily;
 pub(crate) mod button_exit;
+pub(crate) mod button_hard;
 pub(crate) mod button_next;
+pub(crate) mod button_practice;
+pub(crate) mod button_share;
 pub(crate) mod centered_modal;
+pub(crate) mod completion;
 pub(crate) mod delete_key;
 pub(crate) mod enter_key;
 pub(crate) mod graphs;
+pub(crate) mod hint;
 pub(crate) mod keyboard;
+pub(crate) mod message;
 pub(crate) mod results;
+pub(crate) mod review;
 pub(crate) mod screen;
+pub(crate) mod theme_key;
+pub(crate) mod tiles;
 pub(crate) mod title_bar;
 
 // --- Helpers
 pub(crate) mod animators;
+pub(crate) mod grid;
 
 // --- Utility
 use crate::{
-    tui_engine::{columns, draw, rows, Element},
+    tui_engine::{columns, draw, glyphs, rows, Element, Glyph, Style},
     AppState,
 };
 
 pub(crate) static APP_WIDTH: u16 = 80; // Width of the game window
 pub(crate) static APP_HEIGHT: u16 = 24; // Height of the game window
 
+// A row of plain glyphs, padded to `width`
+pub(crate) fn text_row(text: &str, width: usize) -> Vec<Glyph> {
+    glyphs(
+        &format!("{:<width$}", text, width = width),
+        Style::default(),
+    )
+}
+
 pub(crate) fn draw_relative<S>(el: &Element<S>, x: u16, y: u16, state: &AppState) {
     if columns() >= APP_WIDTH && rows() >= APP_HEIGHT {
         el.x.set(state.app_x + x);
@@ -1,8 +1,20 @@
+use crate::calendar::today;
+use crate::language::language;
 use crate::tui_engine::*;
 use crate::AppState;
 
 static X: u16 = 40;
 static Y: u16 = 4;
+static WIDTH: usize = 17; // up to the graphs
+
+// a streak not extended yesterday or today is over
+fn daily_streak(state: &AppState) -> u32 {
+    let today = today();
+    match state.daily.last_won {
+        Some(day) if day + 1 >= today => state.daily.streak.0,
+        _ => 0,
+    }
+}
 
 pub fn build<'a>() -> Element<'a, AppState> {
     let mut results: Element<AppState> = Element::new(0, 0, Look::new());
@@ -12,17 +24,35 @@ pub fn build<'a>() -> Element<'a, AppState> {
         let p: u32 = r.iter().sum();
 
         let win_percent = if p > 0 {
-            (((p - r[6]) as f32 / p as f32) * 100.0).round() as u32
+            (((p - r[state.attempts]) as f32 / p as f32) * 100.0).round() as u32
         } else {
             0
         };
 
-        let look = vec![
-            vec![format!("Played: {}", p)],
-            vec![format!("Win %: {}", win_percent)],
-            vec![format!("Streak: {}  ", state.streak.0)],
-            vec![format!("Max Streak: {}", state.streak.1)],
+        let strings = &language().strings;
+        let lines = [
+            (&strings.played, p),
+            (&strings.win_percent, win_percent),
+            (&strings.streak, state.streak.0),
+            (&strings.max_streak, state.streak.1),
+            (&strings.hard_wins, state.hard_wins),
+            (&strings.daily_streak, daily_streak(state)),
         ];
+        // padded up to the graphs so a reset streak leaves no digits behind,
+        // a long label is cut short to keep a space before the graphs
+        let look: Vec<Vec<String>> = lines
+            .iter()
+            .map(|(label, n)| {
+                let n = n.to_string();
+                let room = WIDTH.saturating_sub(n.len() + 3);
+                let label: String = label.chars().take(room).collect();
+                vec![format!(
+                    "{:<width$}",
+                    format!("{}: {}", label, n),
+                    width = WIDTH
+                )]
+            })
+            .collect();
 
         el.look.update(look);
 
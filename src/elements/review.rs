@@ -0,0 +1,101 @@
+use crate::elements::text_row;
+use crate::keymap::{pressed, Action};
+use crate::solver::Rating;
+use crate::theme::Theme;
+use crate::tui_engine::*;
+use crate::{AppState, Step};
+
+// Covers the board up to the results
+static X: u16 = 9;
+static Y: u16 = 4;
+static WIDTH: usize = 30;
+static HEIGHT: usize = 18;
+
+fn rating_label(rating: Option<Rating>) -> &'static str {
+    match rating {
+        Some(Rating::Skillful) => "skillful",
+        Some(Rating::Lucky) => "lucky",
+        Some(Rating::Weak) => "weak",
+        None => "",
+    }
+}
+
+// "  1 CRANE    18  skillful", the letters colored like the board and the
+// wasted ones underlined
+fn step_row(number: usize, step: &Step, theme: &Theme) -> Vec<Glyph> {
+    let line = format!(
+        "{:>3} {} {:>5}  {}",
+        number,
+        step.guess,
+        step.left,
+        rating_label(step.rating)
+    );
+    let mut row = text_row(&line, WIDTH);
+    for (i, cell) in row[4..4 + step.pattern.len()].iter_mut().enumerate() {
+        cell.style = cell.style.patch(theme.status(step.pattern[i]));
+        if step.wasted[i] {
+            cell.style = cell.style.underline();
+        }
+    }
+    row
+}
+
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut review: Element<AppState> = Element::new(0, 0, Look::new());
+
+    review.on_keypress = Some(Box::new(|_el, state, event| {
+        if state.review.is_some() && pressed(event, Action::Close) {
+            state.review = None;
+        }
+    }));
+    review.on_state = Some(Box::new(|el, state| {
+        let Some(review) = &state.review else {
+            // the board draws over the panel once closed
+            el.look.update(Look::new());
+            return;
+        };
+
+        let (title, steps, legend, footer) = if review.solver_page {
+            (
+                format!("  Solver on {}", review.answer),
+                &review.solver,
+                "",
+                format!(
+                    "  {} or {} to close",
+                    state.keys.label(Action::Hint),
+                    state.keys.label(Action::Close)
+                ),
+            )
+        } else {
+            (
+                format!("  You on {}", review.answer),
+                &review.player,
+                "  underlined: told nothing",
+                format!(
+                    "  {} solver, {} to close",
+                    state.keys.label(Action::Hint),
+                    state.keys.label(Action::Close)
+                ),
+            )
+        };
+
+        // "left" over the counts, after the number and the guess
+        let heading = format!("{:>width$}", "left", width = 4 + state.letters + 6);
+        let mut rows = vec![
+            text_row(&title, WIDTH),
+            text_row("", WIDTH),
+            text_row(&heading, WIDTH),
+        ];
+        for (i, step) in steps.iter().enumerate() {
+            rows.push(step_row(i + 1, step, &state.theme));
+        }
+        rows.resize(HEIGHT - 2, text_row("", WIDTH));
+        rows.push(text_row(legend, WIDTH));
+        rows.push(text_row(&footer, WIDTH));
+        el.look.update(rows);
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    review
+}
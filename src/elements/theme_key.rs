@@ -0,0 +1,18 @@
+use crate::keymap::{pressed, Action};
+use crate::tui_engine::*;
+use crate::AppState;
+
+// The theme key (F2) goes through the themes for this session,
+// config.toml sets the one to start with and can add its own
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut theme_key: Element<AppState> = Element::new(0, 0, Look::new());
+
+    theme_key.on_keypress = Some(Box::new(|_el, state, event| {
+        if pressed(event, Action::Theme) {
+            state.theme = state.theme.next(&state.themes);
+            state.message = Some(format!("Theme: {}", state.theme.name));
+        }
+    }));
+
+    theme_key
+}
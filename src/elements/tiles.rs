@@ -0,0 +1,90 @@
+use crate::elements::grid::{Grid, TILE_GAP};
+use crate::game::{board_answer, get_letter_status, solved_on};
+use crate::tui_engine::*;
+use crate::{AppState, GameStatus};
+
+static X: u16 = 9;
+static Y: u16 = 4;
+
+// Rows of one board: guesses up to the one that solved it, or up to the
+// row in play, the latest ones when not all fit. A board left unsolved
+// at the end shows its answer in the last row.
+fn board_rows(state: &AppState, grid: &Grid, board: usize) -> Vec<Vec<Glyph>> {
+    let answer_arr: Vec<char> = board_answer(state, board).chars().collect();
+    let missed = state.game == GameStatus::Lost && solved_on(state, board).is_none();
+    let end = match solved_on(state, board) {
+        Some(row) => row + 1,
+        None if state.game == GameStatus::InPlay => state.in_play + 1,
+        None => state.in_play,
+    };
+    let shown = if missed {
+        grid.attempts - 1
+    } else {
+        grid.attempts
+    };
+    let first = end.saturating_sub(shown);
+
+    let mut rows = grid.blank();
+    for (r, guess_arr) in state.status[first..end].iter().enumerate() {
+        for (c, &letter) in guess_arr.iter().enumerate() {
+            let mut cell = grid.cell(letter);
+
+            // submitted guesses get their colors, the row in play stays plain
+            if first + r != state.in_play && letter != ' ' {
+                let letter_status = get_letter_status(c, guess_arr, &answer_arr);
+                for item in cell.iter_mut().flatten() {
+                    item.style = item.style.patch(state.theme.status(letter_status));
+                }
+            }
+
+            grid.paint(&mut rows, r, c, cell);
+        }
+    }
+
+    if missed {
+        for (c, &letter) in answer_arr.iter().enumerate() {
+            let mut cell = grid.cell(letter);
+            for item in cell.iter_mut().flatten() {
+                item.style = item.style.inverse();
+            }
+            grid.paint(&mut rows, end - first, c, cell);
+        }
+    }
+    rows
+}
+
+// Small boards side by side, drawn in place of the board when
+// several answers are played at once
+pub fn build<'a>() -> Element<'a, AppState> {
+    let mut tiles: Element<AppState> = Element::new(0, 0, Look::new());
+
+    tiles.on_state = Some(Box::new(|el, state| {
+        if state.answers.len() < 2 {
+            el.look.update(Look::new());
+            return;
+        }
+
+        let boards = state.answers.len();
+        let (grid, columns) = Grid::tile(state.letters, state.attempts, boards);
+        let tile_rows = boards.div_ceil(columns);
+        let width = columns * (grid.width() + TILE_GAP) - TILE_GAP;
+        let height = tile_rows * (grid.height() + 1) - 1;
+
+        let mut rows = vec![vec![Glyph::blank(); width]; height];
+        for board in 0..boards {
+            let top = board / columns * (grid.height() + 1);
+            let left = board % columns * (grid.width() + TILE_GAP);
+            for (dy, row) in board_rows(state, &grid, board).into_iter().enumerate() {
+                for (dx, item) in row.into_iter().enumerate() {
+                    rows[top + dy][left + dx] = item;
+                }
+            }
+        }
+
+        el.look.update(rows);
+
+        crate::elements::draw_relative(el, X, Y, state);
+    }));
+
+    tiles
+}
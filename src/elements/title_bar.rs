@@ -5,14 +5,14 @@ pub fn build<'a>() -> Element<'a, AppState> {
     let mut title_bar: Element<AppState> = Element::new(0, 0, Look::new());
 
     title_bar.on_state = Some(Box::new(move |el, _state| {
-        let cols = crossterm::terminal::size().unwrap().0 as usize;
+        let cols = columns() as usize;
 
         let mut line = " ".repeat(cols);
         let text = "Rewordle";
-        line.replace_range(0..text.len().min(cols), &text);
+        line.replace_range(0..text.len().min(cols), text);
 
         el.look
-            .update(vec![vec![terminal_style::format::inverse(&line)]]);
+            .update(Look::from(line).styled(Style::default().inverse()));
 
         draw(el);
     }));
@@ -1,62 +1,11 @@
 use std::{
     cell::RefCell,                           // Interior mutability for shared state
-    io::stdout,                              // Terminal output
     panic::{catch_unwind, AssertUnwindSafe}, // Safe panic handling
     rc::Rc,                                  // Shared ownership of state
     time::Duration,
 };
 
-use crossterm::{
-    cursor,
-    event::{
-        DisableMouseCapture, EnableMouseCapture, Event, EventStream, KeyCode, KeyEvent,
-        KeyModifiers, MouseButton, MouseEventKind,
-    },
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, ClearType},
-};
-
-use futures::{executor::block_on, future::FutureExt, StreamExt};
-use futures_timer::Delay;
-
-// -----------------------------
-// Key Mapping
-// -----------------------------
-
-/// Converts KeyModifiers into human-friendly string representation
-fn modifiers_map(modifiers: KeyModifiers) -> Vec<String> {
-    let mut result = Vec::new();
-    if modifiers.contains(KeyModifiers::CONTROL) {
-        result.push("ctrl".to_string());
-    }
-    if modifiers.contains(KeyModifiers::SHIFT) {
-        result.push("shift".to_string());
-    }
-    if modifiers.contains(KeyModifiers::ALT) {
-        result.push("alt".to_string());
-    }
-    if modifiers.contains(KeyModifiers::META) {
-        result.push("meta".to_string());
-    }
-    result
-}
-
-/// Converts raw crossterm KeyEvent into human-friendly string
-fn key_map(key: &KeyEvent) -> Option<String> {
-    use KeyCode::*;
-    match key.code {
-        Enter => Some("enter".into()),
-        Up => Some("up".into()),
-        Down => Some("down".into()),
-        Left => Some("left".into()),
-        Right => Some("right".into()),
-        Esc => Some("escape".into()),
-        Backspace => Some("delete".into()),
-        F(n @ 1..=12) => Some(format!("f{}", n)), // function keys
-        Char(c) => Some(c.to_string()),           // any typed char
-        _ => None,
-    }
-}
+use crate::backend::{self, Input}; // Terminal access
 
 // -----------------------------
 // Event Data
@@ -65,24 +14,79 @@ fn key_map(key: &KeyEvent) -> Option<String> {
 /// Structure passed to callbacks on each loop iteration or event
 #[derive(Clone, Debug, Default)]
 pub struct EventData {
-    pub loop_count: usize,      // Number of loop iterations
+    #[allow(dead_code)] // Available to callbacks, unused by rewordle itself
+    pub loop_count: usize, // Number of loop iterations
     pub key: Option<String>,    // Last pressed key
     pub modifiers: Vec<String>, // Pressed modifiers, e.g., ["ctrl", "shift"]
+    pub actions: Vec<String>,   // Actions the keymap binds to the pressed key
     pub x: Option<u16>,         // Mouse X coordinate if relevant
     pub y: Option<u16>,         // Mouse Y coordinate if relevant
 }
 
+// -----------------------------
+// Keymap
+// -----------------------------
+
+/// A key, with the modifiers held, bound to a named action
+#[derive(Clone, Debug, PartialEq)]
+pub struct Binding {
+    pub key: String,            // key as the backend names it, e.g. "w" or "escape"
+    pub modifiers: Vec<String>, // modifiers that must be held, e.g. ["ctrl"]
+    pub action: String,         // name of the action
+}
+
+/// Bindings looked up on every key press
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keymap {
+    pub bindings: Vec<Binding>,
+}
+
+impl Keymap {
+    /// Actions bound to the key of an event, in binding order. Letters match
+    /// either case and shift is ignored, it is part of the typed character.
+    pub fn actions(&self, event: &EventData) -> Vec<String> {
+        let Some(key) = event.key.as_deref() else {
+            return Vec::new();
+        };
+        let held = |m: &String| m != "shift";
+        let mut held_modifiers: Vec<&String> = event.modifiers.iter().filter(|m| held(m)).collect();
+        held_modifiers.sort();
+
+        self.bindings
+            .iter()
+            .filter(|binding| binding.key.to_lowercase() == key.to_lowercase())
+            .filter(|binding| {
+                let mut modifiers: Vec<&String> =
+                    binding.modifiers.iter().filter(|m| held(m)).collect();
+                modifiers.sort();
+                modifiers == held_modifiers
+            })
+            .map(|binding| binding.action.clone())
+            .collect()
+    }
+}
+
 // -----------------------------
 // Listener Struct
 // -----------------------------
 
+/// Hook receiving mutable state and the event that triggered it
+pub type EventHook<'a, S> = Box<dyn FnMut(&mut S, EventData) + 'a>;
+
+/// Hook receiving the state after it changed
+pub type StateHook<'a, S> = Box<dyn FnMut(&S) + 'a>;
+
+/// Hook called once per loop iteration, after all other callbacks
+pub type FrameHook<'a> = Box<dyn FnMut() + 'a>;
+
 /// A Listener contains callback hooks for different event types
 pub struct Listener<'a, S: 'a> {
-    pub on_loop: Box<dyn FnMut(&mut S, EventData) + 'a>,
-    pub on_keypress: Box<dyn FnMut(&mut S, EventData) + 'a>,
-    pub on_move: Box<dyn FnMut(&mut S, EventData) + 'a>,
-    pub on_click: Box<dyn FnMut(&mut S, EventData) + 'a>,
-    pub on_state: Box<dyn FnMut(&S) + 'a>,
+    pub on_loop: EventHook<'a, S>,
+    pub on_keypress: EventHook<'a, S>,
+    pub on_move: EventHook<'a, S>,
+    pub on_click: EventHook<'a, S>,
+    pub on_state: StateHook<'a, S>,
+    pub on_frame: FrameHook<'a>,
 }
 
 impl<'a, S: 'a> Listener<'a, S> {
@@ -93,6 +97,7 @@ impl<'a, S: 'a> Listener<'a, S> {
             on_move: Box::new(|_s, _| {}),
             on_click: Box::new(|_s, _| {}),
             on_state: Box::new(|_| {}),
+            on_frame: Box::new(|| {}),
         }
     }
 }
@@ -107,35 +112,14 @@ impl<'a, S: 'a> Default for Listener<'a, S> {
 // Terminal Setup / Teardown
 // -----------------------------
 
-/// Configure terminal for raw mode + mouse tracking
+/// Prepare the installed backend for drawing and input
 pub fn setup() {
-    enable_raw_mode().expect("Failed to enable raw mode");
-    let mut stdout = stdout();
-
-    execute!(
-        stdout,
-        cursor::Hide,         // hide cursor
-        cursor::MoveTo(0, 0), // reset cursor
-        EnableMouseCapture    // enable mouse events
-    )
-    .expect("Failed to initialize terminal");
+    backend::with(|b| b.setup());
 }
 
 /// Restore terminal to normal state
 fn teardown() {
-    let mut stdout = stdout();
-
-    execute!(
-        stdout,
-        crossterm::style::ResetColor,               // reset text color
-        cursor::Show,                               // show cursor
-        crossterm::terminal::Clear(ClearType::All), // clear screen
-        cursor::MoveTo(0, 0),                       // move cursor to top-left
-        DisableMouseCapture                         // disable mouse events
-    )
-    .expect("Failed to reset terminal");
-
-    disable_raw_mode().ok();
+    backend::with(|b| b.teardown());
 }
 
 // -----------------------------
@@ -146,10 +130,12 @@ fn teardown() {
 /// - `state`: initial application state
 /// - `listeners`: collection of callback hooks
 ///  - `tick_rate`: how often to tick when no events occur
+///  - `keymap`: actions to tag key events with
 pub fn start<'a, S>(
     state: S,
     listeners: &mut [Listener<'a, S>],
     tick_rate: Duration,
+    keymap: &Keymap,
     alt_exit: Option<&dyn Fn(&S) -> bool>,
 ) -> S
 where
@@ -165,7 +151,6 @@ where
 
     // Use Rc<RefCell> to allow interior mutability across loop and events
     let state = Rc::new(RefCell::new(state));
-    let mut events = EventStream::new();
 
     // Notify listeners of initial state
     // Will allow downstream users of event loop to draw initial UI
@@ -212,118 +197,90 @@ where
                 }
             }
 
+            // --- FRAME CALLBACK ---
+            // Every event continues back here, so this runs after each change
+            for l in listeners.iter_mut() {
+                (l.on_frame)();
+            }
+
             loop_count += 1;
 
             // --- EVENT HANDLING ---
-            let maybe_event: Option<Result<Event, std::io::Error>> = block_on(async {
-                futures::select! {
-                    event = events.next().fuse() => event,       // terminal event
-                    _ = Delay::new(tick_rate).fuse() => None,   // timeout → just return None
-                }
-            });
-
-            // Handle EOF gracefully
-            if let Some(event_result) = maybe_event {
-                match event_result {
-                    Ok(event) => match event {
-                        // ----- KEY EVENTS -----
-                        Event::Key(key_event) => {
-                            // Ctrl-C exits loop only if no alt_exit was provided
-                            if alt_exit.is_none()
-                                && key_event.code == KeyCode::Char('c')
-                                && key_event.modifiers.contains(KeyModifiers::CONTROL)
-                            {
-                                should_exit = true;
-                                continue;
-                            }
-
-                            let key = key_map(&key_event);
-                            let old_state = state.borrow().clone();
-
-                            for l in listeners.iter_mut() {
-                                let mut s = state.borrow_mut();
-                                if let Some(ref k) = key {
-                                    (l.on_keypress)(
-                                        &mut s,
-                                        EventData {
-                                            loop_count,
-                                            key: Some(k.clone()),
-                                            modifiers: modifiers_map(key_event.modifiers),
-                                            ..Default::default()
-                                        },
-                                    );
-                                }
-                            }
-
-                            // Notify if state changed
-                            let new_state = state.borrow();
-                            if *new_state != old_state {
-                                for l in listeners.iter_mut() {
-                                    (l.on_state)(&new_state);
-                                }
-                            }
-                            continue;
-                        }
-
-                        // ----- MOUSE EVENTS -----
-                        Event::Mouse(mouse_event) => {
-                            let pos = (mouse_event.column, mouse_event.row);
-                            let old_state = state.borrow().clone();
-
-                            for l in listeners.iter_mut() {
-                                let mut s = state.borrow_mut();
-                                // Trigger on_move ONLY when the mouse actually moved
-                                if matches!(mouse_event.kind, MouseEventKind::Moved) {
-                                    (l.on_move)(
-                                        &mut s,
-                                        EventData {
-                                            loop_count,
-                                            x: Some(pos.0),
-                                            y: Some(pos.1),
-                                            modifiers: modifiers_map(mouse_event.modifiers),
-                                            ..Default::default()
-                                        },
-                                    );
-                                }
-
-                                // Trigger on_click for left-up or drag
-                                if matches!(
-                                    mouse_event.kind,
-                                    MouseEventKind::Up(MouseButton::Left)
-                                        | MouseEventKind::Drag(MouseButton::Left)
-                                ) {
-                                    (l.on_click)(
-                                        &mut s,
-                                        EventData {
-                                            loop_count,
-                                            x: Some(pos.0),
-                                            y: Some(pos.1),
-                                            modifiers: modifiers_map(mouse_event.modifiers),
-                                            ..Default::default()
-                                        },
-                                    );
-                                }
-                            }
-
-                            // Notify if state changed
-                            let new_state = state.borrow();
-                            if *new_state != old_state {
-                                for l in listeners.iter_mut() {
-                                    (l.on_state)(&new_state);
-                                }
-                            }
-                            continue;
-                        }
-
-                        _ => {} // Ignore other events
-                    },
-                    Err(_) => {
-                        // Treat IO errors from EventStream as EOF → exit gracefully
+            let maybe_input = backend::with(|b| b.poll(tick_rate));
+
+            // If maybe_input is None, that means it was a tick → do nothing, loop continues
+            let Some(input) = maybe_input else {
+                continue;
+            };
+
+            let old_state = state.borrow().clone();
+
+            match input {
+                // ----- KEY EVENTS -----
+                Input::Key(event) => {
+                    // Ctrl-C exits loop only if no alt_exit was provided
+                    if alt_exit.is_none()
+                        && event.key.as_deref() == Some("c")
+                        && event.modifiers.iter().any(|m| m == "ctrl")
+                    {
                         should_exit = true;
+                        continue;
+                    }
+
+                    let actions = keymap.actions(&event);
+                    for l in listeners.iter_mut() {
+                        let mut s = state.borrow_mut();
+                        (l.on_keypress)(
+                            &mut s,
+                            EventData {
+                                loop_count,
+                                actions: actions.clone(),
+                                ..event.clone()
+                            },
+                        );
                     }
                 }
+
+                // ----- MOUSE EVENTS -----
+                Input::Move(event) => {
+                    for l in listeners.iter_mut() {
+                        let mut s = state.borrow_mut();
+                        (l.on_move)(
+                            &mut s,
+                            EventData {
+                                loop_count,
+                                ..event.clone()
+                            },
+                        );
+                    }
+                }
+                Input::Click(event) => {
+                    for l in listeners.iter_mut() {
+                        let mut s = state.borrow_mut();
+                        (l.on_click)(
+                            &mut s,
+                            EventData {
+                                loop_count,
+                                ..event.clone()
+                            },
+                        );
+                    }
+                }
+
+                // Input closed (EOF or IO error) → exit gracefully
+                Input::Eof => {
+                    should_exit = true;
+                    continue;
+                }
+            }
+
+            // Notify if state changed
+            let new_state = state.borrow();
+            if *new_state != old_state {
+                for l in listeners.iter_mut() {
+                    (l.on_state)(&new_state);
+                }
             }
-            // If maybe_event is None, that means it was a tick → do nothing, loop continues
         }
     }));
 
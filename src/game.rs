@@ -1,12 +1,83 @@
-use crate::words::{PLAY_WORDS, VALID_WORDS};
-use crate::{AppState, GameStatus, WordStatus};
+use std::cmp::Reverse;
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hasher};
 
-// Determines letter status based on Wordle rules
-fn wordle_highlight(guess_arr: &[char], answer_arr: &[char]) -> Vec<u8> {
+use crate::calendar::today;
+use crate::history::{self, now};
+use crate::keymap::Action;
+use crate::language::{language, Language};
+use crate::words::{is_valid, play_words, LETTERS, PLAY_WORDS};
+use crate::{AppState, GameStatus, Mode, WordStatus};
+
+pub static ATTEMPTS: usize = 6; // guesses of a Wordle game
+pub static MIN_ATTEMPTS: usize = 6;
+pub static MAX_ATTEMPTS: usize = 10;
+pub static BOARDS: &[usize] = &[1, 2, 4, 8]; // answers played at once
+pub static ABSURDLE_ROWS: usize = 18; // guesses an absurdle board has room for, packed
+
+// Games of several boards get five guesses more than boards
+pub(crate) fn board_attempts(boards: usize) -> usize {
+    boards + 5
+}
+
+// Name of a game of several boards
+pub(crate) fn boards_name(boards: usize) -> &'static str {
+    match boards {
+        2 => "Dordle",
+        4 => "Quordle",
+        _ => "Octordle",
+    }
+}
+
+// Only single boards of five letter English words follow the archive,
+// everything else draws its words at random
+pub(crate) fn plays_archive(letters: usize, boards: usize) -> bool {
+    letters == LETTERS && boards == 1 && language().has_archive()
+}
+
+// Mode of the counted games of a board size, the archive in order or the
+// words of sizes without one at random
+pub(crate) fn main_mode(letters: usize, boards: usize) -> Mode {
+    if plays_archive(letters, boards) {
+        Mode::Archive
+    } else {
+        Mode::Shuffle
+    }
+}
+
+// The answer of a board
+pub(crate) fn board_answer(state: &AppState, board: usize) -> &'static str {
+    play_words(state.letters)[state.answers[board]]
+}
+
+// Attempt a board was solved on, among the submitted guesses
+pub(crate) fn solved_on(state: &AppState, board: usize) -> Option<usize> {
+    let answer = board_answer(state, board);
+    state.status[..state.in_play]
+        .iter()
+        .position(|row| language().same(&row.iter().collect::<String>(), answer))
+}
+
+// Determines letter status based on Wordle rules, a letter with a fold
+// matches the letter it folds to
+pub(crate) fn wordle_highlight(guess_arr: &[char], answer_arr: &[char]) -> Vec<u8> {
+    highlight_in(language(), guess_arr, answer_arr)
+}
+
+// The same with the folds of `language`
+pub(crate) fn highlight_in(
+    language: &Language,
+    guess_arr: &[char],
+    answer_arr: &[char],
+) -> Vec<u8> {
     const EXACT: u8 = 2;
     const EXISTS: u8 = 1;
     const DOES_NOT_EXIST: u8 = 0;
 
+    let guess_arr: Vec<char> = guess_arr.iter().map(|&c| language.fold(c)).collect();
+    let answer_arr: Vec<char> = answer_arr.iter().map(|&c| language.fold(c)).collect();
+
     // ----- STEP 1: "Punch" correct matches out of answer_map -----
     let mut answer_map: Vec<char> = answer_arr
         .iter()
@@ -51,69 +122,214 @@ pub(crate) fn get_letter_status(pos: usize, guess_arr: &[char], answer_arr: &[ch
         .unwrap_or(0)
 }
 
-fn is_valid_key(key: &str) -> bool {
-    key.len() == 1 && key.chars().all(|c| c.is_ascii_alphabetic())
+// Ordinal position name used in hard mode messages
+fn ordinal(n: usize) -> String {
+    match n {
+        1 => "1st".to_string(),
+        2 => "2nd".to_string(),
+        3 => "3rd".to_string(),
+        _ => format!("{}th", n),
+    }
 }
 
-pub(crate) fn mutate_state_letter(state: &mut AppState, keypress: &str) {
-    match state.game {
-        GameStatus::InPlay => {
-            let line_in_play = &mut state.status[state.in_play];
-            let index = line_in_play.iter().position(|&c| c == ' ');
-            let uppercase_keypress = keypress.to_uppercase();
-
-            if is_valid_key(&uppercase_keypress) && index.is_some() {
-                // Insert letter
-                let idx = index.unwrap();
-                line_in_play[idx] = uppercase_keypress.chars().next().unwrap();
-            } else if keypress == "enter" && index.is_none() {
-                // Enter pressed and line is full
-                let word: String = line_in_play.iter().collect();
-                if !VALID_WORDS.contains(&word.as_str()) && !PLAY_WORDS.contains(&word.as_str()) {
-                    state.word_status = WordStatus::Invalid;
-                    return;
-                } else {
-                    state.word_status = WordStatus::Valid;
-                    return;
+// In hard mode a guess must keep green letters in place and include every
+// revealed yellow. Returns a message naming the first broken constraint.
+fn hard_mode_violation(state: &AppState, guess: &[char]) -> Option<String> {
+    if !state.hard_mode {
+        return None;
+    }
+
+    for board in 0..state.answers.len() {
+        let answer_arr: Vec<char> = board_answer(state, board).chars().collect();
+        // the hints of a solved board no longer bind
+        let rows = solved_on(state, board).unwrap_or(state.in_play);
+
+        for row in &state.status[..rows] {
+            let highlight = wordle_highlight(row, &answer_arr);
+
+            // greens first, they are the most specific hint
+            for (i, &ch) in row.iter().enumerate() {
+                if highlight[i] == 2 && guess[i] != ch {
+                    return Some(format!("{} letter must be {}", ordinal(i + 1), ch));
                 }
-            } else if keypress == "delete" && line_in_play[0] != ' ' {
-                // Delete last letter
-                let idx = index.unwrap_or(5);
-                if idx > 0 {
-                    line_in_play[idx - 1] = ' ';
-                } else {
-                    line_in_play[0] = ' ';
+            }
+
+            // every revealed letter must appear at least as often as it was revealed
+            for &ch in row {
+                let revealed = row
+                    .iter()
+                    .zip(&highlight)
+                    .filter(|(&c, &h)| c == ch && h > 0)
+                    .count();
+                let guessed = guess.iter().filter(|&&c| c == ch).count();
+                if guessed < revealed {
+                    return Some(format!("Guess must contain {}", ch));
                 }
             }
-            // Any other key is ignored
         }
-        _ => {
-            if keypress == "enter" {
-                mutate_state_new_game(state);
+    }
+
+    None
+}
+
+// The completion screen, the browser and the review take their own keys
+fn covered(state: &AppState) -> bool {
+    state.complete || state.browser.is_some() || state.review.is_some()
+}
+
+// Types a letter into the row in play, anything else is ignored
+pub(crate) fn mutate_state_letter(state: &mut AppState, keypress: &str) {
+    // input is ignored while a submitted word is being revealed
+    if covered(state) || state.game != GameStatus::InPlay || state.word_status != WordStatus::InPlay
+    {
+        return;
+    }
+
+    let line_in_play = &mut state.status[state.in_play];
+    let index = line_in_play.iter().position(|&c| c == ' ');
+
+    // a letter of the alphabet, typed in either case
+    if let (Some(letter), Some(idx)) = (language().letter(keypress), index) {
+        line_in_play[idx] = letter;
+        state.message = None;
+    }
+}
+
+// Acts on the row in play, or moves on once the game is over
+pub(crate) fn mutate_state_action(state: &mut AppState, action: Action) {
+    if covered(state) {
+        return;
+    }
+
+    match (&state.game, action) {
+        (GameStatus::InPlay, _) if state.word_status != WordStatus::InPlay => {}
+        (GameStatus::InPlay, Action::Submit) => {
+            let guess = state.status[state.in_play].clone();
+            // only a full line is checked
+            if guess.contains(&' ') {
+                return;
+            }
+            let word: String = guess.iter().collect();
+            if !is_valid(&word) {
+                state.word_status = WordStatus::Invalid;
+            } else if let Some(message) = hard_mode_violation(state, &guess) {
+                state.word_status = WordStatus::Invalid;
+                state.message = Some(message);
+            } else {
+                state.word_status = WordStatus::Valid;
+                // absurdle picks its answer before the reveal shows it
+                if state.mode == Mode::Absurdle {
+                    narrow_candidates(state, &guess);
+                }
+            }
+        }
+        (GameStatus::InPlay, Action::DeleteLetter) => {
+            let line_in_play = &mut state.status[state.in_play];
+            if let Some(idx) = line_in_play.iter().rposition(|&c| c != ' ') {
+                line_in_play[idx] = ' ';
+                state.message = None;
+            }
+        }
+        (GameStatus::InPlay, Action::ClearRow) => {
+            let line_in_play = &mut state.status[state.in_play];
+            if line_in_play.iter().any(|&c| c != ' ') {
+                line_in_play.fill(' ');
+                state.message = None;
             }
         }
+        (GameStatus::Won | GameStatus::Lost, Action::NewGame) => mutate_state_new_game(state),
+        // the other actions belong to their own elements
+        _ => {}
+    }
+}
+
+// Practice, picked, daily and absurdle games stay out of results and streak,
+// and so do replays of missed words, their first game was counted
+fn counted(mode: Mode) -> bool {
+    !matches!(
+        mode,
+        Mode::Practice | Mode::Pick | Mode::Daily | Mode::Absurdle | Mode::Missed
+    )
+}
+
+// A loss ends the streak, and the archive moves on to its next word
+fn lose(state: &mut AppState) {
+    if state.mode == Mode::Archive && plays_archive(state.letters, state.boards) {
+        state.word_index += 1;
+    }
+    if counted(state.mode) {
+        state.results[state.attempts] += 1;
+        state.streak.0 = 0;
+    }
+    if state.mode == Mode::Daily {
+        state.daily.streak.0 = 0;
+    }
+    state.game = GameStatus::Lost;
+}
+
+// A counted game left half played is lost, history included, so starting
+// over is no way around a bad start
+pub(crate) fn mutate_state_forfeit(state: &mut AppState) {
+    if state.game != GameStatus::InPlay || state.in_play == 0 || !counted(state.mode) {
+        return;
+    }
+    lose(state);
+    if let Some(record) = history::GameRecord::from_state(state) {
+        history::append(&record).ok();
     }
 }
 
 pub(crate) fn mutate_state_new_word(state: &mut AppState) {
-    let line_in_play = &mut state.status[state.in_play]; // now mutable borrow is fine
+    let line_in_play = state.status[state.in_play].clone();
     let word: String = line_in_play.iter().collect();
+    let counted = counted(state.mode);
+    if state.mode == Mode::Daily {
+        state.daily.guesses.push(word.clone());
+    }
+
+    // only archive games move the progression on
+    let advances = state.mode == Mode::Archive && plays_archive(state.letters, state.boards);
+    // won once every board is solved, by this word or an earlier one
+    let won = (0..state.answers.len())
+        .all(|b| solved_on(state, b).is_some() || language().same(board_answer(state, b), &word));
 
-    if &word == state.answer {
+    if won {
         // Win condition
-        state.word_index += 1;
-        state.results[state.in_play] += 1;
-        state.streak.0 += 1;
-        if state.streak.0 > state.streak.1 {
-            state.streak.1 = state.streak.0;
+        if advances {
+            state.word_index += 1;
+        }
+        if counted {
+            state.results[state.in_play] += 1;
+            if state.hard_mode {
+                state.hard_wins += 1;
+            }
+            state.streak.0 += 1;
+            if state.streak.0 > state.streak.1 {
+                state.streak.1 = state.streak.0;
+            }
+        }
+        if state.mode == Mode::Daily {
+            // the daily streak counts days in a row
+            let daily = &mut state.daily;
+            let day = daily.day.unwrap_or(0);
+            let yesterday = day.checked_sub(1);
+            daily.streak.0 = if yesterday.is_some() && daily.last_won == yesterday {
+                daily.streak.0 + 1
+            } else {
+                1
+            };
+            daily.streak.1 = daily.streak.1.max(daily.streak.0);
+            daily.last_won = Some(day);
         }
         state.game = GameStatus::Won;
-    } else if state.in_play == 5 {
+    } else if state.mode == Mode::Absurdle && state.in_play + 1 < ABSURDLE_ROWS {
+        // absurdle goes on until its word is found, the board grows a row at a time
+        if state.in_play + 1 == state.status.len() {
+            state.status.push(vec![' '; state.letters]);
+        }
+    } else if state.in_play + 1 == state.status.len() {
         // Last attempt, lost
-        state.word_index += 1;
-        state.results[6] += 1;
-        state.streak.0 = 0;
-        state.game = GameStatus::Lost;
+        lose(state);
     }
 
     // Update used letters
@@ -126,10 +342,211 @@ pub(crate) fn mutate_state_new_word(state: &mut AppState) {
     state.in_play += 1;
 }
 
+// A fresh seed from std's per-process random keys
+pub(crate) fn random_seed() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(now());
+    hasher.finish()
+}
+
+// The n-th index of the practice set of `seed` (splitmix64) in a list of `len` words,
+// the same seed always gives the same words
+fn practice_index(seed: u64, n: u64, len: usize) -> usize {
+    let mut z = seed.wrapping_add(n.wrapping_add(1).wrapping_mul(0x9E3779B97F4A7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    ((z ^ (z >> 31)) % len as u64) as usize
+}
+
+// The next word of the practice seed, in the words of the current length
+fn draw_word(state: &mut AppState) -> usize {
+    let seed = *state.practice_seed.get_or_insert_with(random_seed);
+    state.practice_games += 1;
+    practice_index(
+        seed,
+        state.practice_games - 1,
+        play_words(state.letters).len(),
+    )
+}
+
+// Absurdle keeps the largest group of candidates that give the guess the
+// same pattern, and answers with one of them. All the candidates left give
+// every guess so far the same patterns, so any of them draws the same board.
+fn narrow_candidates(state: &mut AppState, guess: &[char]) {
+    let words = play_words(state.letters);
+    let mut groups: BTreeMap<Vec<u8>, Vec<usize>> = BTreeMap::new();
+    for &index in &state.candidates {
+        let answer_arr: Vec<char> = words[index].chars().collect();
+        groups
+            .entry(wordle_highlight(guess, &answer_arr))
+            .or_default()
+            .push(index);
+    }
+
+    // a win only when nothing else is left, then the most words, then the fewest hints
+    let solved = vec![2; guess.len()];
+    let (_, kept) = groups
+        .into_iter()
+        .max_by_key(|(pattern, group)| {
+            let hints: u32 = pattern.iter().map(|&s| s as u32).sum();
+            (*pattern != solved, group.len(), Reverse(hints))
+        })
+        .unwrap();
+
+    state.candidates = kept;
+    state.answer_index = state.candidates[0];
+    state.answer = words[state.answer_index];
+    state.answers = vec![state.answer_index];
+}
+
+// Index of the next answer for the mode, None once there is nothing left to play
+fn next_answer(state: &mut AppState) -> Option<usize> {
+    if let Some(index) = state.pick.take() {
+        state.mode = Mode::Pick;
+        return Some(index);
+    }
+    // today's word was played, the progression carries on where it was
+    if state.mode == Mode::Daily && state.game != GameStatus::InPlay {
+        state.mode = main_mode(state.letters, state.boards);
+    }
+
+    match state.mode {
+        Mode::Archive => Some(state.word_index).filter(|&i| i < PLAY_WORDS.len()),
+        // other board sizes have no archive, their words are drawn like practice ones
+        Mode::Shuffle | Mode::Practice => Some(draw_word(state)),
+        Mode::Missed => (!state.missed.is_empty()).then(|| state.missed.remove(0)),
+        // every word is possible until the guesses say otherwise
+        Mode::Absurdle => {
+            state.candidates = (0..play_words(state.letters).len()).collect();
+            Some(0)
+        }
+        Mode::Daily => {
+            // a new day, a new board
+            let day = today();
+            if state.daily.day != Some(day) {
+                state.daily.day = Some(day);
+                state.daily.guesses.clear();
+            }
+            Some(day % PLAY_WORDS.len())
+        }
+        // a picked word was played, back to the progression where it was
+        Mode::Pick => {
+            state.mode = main_mode(state.letters, state.boards);
+            next_answer(state)
+        }
+    }
+}
+
 pub(crate) fn mutate_state_new_game(state: &mut AppState) {
+    let next = next_answer(state); // looks at how the last game ended
     state.game = GameStatus::InPlay; // Reset game status
-    state.answer = PLAY_WORDS[state.word_index]; // Pick next answer
+    match next {
+        Some(index) => {
+            state.answer_index = index;
+            state.answer = play_words(state.letters)[index]; // Pick next answer
+            state.answers = vec![index];
+            // the other boards draw answers of their own, all different
+            while state.answers.len() < state.boards {
+                let other = draw_word(state);
+                if !state.answers.contains(&other) {
+                    state.answers.push(other);
+                }
+            }
+            state.complete = false;
+        }
+        None => {
+            // out of words, offer the ways to go on
+            state.answer = "";
+            state.answers = Vec::new();
+            state.complete = true;
+            state.missed = history::missed();
+        }
+    }
     state.in_play = 0; // Reset attempt index
-    state.status = vec![vec![' '; 5]; 6]; // Empty 6x5 board
+    state.status = vec![vec![' '; state.letters]; state.attempts]; // Empty board
     state.used = Vec::new(); // Clear used letters
+    state.started_at = now(); // Time the new game
+    state.message = None; // Clear hard mode message
+    state.review = None; // Close the review of the last game
+
+    // today's board as it was left, finished or not
+    if state.mode == Mode::Daily {
+        let guesses = state.daily.guesses.clone();
+        mutate_state_restore(state, &guesses);
+    }
+}
+
+// Puts saved guesses back on an empty board
+pub(crate) fn mutate_state_restore(state: &mut AppState, guesses: &[String]) {
+    // absurdle candidates follow from the guesses, the same ones every time
+    if state.mode == Mode::Absurdle {
+        state.candidates = (0..play_words(state.letters).len()).collect();
+        for guess in guesses {
+            narrow_candidates(state, &guess.chars().collect::<Vec<char>>());
+        }
+    }
+    // and the board as many rows as were guessed, and one for the next guess
+    if state.mode == Mode::Absurdle {
+        let rows = (guesses.len() + 1).clamp(state.attempts, ABSURDLE_ROWS);
+        state.status.resize(rows, vec![' '; state.letters]);
+    }
+    for (row, guess) in state.status.iter_mut().zip(guesses) {
+        *row = guess.chars().collect();
+        for ch in guess.chars() {
+            if !state.used.contains(&ch) {
+                state.used.push(ch);
+            }
+        }
+    }
+    state.in_play = guesses.len().min(state.status.len());
+
+    let won = (0..state.answers.len()).all(|b| solved_on(state, b).is_some());
+    if won && !guesses.is_empty() {
+        state.game = GameStatus::Won;
+    } else if guesses.len() >= state.status.len() {
+        state.game = GameStatus::Lost;
+    }
+}
+
+// Leaves the completion screen for the chosen mode
+pub(crate) fn mutate_state_choose_mode(state: &mut AppState, mode: Mode) {
+    match mode {
+        Mode::Archive => {
+            // second pass, results and streak carry on
+            state.word_index = 0;
+            state.passes += 1;
+        }
+        Mode::Missed if state.missed.is_empty() => return,
+        _ => {}
+    }
+
+    state.mode = mode;
+    mutate_state_new_game(state);
+}
+
+// Switches between practice and the counted games, abandoning the board
+pub(crate) fn mutate_state_toggle_practice(state: &mut AppState) {
+    state.mode = if state.mode == Mode::Practice {
+        main_mode(state.letters, state.boards)
+    } else {
+        Mode::Practice
+    };
+    mutate_state_new_game(state);
+}
+
+// Plays a word chosen in the archive browser, word_index stays where it is
+pub(crate) fn mutate_state_pick(state: &mut AppState, index: usize) {
+    state.pick = Some(index);
+    state.browser = None;
+    mutate_state_new_game(state);
+}
+
+// Switches between today's word and the archive
+pub(crate) fn mutate_state_toggle_daily(state: &mut AppState) {
+    state.mode = if state.mode == Mode::Daily {
+        main_mode(state.letters, state.boards)
+    } else {
+        Mode::Daily
+    };
+    mutate_state_new_game(state);
 }
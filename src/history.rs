@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::game::{board_answer, solved_on, wordle_highlight};
+use crate::language::language;
+use crate::storage::{default_attempts, default_letters, save_path};
+use crate::words::{LETTERS, PLAY_WORDS};
+use crate::{AppState, GameStatus, Mode};
+
+// -----------------------------
+// Game Record
+// -----------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Won,
+    Lost,
+}
+
+// One finished game, a line of the history log
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub word_index: usize,     // index into the words of its length
+    pub answer: String,        // the correct answer
+    pub guesses: Vec<String>,  // every submitted guess, in order
+    pub patterns: Vec<String>, // highlight per guess, e.g. "20100" (2 exact, 1 exists), one board only
+    pub outcome: Outcome,
+    pub hard_mode: bool,
+    pub finished_at: u64, // unix time, seconds
+    pub duration: u64,    // seconds from first sight of the board to the end
+    #[serde(default)]
+    pub practice: bool, // not counted in results and streak
+    #[serde(default = "default_letters")]
+    pub letters: usize,
+    #[serde(default = "default_attempts")]
+    pub attempts: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub answers: Vec<String>, // every answer of a game of several boards
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub board_patterns: Vec<Vec<String>>, // patterns of each of several boards, up to its solve
+    #[serde(default)]
+    pub absurdle: bool, // answered by whichever word held out longest, not counted
+    #[serde(default)]
+    pub daily: bool, // the word of the day, not counted
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub word_list: String, // language the words came from, empty for English
+}
+
+impl GameRecord {
+    // None while the game is still in play
+    pub fn from_state(state: &AppState) -> Option<Self> {
+        let outcome = match state.game {
+            GameStatus::Won => Outcome::Won,
+            GameStatus::Lost => Outcome::Lost,
+            GameStatus::InPlay => return None,
+        };
+
+        let rows = &state.status[..state.in_play];
+        let finished_at = now();
+
+        // highlight of each row against an answer, as "20100"
+        let patterns = |answer: &str, rows: &[Vec<char>]| -> Vec<String> {
+            let answer_arr: Vec<char> = answer.chars().collect();
+            rows.iter()
+                .map(|row| {
+                    wordle_highlight(row, &answer_arr)
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect()
+                })
+                .collect()
+        };
+
+        Some(Self {
+            word_index: state.answer_index,
+            answer: state.answer.to_string(),
+            guesses: rows.iter().map(|row| row.iter().collect()).collect(),
+            patterns: match state.boards {
+                1 => patterns(state.answer, rows),
+                _ => Vec::new(),
+            },
+            outcome,
+            hard_mode: state.hard_mode,
+            finished_at,
+            duration: finished_at.saturating_sub(state.started_at),
+            practice: state.mode == Mode::Practice,
+            letters: state.letters,
+            attempts: state.attempts,
+            answers: match state.boards {
+                1 => Vec::new(),
+                _ => (0..state.boards)
+                    .map(|b| board_answer(state, b).to_string())
+                    .collect(),
+            },
+            // a solved board takes no more guesses
+            board_patterns: match state.boards {
+                1 => Vec::new(),
+                _ => (0..state.boards)
+                    .map(|b| {
+                        let end = solved_on(state, b).map_or(rows.len(), |row| row + 1);
+                        patterns(board_answer(state, b), &rows[..end])
+                    })
+                    .collect(),
+            },
+            absurdle: state.mode == Mode::Absurdle,
+            daily: state.mode == Mode::Daily,
+            word_list: language().word_list().to_string(),
+        })
+    }
+}
+
+// -----------------------------
+// Log
+// -----------------------------
+
+// Seconds since the unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// The log sits next to the save file
+pub fn history_path() -> PathBuf {
+    save_path().with_file_name("history.jsonl")
+}
+
+// Appends one JSON line per game, earlier lines are never rewritten
+pub fn append(record: &GameRecord) -> std::io::Result<()> {
+    let path = history_path();
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        create_dir_all(dir)?;
+    }
+
+    let line = serde_json::to_string(record)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+// Every recorded game, oldest first, no log is an empty history
+pub fn read() -> Result<Vec<GameRecord>, String> {
+    let path = history_path();
+    let content = match read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("{} could not be read: {}", path.display(), e)),
+    };
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(n, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| format!("{} line {} is malformed: {}", path.display(), n + 1, e))
+        })
+        .collect()
+}
+
+// Games of the archive words, single boards of five letter English words
+// played in order, picked or missed, not practice, daily or absurdle ones
+fn archive_records() -> Vec<GameRecord> {
+    read()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|r| {
+            !r.practice
+                && !r.absurdle
+                && !r.daily
+                && r.letters == LETTERS
+                && r.answers.is_empty()
+                && r.word_list.is_empty()
+        })
+        .collect()
+}
+
+// Words whose latest counted game was lost, oldest loss first
+// An unreadable log has no missed words
+pub fn missed() -> Vec<usize> {
+    let mut missed: Vec<usize> = Vec::new();
+    for record in archive_records() {
+        missed.retain(|&i| i != record.word_index);
+        if record.outcome == Outcome::Lost {
+            missed.push(record.word_index);
+        }
+    }
+    missed
+}
+
+// Latest counted outcome of every word, None for words never finished
+pub fn outcomes() -> Vec<Option<Outcome>> {
+    let mut outcomes = vec![None; PLAY_WORDS.len()];
+    for record in archive_records() {
+        if let Some(outcome) = outcomes.get_mut(record.word_index) {
+            *outcome = Some(record.outcome);
+        }
+    }
+    outcomes
+}
@@ -0,0 +1,198 @@
+use crate::tui_engine::{Binding, EventData, Keymap};
+
+// -----------------------------
+// Actions
+// -----------------------------
+// What a key does besides typing a letter. The event loop tags each key
+// press with the actions bound to it, the elements act on the ones that
+// apply at the time, so one key can clear the row in play and close a panel.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Submit,       // check the guess in play
+    DeleteLetter, // take back the last letter typed
+    ClearRow,     // take back every letter typed
+    NewGame,      // go on to the next word once the game is over
+    Quit,         // leave the game
+    Hint,         // suggest a guess, or review a finished game
+    Theme,        // switch to the next theme
+    Share,        // copy the share grid of a finished game
+    Archive,      // open and close the archive browser
+    Close,        // close the browser or the review
+}
+
+// Every action, in the order the config documents them
+pub static ACTIONS: &[Action] = &[
+    Action::Submit,
+    Action::DeleteLetter,
+    Action::ClearRow,
+    Action::NewGame,
+    Action::Quit,
+    Action::Hint,
+    Action::Theme,
+    Action::Share,
+    Action::Archive,
+    Action::Close,
+];
+
+// Keys that are not a typed character, as the backend names them
+pub static NAMED_KEYS: &[&str] = &[
+    "enter", "delete", "escape", "tab", "up", "down", "left", "right",
+];
+
+// Modifiers a key can be bound with, "ctrl-w"
+pub static MODIFIERS: &[&str] = &["ctrl", "alt"];
+
+impl Action {
+    // Name in the [keys] section of the config
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Submit => "submit",
+            Action::DeleteLetter => "delete_letter",
+            Action::ClearRow => "clear_row",
+            Action::NewGame => "new_game",
+            Action::Quit => "quit",
+            Action::Hint => "hint",
+            Action::Theme => "theme",
+            Action::Share => "share",
+            Action::Archive => "archive",
+            Action::Close => "close",
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Action> {
+        ACTIONS.iter().copied().find(|action| action.name() == name)
+    }
+
+    // Works while a guess is typed, so a plain letter would be typed instead
+    pub fn typing(self) -> bool {
+        !matches!(self, Action::NewGame | Action::Share | Action::Close)
+    }
+
+    fn defaults(self) -> &'static [&'static str] {
+        match self {
+            Action::Submit => &["enter"],
+            Action::DeleteLetter => &["delete", "ctrl-h"],
+            Action::ClearRow => &["ctrl-w", "escape"],
+            Action::NewGame => &["enter"],
+            Action::Quit => &["ctrl-c"],
+            Action::Hint => &["?"],
+            Action::Theme => &["f2"],
+            Action::Share => &["s"],
+            Action::Archive => &["tab"],
+            Action::Close => &["escape"],
+        }
+    }
+}
+
+// Whether the key of an event is bound to the action
+pub fn pressed(event: &EventData, action: Action) -> bool {
+    event.actions.iter().any(|name| name == action.name())
+}
+
+// -----------------------------
+// Keys
+// -----------------------------
+
+// Splits "ctrl-w" into the key and its modifiers, None when it is not a key:
+// a single character, f1 to f12 or one of NAMED_KEYS
+pub fn chord(text: &str) -> Option<(String, Vec<String>)> {
+    let mut rest = text;
+    let mut modifiers = Vec::new();
+    while rest.chars().count() > 1 {
+        let Some((modifier, key)) = rest.split_once('-') else {
+            break;
+        };
+        let modifier = modifier.to_lowercase();
+        if !MODIFIERS.contains(&modifier.as_str()) {
+            return None;
+        }
+        modifiers.push(modifier);
+        rest = key;
+    }
+
+    let key = match rest.chars().count() {
+        1 => rest.to_string(),
+        _ => rest.to_lowercase(),
+    };
+    let function_key = key
+        .strip_prefix('f')
+        .and_then(|n| n.parse::<u8>().ok())
+        .is_some_and(|n| (1..=12).contains(&n));
+
+    if key.chars().count() == 1 || NAMED_KEYS.contains(&key.as_str()) || function_key {
+        Some((key, modifiers))
+    } else {
+        None
+    }
+}
+
+// Keys bound to each action, as written in the config
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keys {
+    bound: Vec<(Action, Vec<String>)>,
+}
+
+impl Default for Keys {
+    fn default() -> Self {
+        Self {
+            bound: ACTIONS
+                .iter()
+                .map(|&action| {
+                    let keys = action.defaults().iter().map(|k| k.to_string()).collect();
+                    (action, keys)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Keys {
+    pub fn bind(&mut self, action: Action, keys: Vec<String>) {
+        if let Some(entry) = self.bound.iter_mut().find(|(a, _)| *a == action) {
+            entry.1 = keys;
+        }
+    }
+
+    // How the first key of the action is named on screen, e.g. "esc" or "F2"
+    pub fn label(&self, action: Action) -> String {
+        let key = self
+            .bound
+            .iter()
+            .find(|(a, _)| *a == action)
+            .and_then(|(_, keys)| keys.first());
+        let Some((key, modifiers)) = key.and_then(|k| chord(k)) else {
+            return String::new();
+        };
+
+        let key = match key.as_str() {
+            "escape" => "esc".to_string(),
+            "delete" => "backspace".to_string(),
+            k if k.len() > 1 && k.starts_with('f') => k.to_uppercase(),
+            k => k.to_string(),
+        };
+        modifiers
+            .iter()
+            .map(|m| format!("{}-", m))
+            .chain([key])
+            .collect()
+    }
+
+    // The bindings the event loop looks keys up in
+    pub fn keymap(&self) -> Keymap {
+        let bindings = self
+            .bound
+            .iter()
+            .flat_map(|(action, keys)| {
+                keys.iter()
+                    .filter_map(|k| chord(k))
+                    .map(|(key, modifiers)| Binding {
+                        key,
+                        modifiers,
+                        action: action.name().to_string(),
+                    })
+            })
+            .collect();
+        Keymap { bindings }
+    }
+}
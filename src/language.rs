@@ -0,0 +1,613 @@
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::config::config_path;
+use crate::words::{
+    GUESSES_4, GUESSES_6, GUESSES_7, GUESSES_8, MAX_LETTERS, MIN_LETTERS, PLAY_WORDS, VALID_WORDS,
+    WORDS_4, WORDS_6, WORDS_7, WORDS_8,
+};
+
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+static ENGLISH: &str = "english"; // name of the built-in language
+static ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub static MAX_ALPHABET: usize = 32; // letters the on-screen keyboard fits
+
+// -----------------------------
+// Strings
+// -----------------------------
+
+// Words of the interface a language pack can translate
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Strings {
+    pub played: String,
+    pub win_percent: String,
+    pub streak: String,
+    pub max_streak: String,
+    pub hard_wins: String,
+    pub daily_streak: String,
+    pub praise: Vec<String>, // a win on the first guess, the second, ... the sixth or later
+    pub not_in_list: String,
+}
+
+impl Default for Strings {
+    fn default() -> Self {
+        Self {
+            played: "Played".to_string(),
+            win_percent: "Win %".to_string(),
+            streak: "Streak".to_string(),
+            max_streak: "Max Streak".to_string(),
+            hard_wins: "Hard Wins".to_string(),
+            daily_streak: "Daily Streak".to_string(),
+            praise: [
+                "Genius",
+                "Magnificent",
+                "Impressive",
+                "Splendid",
+                "Great",
+                "Phew",
+            ]
+            .map(String::from)
+            .to_vec(),
+            not_in_list: "Not in word list".to_string(),
+        }
+    }
+}
+
+impl Strings {
+    // Praise of a win on attempt `n`, counted from 1
+    pub fn praise(&self, n: usize) -> &str {
+        let index = n.clamp(1, self.praise.len()) - 1;
+        &self.praise[index]
+    }
+}
+
+// -----------------------------
+// Language
+// -----------------------------
+// The words of the game, the letters they are typed with and the words of
+// the interface. Words are kept as they are written, accents and all, and
+// compared through the folds: a letter with a fold counts as the alphabet
+// letter it folds to, so "E" can be typed for the "É" of an answer.
+
+#[derive(Debug)]
+pub struct Language {
+    pub name: String,                            // "english", or the name of the pack
+    pub alphabet: Vec<char>,                     // letters that can be typed
+    folds: BTreeMap<char, char>,                 // letter written in words -> alphabet letter
+    answers: BTreeMap<usize, Vec<&'static str>>, // by length
+    guesses: BTreeMap<usize, Vec<&'static str>>, // accepted besides answers, by length
+    accepted: HashSet<String>,                   // folded answers and guesses
+    list: Option<String>,                        // name of word files read in place of these
+    list_path: Option<PathBuf>,                  // full path of the answers file of the list
+    pub strings: Strings,
+}
+
+// Uppercase of a letter that has a single letter uppercase, "ß" stays as it is
+pub fn uppercase(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+fn by_length(words: &[&'static str]) -> BTreeMap<usize, Vec<&'static str>> {
+    let mut lengths: BTreeMap<usize, Vec<&'static str>> = BTreeMap::new();
+    for &word in words {
+        lengths.entry(word.chars().count()).or_default().push(word);
+    }
+    lengths
+}
+
+impl Language {
+    // The built-in words, the Wordle archive among them
+    pub fn english() -> Self {
+        let mut answers = BTreeMap::new();
+        answers.insert(4, WORDS_4.to_vec());
+        answers.insert(5, PLAY_WORDS.to_vec());
+        answers.insert(6, WORDS_6.to_vec());
+        answers.insert(7, WORDS_7.to_vec());
+        answers.insert(8, WORDS_8.to_vec());
+        let mut guesses = BTreeMap::new();
+        guesses.insert(4, GUESSES_4.to_vec());
+        guesses.insert(5, VALID_WORDS.to_vec());
+        guesses.insert(6, GUESSES_6.to_vec());
+        guesses.insert(7, GUESSES_7.to_vec());
+        guesses.insert(8, GUESSES_8.to_vec());
+
+        Self::new(
+            ENGLISH.to_string(),
+            ALPHABET.chars().collect(),
+            BTreeMap::new(),
+            answers,
+            guesses,
+            Strings::default(),
+        )
+    }
+
+    fn new(
+        name: String,
+        alphabet: Vec<char>,
+        folds: BTreeMap<char, char>,
+        answers: BTreeMap<usize, Vec<&'static str>>,
+        guesses: BTreeMap<usize, Vec<&'static str>>,
+        strings: Strings,
+    ) -> Self {
+        let mut language = Self {
+            name,
+            alphabet,
+            folds,
+            answers,
+            guesses,
+            accepted: HashSet::new(),
+            list: None,
+            list_path: None,
+            strings,
+        };
+        language.accept();
+        language
+    }
+
+    // Folds every answer and guess into the words a guess is checked against
+    fn accept(&mut self) {
+        let words = self.answers.values().chain(self.guesses.values());
+        self.accepted = words.flatten().map(|w| self.fold_word(w)).collect();
+    }
+
+    // Only the built-in words follow the Wordle archive and its dates
+    pub fn has_archive(&self) -> bool {
+        self.name == ENGLISH && self.list.is_none()
+    }
+
+    // Name the words go by, none for the built-in ones: the language pack,
+    // or the file of answers read in its place
+    pub fn word_list(&self) -> &str {
+        match &self.list {
+            Some(list) => list,
+            None if self.has_archive() => "",
+            None => &self.name,
+        }
+    }
+
+    // Key the results and game of the words are saved under: the name, or
+    // the full path of a file of answers, as two lists may share a name
+    pub fn list_key(&self) -> String {
+        match &self.list_path {
+            Some(path) => path.display().to_string(),
+            None => self.word_list().to_string(),
+        }
+    }
+
+    // Words read from a file of answers, which may be edited between runs
+    pub fn has_list(&self) -> bool {
+        self.list.is_some()
+    }
+
+    // Plays the words of a file of answers, with the typing and strings of
+    // the language. A dictionary is then the only other words accepted and
+    // has every answer, without one the words of the language still are.
+    pub fn with_lists(
+        mut self,
+        answers: &Path,
+        dictionary: Option<&Path>,
+    ) -> Result<Self, LanguageError> {
+        let words = read_words(answers, &self.alphabet, &self.folds)?;
+        if words.is_empty() {
+            return Err(LanguageError::Invalid("has no words".to_string()).at(answers));
+        }
+
+        let guesses: Vec<&'static str> = match dictionary {
+            Some(path) => {
+                let dictionary = read_words(path, &self.alphabet, &self.folds)?;
+                let listed: HashSet<String> =
+                    dictionary.iter().map(|w| self.fold_word(w)).collect();
+                if let Some(word) = words.iter().find(|w| !listed.contains(&self.fold_word(w))) {
+                    return Err(LanguageError::Invalid(format!(
+                        "\"{}\" is not in the dictionary {}",
+                        word,
+                        path.display()
+                    ))
+                    .at(answers));
+                }
+                dictionary
+            }
+            None => {
+                let words = self.answers.values().chain(self.guesses.values());
+                words.flatten().copied().collect()
+            }
+        };
+
+        // guesses are the words besides the answers, each once
+        let mut seen: HashSet<String> = words.iter().map(|w| self.fold_word(w)).collect();
+        let guesses: Vec<&'static str> = guesses
+            .into_iter()
+            .filter(|w| seen.insert(self.fold_word(w)))
+            .collect();
+
+        self.list = Some(answers.file_stem().map_or_else(
+            || answers.display().to_string(),
+            |stem| stem.to_string_lossy().to_string(),
+        ));
+        self.list_path = Some(answers.canonicalize().unwrap_or(answers.to_path_buf()));
+        self.answers = by_length(&words);
+        self.guesses = by_length(&guesses);
+        self.accept();
+        Ok(self)
+    }
+
+    // Answers of a length, in the order they are numbered
+    pub fn answers(&'static self, letters: usize) -> &'static [&'static str] {
+        self.answers.get(&letters).map_or(&[], |words| words)
+    }
+
+    // Words accepted as guesses besides the answers
+    pub fn guesses(&'static self, letters: usize) -> &'static [&'static str] {
+        self.guesses.get(&letters).map_or(&[], |words| words)
+    }
+
+    pub fn accepts(&self, word: &str) -> bool {
+        self.accepted.contains(&self.fold_word(word))
+    }
+
+    // The alphabet letter a letter counts as
+    pub fn fold(&self, c: char) -> char {
+        self.folds.get(&c).copied().unwrap_or(c)
+    }
+
+    pub fn fold_word(&self, word: &str) -> String {
+        word.chars().map(|c| self.fold(c)).collect()
+    }
+
+    // Whether two words are the same once folded, "ECOLE" is "ÉCOLE"
+    pub fn same(&self, a: &str, b: &str) -> bool {
+        a.chars().count() == b.chars().count()
+            && a.chars()
+                .zip(b.chars())
+                .all(|(x, y)| self.fold(x) == self.fold(y))
+    }
+
+    // The letter a key types, None when it types none: lowercase is
+    // typed as uppercase and a letter with a fold as the one it folds to
+    pub fn letter(&self, key: &str) -> Option<char> {
+        let mut chars = key.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+        [c, uppercase(c)]
+            .into_iter()
+            .map(|c| self.fold(c))
+            .find(|c| self.alphabet.contains(c))
+    }
+
+    // Letters of a word as their place in the alphabet, what the solver
+    // compares. A letter outside of it gets a place of its own.
+    pub fn encode(&self, word: &str) -> Vec<u8> {
+        word.chars()
+            .map(|c| {
+                let c = self.fold(c);
+                self.alphabet
+                    .iter()
+                    .position(|&a| a == c)
+                    .unwrap_or(MAX_ALPHABET) as u8
+            })
+            .collect()
+    }
+}
+
+// Uses `language` for this run, it has no effect once words were looked up
+pub fn set_language(language: Language) {
+    LANGUAGE.set(language).ok();
+}
+
+// The language of this run, English unless one was set
+pub fn language() -> &'static Language {
+    LANGUAGE.get_or_init(Language::english)
+}
+
+// -----------------------------
+// Packs
+// -----------------------------
+// A language pack is a TOML file with the alphabet, the folds, the word
+// files and the strings, e.g. languages/french.toml next to config.toml.
+// Word files hold a word per line, # starts a comment.
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackFile {
+    name: Option<String>, // the file name without .toml when left out
+    alphabet: String,
+    #[serde(default)]
+    fold: BTreeMap<String, String>,
+    answers: PathBuf,         // relative to the pack
+    guesses: Option<PathBuf>, // relative to the pack
+    #[serde(default)]
+    strings: Strings,
+}
+
+// Where a pack of that name is looked for, a name with a path in it is the path
+pub fn pack_path(name: &str) -> Option<PathBuf> {
+    if name.contains(std::path::MAIN_SEPARATOR) || name.ends_with(".toml") {
+        return Some(PathBuf::from(name));
+    }
+    let config = config_path()?;
+    let dir = config.parent()?.join("languages");
+    Some(dir.join(format!("{}.toml", name)))
+}
+
+// A single character of the pack, e.g. a fold
+fn single(value: &str, what: &str) -> Result<char, LanguageError> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(uppercase(c)),
+        _ => Err(LanguageError::Invalid(format!(
+            "{} \"{}\" is not a single letter",
+            what, value
+        ))),
+    }
+}
+
+// The words of a file, uppercased and checked against the alphabet and the
+// letters that fold into it, a word may be listed once
+fn read_words(
+    path: &Path,
+    alphabet: &[char],
+    folds: &BTreeMap<char, char>,
+) -> Result<Vec<&'static str>, LanguageError> {
+    let content = read_to_string(path).map_err(|e| LanguageError::Io(e).at(path))?;
+    let letters: HashSet<char> = alphabet.iter().chain(folds.keys()).copied().collect();
+
+    let mut words = Vec::new();
+    let mut lines: HashMap<String, usize> = HashMap::new(); // folded word -> line
+    for (n, line) in content.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let word: String = line.chars().map(uppercase).collect();
+        let invalid = |reason: String| {
+            LanguageError::Invalid(format!("line {}: \"{}\" {}", n + 1, line, reason)).at(path)
+        };
+
+        let length = word.chars().count();
+        if !(MIN_LETTERS..=MAX_LETTERS).contains(&length) {
+            return Err(invalid(format!(
+                "has {} letters, words of {} to {} are played",
+                length, MIN_LETTERS, MAX_LETTERS
+            )));
+        }
+        if let Some(c) = word.chars().find(|c| !letters.contains(c)) {
+            return Err(invalid(format!("has \"{}\", not in the alphabet", c)));
+        }
+        let folded: String = word.chars().map(|c| *folds.get(&c).unwrap_or(&c)).collect();
+        if let Some(first) = lines.insert(folded, n + 1) {
+            return Err(invalid(format!("is already on line {}", first)));
+        }
+        // loaded once for the whole run
+        words.push(&*Box::leak(word.into_boxed_str()));
+    }
+    Ok(words)
+}
+
+fn load_pack(path: &Path, name: &str) -> Result<Language, LanguageError> {
+    let content = read_to_string(path)?;
+    let pack: PackFile =
+        toml::from_str(&content).map_err(|e| LanguageError::Parse(e.to_string()))?;
+
+    let alphabet: Vec<char> = pack.alphabet.chars().map(uppercase).collect();
+    if alphabet.is_empty() || alphabet.len() > MAX_ALPHABET {
+        return Err(LanguageError::Invalid(format!(
+            "the alphabet has {} letters, the keyboard fits 1 to {}",
+            alphabet.len(),
+            MAX_ALPHABET
+        )));
+    }
+    if let Some(c) = alphabet.iter().find(|c| !c.is_alphabetic()) {
+        return Err(LanguageError::Invalid(format!(
+            "the alphabet has \"{}\", which is not a letter",
+            c
+        )));
+    }
+
+    let mut folds = BTreeMap::new();
+    for (from, to) in &pack.fold {
+        let from = single(from, "fold")?;
+        let to = single(to, "fold")?;
+        if alphabet.contains(&from) || !alphabet.contains(&to) {
+            return Err(LanguageError::Invalid(format!(
+                "fold \"{}\" = \"{}\" must turn a letter outside the alphabet into one in it",
+                from, to
+            )));
+        }
+        folds.insert(from, to);
+    }
+    if pack.strings.praise.is_empty() {
+        return Err(LanguageError::Invalid(
+            "strings.praise needs at least one word".to_string(),
+        ));
+    }
+
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let answers = read_words(&dir.join(&pack.answers), &alphabet, &folds)?;
+    let guesses = match &pack.guesses {
+        Some(file) => read_words(&dir.join(file), &alphabet, &folds)?,
+        None => Vec::new(),
+    };
+    if answers.is_empty() {
+        return Err(LanguageError::Invalid(format!(
+            "{} has no words",
+            pack.answers.display()
+        )));
+    }
+
+    // the stats and history of a pack are kept apart by its name
+    let name = pack.name.unwrap_or_else(|| name.to_string());
+    if name.trim().is_empty() || name == ENGLISH {
+        return Err(LanguageError::Invalid(format!(
+            "a pack can not be named \"{}\"",
+            name
+        )));
+    }
+
+    Ok(Language::new(
+        name,
+        alphabet,
+        folds,
+        by_length(&answers),
+        by_length(&guesses),
+        pack.strings,
+    ))
+}
+
+// The built-in language, or the pack of that name or path
+pub fn load(name: &str) -> Result<Language, LanguageError> {
+    if name == ENGLISH {
+        return Ok(Language::english());
+    }
+    let path = pack_path(name).ok_or(LanguageError::NoConfigDir)?;
+    let stem = path
+        .file_stem()
+        .map_or(name.to_string(), |s| s.to_string_lossy().to_string());
+    load_pack(&path, &stem).map_err(|e| match e {
+        LanguageError::At(..) => e,
+        e => e.at(&path),
+    })
+}
+
+// -----------------------------
+// Errors
+// -----------------------------
+
+#[derive(Debug)]
+pub enum LanguageError {
+    Io(std::io::Error),              // pack or word file could not be read
+    Parse(String),                   // pack is not valid TOML, or has unknown settings
+    Invalid(String),                 // pack or word file parsed but holds a bad value
+    NoConfigDir,                     // no directory to look for packs in
+    At(PathBuf, Box<LanguageError>), // any of the above, in this file
+}
+
+impl LanguageError {
+    fn at(self, path: &Path) -> Self {
+        LanguageError::At(path.to_path_buf(), Box::new(self))
+    }
+}
+
+impl fmt::Display for LanguageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LanguageError::Io(e) => write!(f, "could not be read: {}", e),
+            LanguageError::Parse(e) => write!(f, "is malformed: {}", e.trim_end()),
+            LanguageError::Invalid(e) => write!(f, "is invalid: {}", e),
+            LanguageError::NoConfigDir => {
+                write!(f, "language packs need a config directory, give a path")
+            }
+            LanguageError::At(path, e) => write!(f, "{} {}", path.display(), e),
+        }
+    }
+}
+
+impl From<std::io::Error> for LanguageError {
+    fn from(e: std::io::Error) -> Self {
+        LanguageError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::highlight_in;
+
+    // The pack shipped in languages/
+    fn french() -> Language {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("languages/french.toml");
+        load_pack(&path, "french").unwrap()
+    }
+
+    // Words with ß counted as S, as German is often typed
+    fn german() -> Language {
+        let folds = BTreeMap::from([('ß', 'S'), ('Ä', 'A'), ('Ö', 'O'), ('Ü', 'U')]);
+        let answers = BTreeMap::from([(6, vec!["STRAßE", "GRÜßEN"])]);
+        Language::new(
+            "deutsch".to_string(),
+            ALPHABET.chars().collect(),
+            folds,
+            answers,
+            BTreeMap::new(),
+            Strings::default(),
+        )
+    }
+
+    fn highlight(language: &Language, guess: &str, answer: &str) -> Vec<u8> {
+        let guess: Vec<char> = guess.chars().collect();
+        let answer: Vec<char> = answer.chars().collect();
+        highlight_in(language, &guess, &answer)
+    }
+
+    #[test]
+    fn the_french_pack_loads() {
+        let french = french();
+        assert_eq!(french.name, "français");
+        assert_eq!(french.strings.not_in_list, "Pas dans la liste");
+        for letters in MIN_LETTERS..=MAX_LETTERS {
+            assert!(!french.answers.get(&letters).unwrap().is_empty());
+        }
+        assert!(french.answers[&5].contains(&"ÉCOLE"));
+    }
+
+    #[test]
+    fn folded_letters_are_typed_as_the_letter_they_fold_to() {
+        let french = french();
+        assert_eq!(french.letter("é"), Some('E'));
+        assert_eq!(french.letter("È"), Some('E'));
+        assert_eq!(french.letter("ç"), Some('C'));
+        assert_eq!(french.letter("e"), Some('E'));
+        assert_eq!(french.letter("ß"), None);
+        assert_eq!(german().letter("ß"), Some('S'));
+        assert_eq!(german().letter("ü"), Some('U'));
+
+        // ß has no single letter uppercase
+        assert_eq!(uppercase('ß'), 'ß');
+        assert_eq!(uppercase('é'), 'É');
+    }
+
+    #[test]
+    fn folded_words_are_accepted() {
+        let french = french();
+        for word in [
+            "ÉCOLE", "ECOLE", "ÈCOLE", "FORÊT", "FORET", "GARÇON", "GARCON",
+        ] {
+            assert!(french.accepts(word), "{} is rejected", word);
+        }
+        assert!(!french.accepts("ECOLX"));
+        assert!(french.same("ECOLE", "ÉCOLE"));
+        assert!(!french.same("ECOLE", "ÉCOLES"));
+
+        let german = german();
+        assert!(german.accepts("STRASE") && german.accepts("STRAßE"));
+        assert!(german.accepts("GRUSEN"));
+        assert!(!german.accepts("STRASSE"));
+        assert_eq!(german.encode("STRAßE"), german.encode("STRASE"));
+    }
+
+    #[test]
+    fn folded_letters_highlight_as_the_letter_they_fold_to() {
+        let french = french();
+        assert_eq!(highlight(&french, "FORET", "FORÊT"), [2; 5]);
+        assert_eq!(highlight(&french, "ÉCOLE", "ECOLE"), [2; 5]);
+        // É and È are both E, each counted once
+        assert_eq!(highlight(&french, "EVEIL", "ÉLÈVE"), [2, 1, 2, 0, 1]);
+        assert_eq!(highlight(&french, "EEEEE", "ÉLÈVE"), [2, 0, 2, 0, 2]);
+        assert_eq!(highlight(&french, "ETAGE", "CRÈME"), [1, 0, 0, 0, 2]);
+
+        let german = german();
+        assert_eq!(highlight(&german, "STRASE", "STRAßE"), [2; 6]);
+        assert_eq!(highlight(&german, "SSSSSS", "STRAßE"), [2, 0, 0, 0, 2, 0]);
+    }
+}
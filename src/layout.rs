@@ -0,0 +1,101 @@
+// -----------------------------
+// Layout
+// -----------------------------
+// The letters of the on-screen keyboard, row by row, as they sit on a
+// physical keyboard. Punctuation keys are left out, a space keeps their
+// place when they come before letters.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layout {
+    pub name: &'static str,
+    pub rows: [&'static str; 3], // top, home and bottom rows, left to right
+}
+
+// Built-in layouts, the first is the default
+pub static LAYOUTS: &[Layout] = &[
+    Layout {
+        name: "qwerty",
+        rows: ["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"],
+    },
+    // French
+    Layout {
+        name: "azerty",
+        rows: ["AZERTYUIOP", "QSDFGHJKLM", "WXCVBN"],
+    },
+    // German and central European
+    Layout {
+        name: "qwertz",
+        rows: ["QWERTZUIOP", "ASDFGHJKL", "YXCVBNM"],
+    },
+    Layout {
+        name: "dvorak",
+        rows: ["   PYFGCRL", "AOEUIDHTNS", "QJKXBMWVZ"],
+    },
+    Layout {
+        name: "colemak",
+        rows: ["QWFPGJLUY", "ARSTDHNEIO", "ZXCVBKM"],
+    },
+];
+
+impl Layout {
+    // A built-in layout by name
+    pub fn named(name: &str) -> Option<Layout> {
+        LAYOUTS.iter().find(|layout| layout.name == name).copied()
+    }
+
+    // The keys for an alphabet, ' ' for a gap. Letters the alphabet lacks
+    // are left out, and letters the layout lacks go at the end of the
+    // shorter of the top two rows, then of the bottom one. An alphabet that
+    // shares few letters with the layout, or that does not fit, goes in its
+    // own order, row after row.
+    pub fn keyboard(&self, alphabet: &[char]) -> Vec<Vec<char>> {
+        let mut rows: Vec<Vec<char>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .filter(|c| *c == ' ' || alphabet.contains(c))
+                    .collect()
+            })
+            .collect();
+        let extra: Vec<char> = alphabet
+            .iter()
+            .copied()
+            .filter(|&c| !self.rows.iter().any(|row| row.contains(c)))
+            .collect();
+        if extra.len() * 2 > alphabet.len() {
+            return in_order(alphabet);
+        }
+
+        for letter in extra {
+            let row = [0, 1]
+                .into_iter()
+                .filter(|&r| rows[r].len() < ROW_KEYS[r])
+                .min_by_key(|&r| rows[r].len())
+                .or((rows[2].len() < ROW_KEYS[2]).then_some(2));
+            match row {
+                Some(r) => rows[r].push(letter),
+                None => return in_order(alphabet),
+            }
+        }
+        rows
+    }
+}
+
+// Most keys each row fits on screen
+static ROW_KEYS: [usize; 3] = [11, 11, 10];
+
+fn in_order(alphabet: &[char]) -> Vec<Vec<char>> {
+    let mut rows = vec![Vec::new(); 3];
+    let mut letters = alphabet.iter().copied();
+    for (row, &fits) in rows.iter_mut().zip(&ROW_KEYS) {
+        row.extend(letters.by_ref().take(fits));
+    }
+    rows
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        LAYOUTS[0]
+    }
+}
@@ -1,21 +1,46 @@
+mod backend;
 mod event_loop;
 mod tui_engine;
 
+mod calendar;
+mod cli;
+mod config;
 mod elements;
 mod game;
+mod history;
+mod keymap;
+mod language;
+mod layout;
 mod rewordle_screen;
+mod share;
+mod solver;
 mod storage;
+mod theme;
 mod words;
 
-use crate::storage::read;
+#[cfg(test)]
+mod tests;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::cli::Action;
+use crate::config::{Config, Timings};
+use crate::history::Outcome;
+use crate::keymap::Keys;
+use crate::solver::Rating;
+use crate::storage::{read, save, set_save_path, SaveData, Stats};
+use crate::theme::Theme;
 use crate::tui_engine::Elements;
-use crate::words::PLAY_WORDS;
+use crate::words::{play_words, LETTERS, PLAY_WORDS};
 
 #[derive(Clone, Debug, PartialEq)]
 enum WordStatus {
     InPlay,
     Valid,
     Invalid,
+    Revealing,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -25,68 +50,394 @@ pub enum GameStatus {
     Lost,
 }
 
+// Where the next answer comes from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    #[default]
+    Archive, // the words in order, advancing word_index
+    Shuffle, // words of other board sizes and word lists at random, as they have no archive
+    #[serde(alias = "random")]
+    Practice, // random words from a seed, kept out of results and streak
+    Missed,  // words lost before, until none are left, not counted again
+    Pick,    // one word chosen in the archive browser, then back to the archive, not counted
+    Daily,   // the word of today's date, once a day, kept out of results and streak
+    Absurdle, // no answer until forced, each guess keeps the most words possible
+}
+
+// Today's word: its board and a streak of consecutive days
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Daily {
+    day: Option<usize>,      // days since the first word, of the board below
+    guesses: Vec<String>,    // guesses on that day's board
+    streak: (u32, u32),      // (current_streak, max_streak) in days
+    last_won: Option<usize>, // day of the latest win
+}
+
+// The archive browser, open over the board
+#[derive(Clone, Debug, PartialEq)]
+pub struct Browser {
+    selected: usize,                // index into PLAY_WORDS
+    top: usize,                     // first index in view
+    query: String,                  // number or date being typed
+    outcomes: Vec<Option<Outcome>>, // latest counted result per word
+}
+
+// A guess of the post-game review with the answers possible after it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    guess: String,
+    pattern: Vec<u8>,
+    left: usize,
+    rating: Option<Rating>, // the player's guesses only
+    wasted: Vec<bool>,      // letters that told nothing new
+}
+
+// The post-game review, open over the board
+#[derive(Clone, Debug, PartialEq)]
+pub struct Review {
+    answer: &'static str,
+    player: Vec<Step>, // the rows of the game just over
+    solver: Vec<Step>, // how the solver would have played the same answer
+    solver_page: bool, // showing the solver's guesses instead of the player's
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct AppState {
     app_x: u16,
     app_y: u16,
     game: GameStatus,
-    word_index: usize,      // the index of the played from the list of words
-    answer: &'static str,   // the correct answer
-    status: Vec<Vec<char>>, // each line is Vec<char>, " " for empty slots
-    in_play: usize,         // current attempt number (0-5)
-    used: Vec<char>,        // letters already guessed
-    results: Vec<u32>,      // results[0..6], wins per attempt, results[6] for losses
-    streak: (u32, u32),     // (current_streak, max_streak)
+    mode: Mode,                     // where answers come from
+    word_index: usize,              // next word of the archive progression
+    answer_index: usize,            // index of the answer in the list of words
+    answer: &'static str,           // the correct answer, empty once all words were played
+    answers: Vec<usize>,            // answers of every board, the first is answer_index
+    candidates: Vec<usize>,         // words absurdle can still answer with
+    complete: bool,                 // nothing left to play, a new mode must be picked
+    passes: u32,                    // completed runs through the archive
+    missed: Vec<usize>,             // words still to replay in missed mode
+    practice_seed: Option<u64>,     // seed of the practice words, drawn when first needed
+    practice_games: u64,            // practice words drawn from the seed so far
+    pick: Option<usize>,            // word chosen in the browser, played next
+    browser: Option<Browser>,       // open archive browser
+    review: Option<Review>,         // open post-game review
+    daily: Daily,                   // board and streak of the daily mode
+    letters: usize,                 // letters per word
+    attempts: usize,                // guesses per game
+    boards: usize,                  // answers played at once with the same guesses
+    status: Vec<Vec<char>>,         // each line is Vec<char>, " " for empty slots
+    in_play: usize,                 // current attempt number (0 to attempts - 1)
+    used: Vec<char>,                // letters already guessed
+    results: Vec<u32>,              // wins per attempt, results[attempts] for losses
+    stats: BTreeMap<String, Stats>, // results of the other configurations, by "LxA"
+    streak: (u32, u32),             // (current_streak, max_streak)
+    hard_mode: bool,                // guesses must reuse revealed hints
+    hard_wins: u32,                 // games won in hard mode
+    theme: Theme,                   // how letter statuses are drawn
+    themes: Vec<Theme>,             // themes the theme key goes through
+    timings: Timings,               // animation speed
+    keys: Keys,                     // keys bound to each action, for labels
+    message: Option<String>,        // feedback shown above the board
+    started_at: u64,                // unix time the game in play was first shown
     exit_flag: bool,
     word_status: WordStatus,
 }
 
+// Plays the words of the built-in language or of a pack for the whole run,
+// or the word files of the command line if it names any, else of the config
+fn use_language(config: &Config, answers: Option<&Path>, dictionary: Option<&Path>) {
+    let (answers, dictionary) = match answers {
+        Some(answers) => (Some(answers), dictionary),
+        None => (config.answers.as_deref(), config.dictionary.as_deref()),
+    };
+    let mut language = language::load(&config.language).unwrap_or_else(|e| fail(e, 1));
+    if let Some(answers) = answers {
+        language = language
+            .with_lists(answers, dictionary)
+            .unwrap_or_else(|e| fail(e, 1));
+    }
+    language::set_language(language);
+}
+
 fn exit_ui(state: &AppState) -> bool {
     // exit UI loop when exit_flag is true
     state.exit_flag
 }
 
-fn main() {
-    // read from storage
-    let from_storage = read();
-
-    // stats from storage
-    let results: Vec<u32> = from_storage[0]
-        .split(':')
-        .map(|s| s.parse::<u32>().unwrap_or(0))
-        .collect();
-
-    let streak: (u32, u32) = (
-        from_storage[1]
-            .split(':')
-            .nth(0)
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(0),
-        from_storage[1]
-            .split(':')
-            .nth(1)
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(0),
-    );
+// Reports an error the way the rest of the CLI does and exits
+fn fail(message: impl std::fmt::Display, code: i32) -> ! {
+    eprintln!("rewordle: {}", message);
+    std::process::exit(code);
+}
 
-    // last word played
-    let word_index: usize = from_storage[2].parse::<usize>().unwrap_or(0);
+// The state a run starts from: the saved game restored onto the board,
+// or the next word
+fn start_state(saved: SaveData, config: &Config) -> AppState {
+    // the saved answer, or the archive word; None when a new one must be picked
+    let answer_index = match saved.mode {
+        _ if saved.boards > 1 => saved.answers.first().copied(),
+        Mode::Archive if game::plays_archive(saved.letters, saved.boards) => {
+            Some(saved.word_index).filter(|&i| i < PLAY_WORDS.len())
+        }
+        Mode::Daily => None, // the day decides, and the daily board is kept apart
+        _ => saved.answer_index,
+    };
 
-    let state = AppState {
+    let mut state = AppState {
         app_x: 0,
         app_y: 0,
         game: GameStatus::InPlay,
-        word_index,
-        answer: PLAY_WORDS[word_index],
-        status: vec![vec![' '; 5]; 6],
+        mode: saved.mode,
+        word_index: saved.word_index,
+        answer_index: answer_index.unwrap_or(0),
+        answer: answer_index.map_or("", |i| play_words(saved.letters)[i]),
+        answers: match saved.boards {
+            1 => answer_index.into_iter().collect(),
+            _ => saved.answers,
+        },
+        candidates: Vec::new(),
+        complete: false,
+        passes: saved.passes,
+        missed: saved.missed,
+        practice_seed: saved.practice_seed,
+        practice_games: saved.practice_games,
+        pick: None,
+        browser: None,
+        review: None,
+        daily: saved.daily,
+        letters: saved.letters,
+        attempts: saved.attempts,
+        boards: saved.boards,
+        status: vec![vec![' '; saved.letters]; saved.attempts],
         in_play: 0,
         used: Vec::new(),
-        results,
-        streak,
+        results: saved.results,
+        stats: saved.stats,
+        streak: saved.streak,
+        hard_mode: saved.hard_mode,
+        hard_wins: saved.hard_wins,
+        theme: config.theme,
+        themes: config.themes.clone(),
+        timings: config.timings,
+        keys: config.keys.clone(),
+        message: None,
+        started_at: saved.started_at.unwrap_or_else(history::now),
         exit_flag: false,
         word_status: WordStatus::InPlay,
     };
-    let elements: Elements<'_, AppState> = crate::rewordle_screen::build();
+    match answer_index {
+        // guesses of an unfinished game, restored onto the board
+        Some(_) => game::mutate_state_restore(&mut state, &saved.guesses),
+        // picks the next word, or shows the completion screen
+        None => game::mutate_state_new_game(&mut state),
+    }
+    state
+}
+
+// The saved game given up as a loss when it is counted and half played,
+// the guesses of another language can not be played back
+fn forfeit(saved: SaveData, config: &Config) -> SaveData {
+    if saved.guesses.is_empty() || saved.word_list != language::language().list_key() {
+        return saved;
+    }
+    let mut state = start_state(saved, config);
+    game::mutate_state_forfeit(&mut state);
+    // saved at once, the loss stands even if nothing is played after it
+    let saved = SaveData::from_state(&state);
+    save(&saved).unwrap_or_else(|e| fail(e, 1));
+    saved
+}
+
+fn main() {
+    let cli = cli::parse(std::env::args().skip(1))
+        .unwrap_or_else(|e| fail(format!("{}\nTry 'rewordle --help'.", e), 2));
+
+    if let Some(path) = cli.save_file {
+        set_save_path(path);
+    }
+
+    // actions that do not need the save file, reset works even on a damaged one
+    match cli.action {
+        Action::Help => {
+            print!("{}", cli::HELP);
+            return;
+        }
+        Action::Version => {
+            println!("rewordle {}", env!("CARGO_PKG_VERSION"));
+            return;
+        }
+        Action::Reset => {
+            cli::reset().unwrap_or_else(|e| fail(e, 1));
+            return;
+        }
+        Action::Solve => {
+            let config = config::read().unwrap_or_else(|e| fail(e, 1));
+            use_language(&config, cli.answers.as_deref(), cli.dictionary.as_deref());
+            let letters = cli.letters.unwrap_or(LETTERS);
+            cli::solve(letters).unwrap_or_else(|e| fail(e, 1));
+            return;
+        }
+        Action::Share => {
+            let records = history::read().unwrap_or_else(|e| fail(e, 1));
+            match records.last() {
+                Some(record) => println!("{}", share::from_record(record)),
+                None => fail("no finished games yet", 1),
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    // the language decides which saved words can be checked
+    let config = config::read().unwrap_or_else(|e| fail(e, 1));
+    use_language(&config, cli.answers.as_deref(), cli.dictionary.as_deref());
+    let language = language::language();
+    if (cli.word.is_some() || cli.daily) && !language.has_archive() {
+        fail(
+            format!(
+                "--word and --daily play the English archive, not the {} words",
+                language.word_list()
+            ),
+            2,
+        );
+    }
+
+    // read from storage, a damaged file is reported rather than reset
+    let mut saved = read().unwrap_or_else(|e| fail(e, 1));
+
+    // the command line picks the mode, or else the configured one when it
+    // can be played on the board size asked for and no game is left to finish
+    let picked = cli.word.is_some() || cli.practice || cli.daily || cli.absurdle;
+    let one_board = cli.boards.is_none_or(|n| n == 1);
+    let default_mode = config.mode.filter(|&mode| {
+        !picked
+            && !saved.in_progress()
+            && match mode {
+                Mode::Daily => {
+                    one_board && cli.letters.is_none_or(|n| n == LETTERS) && language.has_archive()
+                }
+                Mode::Absurdle => one_board,
+                _ => true,
+            }
+    });
+    let practice = cli.practice || default_mode == Some(Mode::Practice);
+    let daily = cli.daily || default_mode == Some(Mode::Daily);
+    let absurdle = cli.absurdle || default_mode == Some(Mode::Absurdle);
+
+    // another board size leaves the game in progress, the archive and
+    // today's word bring back a single board of five letters
+    let to_archive = cli.word.is_some() || daily;
+    let letters = match cli.letters {
+        Some(letters) => letters,
+        None if to_archive => LETTERS,
+        None => saved.letters,
+    };
+    let boards = match cli.boards {
+        Some(boards) => boards,
+        None if to_archive || absurdle || cli.attempts.is_some() => 1,
+        None => saved.boards,
+    };
+    let attempts = match cli.attempts {
+        _ if boards > 1 => game::board_attempts(boards),
+        Some(attempts) => attempts,
+        // back from several boards to the usual guesses
+        None if saved.boards > 1 => game::ATTEMPTS,
+        None => saved.attempts,
+    };
+
+    // a counted game left for another board size, word or mode is lost
+    let resizes = language.list_key() != saved.word_list
+        || (letters, attempts, boards) != (saved.letters, saved.attempts, saved.boards);
+    let jumps = (cli.word).is_some_and(|w| w != saved.word_index || saved.mode != Mode::Archive);
+    let practices = practice && (cli.seed.is_some() || saved.mode != Mode::Practice);
+    let absurdles = absurdle && saved.mode != Mode::Absurdle;
+    if (resizes || jumps || practices || daily || absurdles) && cli.action != Action::Stats {
+        saved = forfeit(saved, &config);
+    }
+    saved.switch_config(&language.list_key(), letters, attempts, boards);
+
+    // a language pack or word list may have few words of a length, or none
+    let count = play_words(letters).len();
+    if count < boards {
+        fail(
+            format!(
+                "the {} words have {} of {} letters, not enough to play",
+                language.word_list(),
+                count,
+                letters
+            ),
+            1,
+        );
+    }
+
+    if cli.action == Action::Stats {
+        print!("{}", cli::stats_text(&saved));
+        return;
+    }
+
+    // jumping to another word starts it afresh
+    if let Some(word_index) = cli
+        .word
+        .filter(|&w| w != saved.word_index || saved.mode != Mode::Archive)
+    {
+        saved.mode = Mode::Archive;
+        saved.word_index = word_index;
+        saved.answer_index = None;
+        saved.guesses.clear();
+        saved.started_at = None;
+    }
+
+    // a seed starts its set from the first word, plain --practice picks up the last set
+    if practice && (cli.seed.is_some() || saved.mode != Mode::Practice) {
+        saved.mode = Mode::Practice;
+        if cli.seed.is_some() {
+            saved.practice_seed = cli.seed;
+            saved.practice_games = 0;
+        }
+        saved.answer_index = None;
+        saved.guesses.clear();
+        saved.started_at = None;
+    }
+
+    if daily {
+        saved.mode = Mode::Daily;
+        saved.answer_index = None;
+        saved.guesses.clear();
+        saved.started_at = None;
+    }
+
+    if absurdle && saved.mode != Mode::Absurdle {
+        saved.mode = Mode::Absurdle;
+        saved.answer_index = None;
+        saved.guesses.clear();
+        saved.started_at = None;
+    }
+
+    // back to the progression from a mode that keeps out of it
+    if default_mode == Some(Mode::Archive)
+        && matches!(saved.mode, Mode::Practice | Mode::Daily | Mode::Absurdle)
+    {
+        saved.mode = game::main_mode(saved.letters, saved.boards);
+        saved.answer_index = None;
+        saved.guesses.clear();
+        saved.started_at = None;
+    }
+
+    let state = start_state(saved, &config);
+    let keys = config.layout.keyboard(&language.alphabet);
+    let elements: Elements<'_, AppState> = crate::rewordle_screen::build(&keys);
+    let keymap = state.keys.keymap();
+
+    let final_state = tui_engine::run(
+        state,
+        elements,
+        Some(config.tick_rate),
+        Some(&keymap),
+        Some(&exit_ui),
+    );
 
-    tui_engine::run(state, elements, None, Some(&exit_ui));
+    // leave the result of a finished game behind in the terminal
+    if let Some(text) = share::from_state(&final_state) {
+        println!("{}", text);
+    }
 }
@@ -1,7 +1,8 @@
 use crate::tui_engine::*;
 use crate::AppState;
 
-pub fn build<'a>() -> Elements<'a, AppState> {
+// `keys` are the rows of the on-screen keyboard
+pub fn build<'a>(keys: &[Vec<char>]) -> Elements<'a, AppState> {
     let elements: Elements<AppState> = Elements::new();
 
     elements.push(crate::elements::screen::build());
@@ -9,13 +10,25 @@ pub fn build<'a>() -> Elements<'a, AppState> {
     elements.push(crate::elements::title_bar::build());
     elements.push(crate::elements::button_exit::build());
     elements.push(crate::elements::button_next::build());
+    elements.push(crate::elements::button_hard::build());
+    elements.push(crate::elements::button_practice::build());
+    elements.push(crate::elements::button_archive::build());
+    elements.push(crate::elements::button_daily::build());
+    elements.push(crate::elements::button_share::build());
     elements.push(crate::elements::board::build());
+    elements.push(crate::elements::tiles::build());
+    elements.push(crate::elements::completion::build());
+    elements.push(crate::elements::review::build());
+    elements.push(crate::elements::browser::build());
     elements.push(crate::elements::enter_key::build());
-    elements.push(crate::elements::delete_key::build());
+    elements.push(crate::elements::delete_key::build(keys[2].len()));
     elements.push(crate::elements::answer::build());
+    elements.push(crate::elements::message::build());
     elements.push(crate::elements::results::build());
+    elements.push(crate::elements::hint::build());
+    elements.push(crate::elements::theme_key::build());
 
-    elements.extend(crate::elements::keyboard::build());
+    elements.extend(crate::elements::keyboard::build(keys));
     elements.extend(crate::elements::graphs::build());
 
     elements
@@ -0,0 +1,187 @@
+use crate::game::{boards_name, solved_on, wordle_highlight};
+use crate::history::{GameRecord, Outcome};
+use crate::language::language;
+use crate::words::LETTERS;
+use crate::{AppState, GameStatus, Mode};
+
+// Maps letter status to the emoji used when sharing
+fn status_to_emoji(status: u8) -> char {
+    match status {
+        2 => '🟩',
+        1 => '🟨',
+        _ => '⬛',
+    }
+}
+
+// Names the game, a practice grid does not give away the archive number
+// and other board sizes, absurdle and language packs have no archive number
+// at all, a pack's words are named after the game, "Wordle french"
+fn title(
+    practice: bool,
+    absurdle: bool,
+    letters: usize,
+    boards: usize,
+    word_index: usize,
+    word_list: &str,
+) -> String {
+    let name = match boards {
+        _ if absurdle => "Absurdle",
+        1 => "Wordle",
+        _ => boards_name(boards),
+    };
+    let name = match word_list {
+        "" => name.to_string(),
+        list => format!("{} {}", name, list),
+    };
+    match (practice, letters, boards) {
+        (_, l, _) if l != LETTERS => format!("{} {} letters", name, l),
+        _ if absurdle => name,
+        (true, _, _) => format!("{} practice", name),
+        (_, _, 1) if word_list.is_empty() => format!("Wordle {}", word_index + 1),
+        _ => name,
+    }
+}
+
+// Attempt number as a keycap emoji, clock faces past ten
+fn score_to_emoji(score: usize) -> String {
+    match score {
+        1..=9 => format!("{}\u{FE0F}\u{20E3}", score),
+        10 => "🔟".to_string(),
+        11 => "🕚".to_string(),
+        12 => "🕛".to_string(),
+        _ => "🕐".to_string(),
+    }
+}
+
+// The share block of several boards: a header line, a blank line, then the
+// attempt each board was solved on, two boards to a row, red when unsolved
+fn boards_grid(title: &str, scores: &[Option<usize>], attempts: usize, hard_mode: bool) -> String {
+    let score = if scores.iter().all(|s| s.is_some()) {
+        scores.iter().flatten().max().unwrap_or(&0).to_string()
+    } else {
+        "X".to_string()
+    };
+    let hard = if hard_mode { "*" } else { "" };
+
+    let rows: Vec<String> = scores
+        .chunks(2)
+        .map(|pair| {
+            pair.iter()
+                .map(|s| s.map_or("🟥".to_string(), score_to_emoji))
+                .collect()
+        })
+        .collect();
+
+    format!(
+        "{} {}/{}{}\n\n{}",
+        title,
+        score,
+        attempts,
+        hard,
+        rows.join("\n")
+    )
+}
+
+// "3/6", or "X/6" for a loss, absurdle counts the guesses it took and is
+// only lost once its board is full
+fn score(guesses: usize, attempts: usize, won: bool, absurdle: bool) -> String {
+    match (won, absurdle) {
+        (false, true) => format!("X/{}", guesses),
+        (false, false) => format!("X/{}", attempts),
+        (true, true) => format!("{} guesses", guesses),
+        (true, false) => format!("{}/{}", guesses, attempts),
+    }
+}
+
+// The classic share block: a header line, a blank line, then one row per guess
+// `title` names the game, e.g. "Wordle 12"
+fn grid(title: &str, score: &str, patterns: &[Vec<u8>], hard_mode: bool) -> String {
+    let hard = if hard_mode { "*" } else { "" };
+
+    let rows: Vec<String> = patterns
+        .iter()
+        .map(|row| row.iter().map(|&s| status_to_emoji(s)).collect())
+        .collect();
+
+    format!("{} {}{}\n\n{}", title, score, hard, rows.join("\n"))
+}
+
+// None while the game is still in play
+pub fn from_state(state: &AppState) -> Option<String> {
+    if state.game == GameStatus::InPlay {
+        return None;
+    }
+
+    let absurdle = state.mode == Mode::Absurdle;
+    let title = title(
+        state.mode == Mode::Practice,
+        absurdle,
+        state.letters,
+        state.boards,
+        state.answer_index,
+        language().word_list(),
+    );
+
+    if state.boards > 1 {
+        let scores: Vec<Option<usize>> = (0..state.boards)
+            .map(|b| solved_on(state, b).map(|row| row + 1))
+            .collect();
+        return Some(boards_grid(
+            &title,
+            &scores,
+            state.attempts,
+            state.hard_mode,
+        ));
+    }
+
+    let answer_arr: Vec<char> = state.answer.chars().collect();
+    let patterns: Vec<Vec<u8>> = state.status[..state.in_play]
+        .iter()
+        .map(|row| wordle_highlight(row, &answer_arr))
+        .collect();
+
+    let won = state.game == GameStatus::Won;
+    let score = score(patterns.len(), state.attempts, won, absurdle);
+    Some(grid(&title, &score, &patterns, state.hard_mode))
+}
+
+pub fn from_record(record: &GameRecord) -> String {
+    let boards = record.answers.len().max(1);
+    let title = title(
+        record.practice,
+        record.absurdle,
+        record.letters,
+        boards,
+        record.word_index,
+        &record.word_list,
+    );
+
+    if boards > 1 {
+        let scores: Vec<Option<usize>> = record
+            .answers
+            .iter()
+            .map(|answer| {
+                record
+                    .guesses
+                    .iter()
+                    .position(|g| language().same(g, answer))
+                    .map(|row| row + 1)
+            })
+            .collect();
+        return boards_grid(&title, &scores, record.attempts, record.hard_mode);
+    }
+
+    let patterns: Vec<Vec<u8>> = record
+        .patterns
+        .iter()
+        .map(|p| {
+            p.chars()
+                .map(|c| c.to_digit(10).unwrap_or(0) as u8)
+                .collect()
+        })
+        .collect();
+
+    let won = record.outcome == Outcome::Won;
+    let score = score(patterns.len(), record.attempts, won, record.absurdle);
+    grid(&title, &score, &patterns, record.hard_mode)
+}
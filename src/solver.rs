@@ -0,0 +1,381 @@
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::game::wordle_highlight;
+use crate::language::{language, MAX_ALPHABET};
+use crate::words::{guess_words, play_words};
+use crate::AppState;
+
+// Candidate sets up to this size are ranked against every accepted word,
+// larger ones among themselves to keep the wait short
+static FULL_POOL_LIMIT: usize = 300;
+
+// Best first guess per word length, the same every time
+static OPENERS: OnceLock<Mutex<BTreeMap<usize, &'static str>>> = OnceLock::new();
+
+// A guess within this share of the best guess's bits plays as well as it
+static SKILL_SHARE: f64 = 0.9;
+
+// A guess and the bits of information it is expected to give
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ranked {
+    pub word: &'static str,
+    pub bits: f64,
+}
+
+// How a guess compares with the best one there was when it was played
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rating {
+    Skillful, // about as much information as the best guess
+    Lucky,    // less expected, but it narrowed the answers down as much or more
+    Weak,     // less expected and no better in the end
+}
+
+// A guess of a finished game looked back on
+#[derive(Clone, Debug, PartialEq)]
+pub struct Graded {
+    pub left: usize,       // answers still possible after it
+    pub rating: Rating,    // against the best guess at the time
+    pub wasted: Vec<bool>, // letters the earlier rows had already ruled out or placed
+}
+
+// -----------------------------
+// Patterns
+// -----------------------------
+
+// The highlight of `guess` against `answer` as a number in base 3, the
+// first letter the most significant digit. Same statuses as wordle_highlight,
+// without the allocations, as the solver compares millions of pairs. Words
+// come as the places of their letters in the alphabet (Language::encode).
+pub fn pattern_code(guess: &[u8], answer: &[u8]) -> usize {
+    let mut status = [0u8; 16];
+    let mut left = [0u8; MAX_ALPHABET + 1];
+
+    for (i, (&g, &a)) in guess.iter().zip(answer).enumerate() {
+        if g == a {
+            status[i] = 2;
+        } else {
+            left[a as usize] += 1;
+        }
+    }
+    for (i, &g) in guess.iter().enumerate() {
+        let slot = &mut left[g as usize];
+        if status[i] == 0 && *slot > 0 {
+            status[i] = 1;
+            *slot -= 1;
+        }
+    }
+
+    status[..guess.len()]
+        .iter()
+        .fold(0, |code, &s| code * 3 + s as usize)
+}
+
+// A pattern of statuses (2 exact, 1 exists, 0 not) as a number
+pub fn code(pattern: &[u8]) -> usize {
+    pattern.iter().fold(0, |code, &s| code * 3 + s as usize)
+}
+
+// The statuses of a pattern number
+pub fn pattern(code: usize, letters: usize) -> Vec<u8> {
+    let mut pattern = vec![0; letters];
+    let mut rest = code;
+    for status in pattern.iter_mut().rev() {
+        *status = (rest % 3) as u8;
+        rest /= 3;
+    }
+    pattern
+}
+
+// -----------------------------
+// Candidates
+// -----------------------------
+
+// Answers that would have given every guess its pattern
+pub fn remaining(letters: usize, history: &[(String, usize)]) -> Vec<&'static str> {
+    let language = language();
+    let guesses: Vec<(Vec<u8>, usize)> = history
+        .iter()
+        .map(|(guess, code)| (language.encode(guess), *code))
+        .collect();
+    play_words(letters)
+        .iter()
+        .copied()
+        .filter(|answer| {
+            let answer = language.encode(answer);
+            guesses
+                .iter()
+                .all(|(guess, code)| pattern_code(guess, &answer) == *code)
+        })
+        .collect()
+}
+
+// The submitted guesses of a single board with their pattern numbers
+pub(crate) fn history(state: &AppState) -> Vec<(String, usize)> {
+    let answer_arr: Vec<char> = state.answer.chars().collect();
+    state.status[..state.in_play]
+        .iter()
+        .map(|row| {
+            (
+                row.iter().collect(),
+                code(&wordle_highlight(row, &answer_arr)),
+            )
+        })
+        .collect()
+}
+
+// -----------------------------
+// Ranking
+// -----------------------------
+
+// Bits of information a guess is expected to give about the candidates
+pub fn entropy(guess: &str, candidates: &[&str]) -> f64 {
+    let language = language();
+    let answers: Vec<Vec<u8>> = candidates.iter().map(|w| language.encode(w)).collect();
+    encoded_entropy(&language.encode(guess), &answers)
+}
+
+// The same for encoded words, so a ranking encodes the candidates once
+fn encoded_entropy(guess: &[u8], answers: &[Vec<u8>]) -> f64 {
+    let mut counts = vec![0u32; 3usize.pow(guess.len() as u32)];
+    for answer in answers {
+        counts[pattern_code(guess, answer)] += 1;
+    }
+
+    let total = answers.len() as f64;
+    counts
+        .iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| {
+            let p = n as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+// The best `n` guesses for the candidates. A candidate gets the chance of
+// being the answer on top of its bits, so it wins a tie with a word that
+// can not be. In hard mode only candidates keep every hint.
+pub fn rank(letters: usize, candidates: &[&'static str], hard_mode: bool, n: usize) -> Vec<Ranked> {
+    let mut pool: Vec<&'static str> = candidates.to_vec();
+    if !hard_mode && candidates.len() > 2 && candidates.len() <= FULL_POOL_LIMIT {
+        pool.extend(
+            play_words(letters)
+                .iter()
+                .chain(guess_words(letters))
+                .filter(|w| !candidates.contains(w)),
+        );
+    }
+
+    let language = language();
+    let answers: Vec<Vec<u8>> = candidates.iter().map(|w| language.encode(w)).collect();
+    let chance = 1.0 / candidates.len().max(1) as f64;
+    let mut ranked: Vec<(f64, Ranked)> = pool
+        .into_iter()
+        .map(|word| {
+            let bits = encoded_entropy(&language.encode(word), &answers);
+            let bonus = if candidates.contains(&word) {
+                chance
+            } else {
+                0.0
+            };
+            (bits + bonus, Ranked { word, bits })
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    ranked.into_iter().take(n).map(|(_, r)| r).collect()
+}
+
+// The top guess, the opening one worked out once per word length
+pub fn best(letters: usize, history: &[(String, usize)], hard_mode: bool) -> Option<&'static str> {
+    if history.is_empty() {
+        let openers = OPENERS.get_or_init(|| Mutex::new(BTreeMap::new()));
+        if let Some(&word) = openers.lock().unwrap().get(&letters) {
+            return Some(word);
+        }
+        let word = rank(letters, play_words(letters), false, 1).first()?.word;
+        openers.lock().unwrap().insert(letters, word);
+        return Some(word);
+    }
+
+    let candidates = remaining(letters, history);
+    rank(letters, &candidates, hard_mode, 1)
+        .first()
+        .map(|r| r.word)
+}
+
+// How the solver plays against an answer: each guess, its pattern number
+// and the answers still possible after it, for up to `attempts` guesses
+pub fn play(letters: usize, answer: &str, attempts: usize) -> Vec<(&'static str, usize, usize)> {
+    let mut history: Vec<(String, usize)> = Vec::new();
+    let mut steps = Vec::new();
+
+    while steps.len() < attempts {
+        let Some(guess) = best(letters, &history, false) else {
+            break;
+        };
+        let code = pattern_code(&language().encode(guess), &language().encode(answer));
+        history.push((guess.to_string(), code));
+        steps.push((guess, code, remaining(letters, &history).len()));
+        if language().same(guess, answer) {
+            break;
+        }
+    }
+    steps
+}
+
+// -----------------------------
+// Analysis
+// -----------------------------
+
+// Looks back on the rows of a finished game: what each guess left, how it
+// compares with the best guess of the moment, and which of its letters
+// told nothing new. The row that found the answer wastes nothing.
+pub fn grade(letters: usize, answer: &str, rows: &[Vec<char>], hard_mode: bool) -> Vec<Graded> {
+    let answer_arr: Vec<char> = answer.chars().collect();
+    let mut history: Vec<(String, usize)> = Vec::new();
+    let mut placed: Vec<(usize, char)> = Vec::new(); // green so far
+    let mut found: Vec<char> = Vec::new(); // green or yellow so far
+    let mut greyed: Vec<char> = Vec::new(); // grey so far
+
+    rows.iter()
+        .map(|row| {
+            let guess: String = row.iter().collect();
+            let candidates = remaining(letters, &history);
+            let bits = entropy(&guess, &candidates);
+            let best_bits =
+                best(letters, &history, hard_mode).map_or(0.0, |word| entropy(word, &candidates));
+
+            let wasted = row
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    !language().same(&guess, answer)
+                        && (placed.contains(&(i, *c)) || (greyed.contains(c) && !found.contains(c)))
+                })
+                .collect();
+
+            let highlight = wordle_highlight(row, &answer_arr);
+            for (i, (&c, &status)) in row.iter().zip(&highlight).enumerate() {
+                match status {
+                    2 => {
+                        placed.push((i, c));
+                        found.push(c);
+                    }
+                    1 => found.push(c),
+                    _ => greyed.push(c),
+                }
+            }
+            history.push((guess.clone(), code(&highlight)));
+
+            // bits the guess actually gave, from the answers it ruled out
+            let left = remaining(letters, &history).len();
+            let actual = (candidates.len() as f64 / left.max(1) as f64).log2();
+            let rating = if bits >= best_bits * SKILL_SHARE {
+                Rating::Skillful
+            } else if actual >= best_bits || language().same(&guess, answer) {
+                Rating::Lucky
+            } else {
+                Rating::Weak
+            };
+
+            Graded {
+                left,
+                rating,
+                wasted,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    // The pattern number of a guess against an answer, as the game colors it
+    fn scored(guess: &str, answer: &str) -> (String, usize) {
+        let highlight = wordle_highlight(&chars(guess), &chars(answer));
+        (guess.to_string(), code(&highlight))
+    }
+
+    #[test]
+    fn patterns_are_numbers_in_base_3() {
+        assert_eq!(code(&[0, 0, 0, 0, 0]), 0);
+        assert_eq!(code(&[0, 0, 0, 0, 1]), 1);
+        assert_eq!(code(&[2, 0, 1, 0, 0]), 2 * 81 + 9);
+        assert_eq!(code(&[2; 5]), 242);
+        for n in 0..3usize.pow(5) {
+            assert_eq!(code(&pattern(n, 5)), n);
+        }
+        assert_eq!(pattern(171, 5), [2, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn repeated_letters_are_counted_once_each() {
+        let language = language();
+        let pairs = [
+            ("SPEED", "ABIDE", [0, 0, 1, 0, 1]),
+            ("EERIE", "THEME", [1, 0, 0, 0, 2]),
+            ("LLAMA", "HELLO", [1, 1, 0, 0, 0]),
+            ("ALLOY", "LLAMA", [1, 2, 1, 0, 0]),
+            ("CIGAR", "CIGAR", [2, 2, 2, 2, 2]),
+        ];
+        for (guess, answer, expected) in pairs {
+            assert_eq!(wordle_highlight(&chars(guess), &chars(answer)), expected);
+            assert_eq!(
+                pattern_code(&language.encode(guess), &language.encode(answer)),
+                code(&expected),
+                "{} against {}",
+                guess,
+                answer
+            );
+        }
+    }
+
+    #[test]
+    fn each_guess_narrows_the_candidates() {
+        let all = remaining(5, &[]);
+        assert_eq!(all.len(), play_words(5).len());
+
+        let first = [scored("CRANE", "CIGAR")];
+        let after_one = remaining(5, &first);
+        assert!(after_one.contains(&"CIGAR"));
+        assert!(after_one.len() < all.len());
+        for answer in &after_one {
+            assert_eq!(scored("CRANE", answer), first[0]);
+        }
+
+        let second = [scored("CRANE", "CIGAR"), scored("SLOTH", "CIGAR")];
+        let after_two = remaining(5, &second);
+        assert!(after_two.contains(&"CIGAR"));
+        assert!(after_two.len() < after_one.len());
+        assert!(after_two.iter().all(|w| after_one.contains(w)));
+    }
+
+    #[test]
+    fn rank_puts_the_most_telling_guess_first() {
+        let candidates = remaining(5, &[scored("CRANE", "CIGAR")]);
+        let ranked = rank(5, &candidates, false, 10);
+        assert_eq!(ranked.len(), 10);
+        let most = (candidates.len() as f64).log2();
+        assert!(ranked.iter().all(|r| r.bits <= most + 1e-9));
+        for r in &ranked {
+            assert!((r.bits - entropy(r.word, &candidates)).abs() < 1e-9);
+        }
+        for c in &candidates {
+            assert!(entropy(c, &candidates) <= ranked[0].bits + 1.0 / candidates.len() as f64);
+        }
+
+        // hard mode only plays words that may be the answer
+        let hard = rank(5, &candidates, true, 10);
+        assert!(hard.iter().all(|r| candidates.contains(&r.word)));
+
+        // a single candidate is the answer
+        assert_eq!(rank(5, &["CIGAR"], false, 1)[0].word, "CIGAR");
+    }
+}
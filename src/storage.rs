@@ -1,58 +1,757 @@
 use regex::Regex;
-use std::fs::{read_to_string, write};
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-fn load_rewordle(default_data: Vec<String>) -> Vec<String> {
-    // three exact formats
-    let re_one = Regex::new(r"^\d+$").unwrap();
-    let re_two = Regex::new(r"^\d+:\d+$").unwrap();
-    let re_seven = Regex::new(r"^\d+(:\d+){6}$").unwrap();
+use crate::calendar::today;
+use crate::game::{
+    board_attempts, main_mode, plays_archive, ABSURDLE_ROWS, ATTEMPTS, BOARDS, MAX_ATTEMPTS,
+    MIN_ATTEMPTS,
+};
+use crate::language::{language, uppercase};
+use crate::words::{play_words, LETTERS, MAX_LETTERS, MIN_LETTERS, PLAY_WORDS};
+use crate::{AppState, Daily, GameStatus, Mode};
 
-    if Path::new(".rewordle").exists() {
-        match read_to_string(".rewordle") {
-            Ok(status) => {
-                let lines: Vec<String> = status.lines().map(|s| s.trim().to_string()).collect();
+static SAVE_VERSION: u32 = 2; // bump when SaveData changes shape
+static SAVE_ENV: &str = "REWORDLE_SAVE_FILE"; // overrides the save location
+static LEGACY_FILE: &str = ".rewordle"; // saves used to live in the working directory
 
-                let all_valid = lines
+static SAVE_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    // tests run on threads of their own, each saving to its own directory
+    static TEST_SAVE_PATH: std::cell::Cell<Option<&'static Path>> =
+        const { std::cell::Cell::new(None) };
+}
+
+// -----------------------------
+// Location
+// -----------------------------
+
+// Uses `path` for this run instead of the default location
+// Has no effect once the save file was accessed
+pub fn set_save_path(path: PathBuf) {
+    SAVE_PATH.set(path).ok();
+}
+
+// Uses `path` for the saves of the calling thread, in tests
+#[cfg(test)]
+pub fn set_test_save_path(path: PathBuf) {
+    TEST_SAVE_PATH.set(Some(Box::leak(path.into_boxed_path())));
+}
+
+// The save file: an explicit path, the environment override,
+// or rewordle/save.json in the platform data directory
+// ($XDG_DATA_HOME on Linux)
+pub fn save_path() -> &'static Path {
+    #[cfg(test)]
+    if let Some(path) = TEST_SAVE_PATH.get() {
+        return path;
+    }
+    SAVE_PATH.get_or_init(|| {
+        if let Some(path) = std::env::var_os(SAVE_ENV).filter(|p| !p.is_empty()) {
+            return PathBuf::from(path);
+        }
+
+        match dirs::data_dir() {
+            Some(dir) => dir.join("rewordle").join("save.json"),
+            None => PathBuf::from(LEGACY_FILE),
+        }
+    })
+}
+
+// -----------------------------
+// Save Data
+// -----------------------------
+
+pub fn default_letters() -> usize {
+    LETTERS
+}
+
+pub fn default_attempts() -> usize {
+    ATTEMPTS
+}
+
+pub fn default_boards() -> usize {
+    1
+}
+
+// Key of a board configuration in the stats of the others,
+// e.g. "6x8", "5x9x4" for four boards, "french:5x6" for the words of a
+// language pack or "/home/me/gloss.txt:5x6" for those of a word list
+pub fn config_key(word_list: &str, letters: usize, attempts: usize, boards: usize) -> String {
+    let size = match boards {
+        1 => format!("{}x{}", letters, attempts),
+        _ => format!("{}x{}x{}", letters, attempts, boards),
+    };
+    match word_list {
+        "" => size,
+        list => format!("{}:{}", list, size),
+    }
+}
+
+// Results of a board configuration while another one is played
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub results: Vec<u32>,  // wins per attempt, last entry for losses
+    pub streak: (u32, u32), // (current_streak, max_streak)
+    pub hard_wins: u32,     // games won in hard mode
+}
+
+impl Stats {
+    pub fn new(attempts: usize) -> Self {
+        Self {
+            results: vec![0; attempts + 1],
+            streak: (0, 0),
+            hard_wins: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SaveData {
+    pub version: u32,
+    pub results: Vec<u32>,    // wins per attempt, last entry for losses
+    pub streak: (u32, u32),   // (current_streak, max_streak)
+    pub word_index: usize,    // next word to play
+    pub hard_mode: bool,      // guesses must reuse revealed hints
+    pub hard_wins: u32,       // games won in hard mode
+    pub guesses: Vec<String>, // guesses of the game in progress
+    #[serde(default)]
+    pub started_at: Option<u64>, // unix time the game in progress started
+    #[serde(default)]
+    pub mode: Mode, // where answers come from
+    #[serde(default)]
+    pub answer_index: Option<usize>, // answer of a game in progress outside the archive
+    #[serde(default)]
+    pub missed: Vec<usize>, // words still to replay in missed mode
+    #[serde(default)]
+    pub passes: u32, // completed runs through the archive
+    #[serde(default)]
+    pub practice_seed: Option<u64>, // seed of the practice words
+    #[serde(default)]
+    pub practice_games: u64, // practice words drawn from the seed so far
+    #[serde(default)]
+    pub daily: Daily, // board and streak of the daily mode
+    #[serde(default = "default_letters")]
+    pub letters: usize, // letters per word
+    #[serde(default = "default_attempts")]
+    pub attempts: usize, // guesses per game
+    #[serde(default)]
+    pub stats: BTreeMap<String, Stats>, // results of the other configurations
+    #[serde(default = "default_boards")]
+    pub boards: usize, // answers played at once
+    #[serde(default)]
+    pub answers: Vec<usize>, // answers of a game of several boards in progress
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub word_list: String, // language pack or path of the word list, empty for English
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub answer_words: Vec<String>, // answers of the game in progress on a word list
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        Self {
+            version: SAVE_VERSION,
+            results: vec![0; 7],
+            streak: (0, 0),
+            word_index: 0,
+            hard_mode: false,
+            hard_wins: 0,
+            guesses: Vec::new(),
+            started_at: None,
+            mode: Mode::Archive,
+            answer_index: None,
+            missed: Vec::new(),
+            passes: 0,
+            practice_seed: None,
+            practice_games: 0,
+            daily: Daily::default(),
+            letters: LETTERS,
+            attempts: ATTEMPTS,
+            stats: BTreeMap::new(),
+            boards: 1,
+            answers: Vec::new(),
+            word_list: String::new(),
+            answer_words: Vec::new(),
+        }
+    }
+}
+
+impl SaveData {
+    // Stats along with the guesses of a game still in progress,
+    // so quitting mid-game resumes the same board
+    pub fn from_state(state: &AppState) -> Self {
+        let in_play = state.game == GameStatus::InPlay && !state.complete;
+        // the daily board is saved with the daily data
+        let in_play_guesses = in_play && state.mode != Mode::Daily;
+        let guesses = if in_play_guesses {
+            state.status[..state.in_play]
+                .iter()
+                .map(|row| row.iter().collect())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            version: SAVE_VERSION,
+            results: state.results.clone(),
+            streak: state.streak,
+            word_index: state.word_index,
+            hard_mode: state.hard_mode,
+            hard_wins: state.hard_wins,
+            guesses,
+            started_at: in_play.then_some(state.started_at),
+            mode: state.mode,
+            // only the archive finds its word again from word_index
+            answer_index: (in_play_guesses && state.mode != Mode::Archive)
+                .then_some(state.answer_index),
+            missed: state.missed.clone(),
+            passes: state.passes,
+            practice_seed: state.practice_seed,
+            practice_games: state.practice_games,
+            daily: state.daily.clone(),
+            letters: state.letters,
+            attempts: state.attempts,
+            stats: state.stats.clone(),
+            boards: state.boards,
+            answers: if in_play_guesses && state.boards > 1 {
+                state.answers.clone()
+            } else {
+                Vec::new()
+            },
+            word_list: language().list_key(),
+            // a word list may be edited before the next start
+            answer_words: if in_play_guesses && language().has_list() && state.boards > 1 {
+                let words = play_words(state.letters);
+                state
+                    .answers
                     .iter()
-                    .all(|s| re_one.is_match(s) || re_two.is_match(s) || re_seven.is_match(s));
+                    .map(|&i| words[i].to_string())
+                    .collect()
+            } else if in_play_guesses && language().has_list() {
+                vec![state.answer.to_string()]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    // A game left with guesses, today's daily board among them until it is
+    // won or out of guesses
+    pub fn in_progress(&self) -> bool {
+        let daily = &self.daily;
+        let daily_over = daily.guesses.len() >= ATTEMPTS
+            || daily.guesses.last().is_some_and(|g| {
+                language().same(g, PLAY_WORDS[daily.day.unwrap_or(0) % PLAY_WORDS.len()])
+            });
+        let daily_left = self.mode == Mode::Daily
+            && daily.day == Some(today())
+            && !daily.guesses.is_empty()
+            && !daily_over;
+        !self.guesses.is_empty() || daily_left
+    }
 
-                if all_valid {
-                    lines
-                } else {
-                    default_data
-                }
+    // Plays another board configuration or word list: the results of the
+    // current one are put aside and those of the new one taken out, the game
+    // in progress is abandoned and modes of the archive are left
+    pub fn switch_config(
+        &mut self,
+        word_list: &str,
+        letters: usize,
+        attempts: usize,
+        boards: usize,
+    ) {
+        if (word_list, letters, attempts, boards)
+            != (&*self.word_list, self.letters, self.attempts, self.boards)
+        {
+            self.swap_stats(word_list, letters, attempts, boards);
+        }
+        self.fit_mode();
+    }
+
+    fn swap_stats(&mut self, word_list: &str, letters: usize, attempts: usize, boards: usize) {
+        let current = Stats {
+            results: std::mem::take(&mut self.results),
+            streak: self.streak,
+            hard_wins: self.hard_wins,
+        };
+        self.stats.insert(
+            config_key(&self.word_list, self.letters, self.attempts, self.boards),
+            current,
+        );
+        let next = self
+            .stats
+            .remove(&config_key(word_list, letters, attempts, boards))
+            .unwrap_or_else(|| Stats::new(attempts));
+        self.results = next.results;
+        self.streak = next.streak;
+        self.hard_wins = next.hard_wins;
+
+        self.word_list = word_list.to_string();
+        self.letters = letters;
+        self.attempts = attempts;
+        self.boards = boards;
+        self.answer_index = None;
+        self.answers.clear();
+        self.answer_words.clear();
+        self.guesses.clear();
+        self.started_at = None;
+    }
+
+    // The file of a word list may have been edited since its game in
+    // progress was saved: a game whose words moved or went is dropped
+    // rather than reported
+    fn drop_edited_game(mut self) -> Self {
+        let language = language();
+        if !language.has_list() || self.word_list != language.list_key() {
+            return self;
+        }
+        let indexes = match self.answer_index {
+            _ if !self.answers.is_empty() => self.answers.clone(),
+            Some(index) => vec![index],
+            None => return self,
+        };
+        let words = play_words(self.letters);
+        let edited = indexes
+            .iter()
+            .enumerate()
+            .any(|(n, &i)| match words.get(i) {
+                None => true,
+                // saves of before the words were kept only have the index
+                Some(word) => (self.answer_words.get(n)).is_some_and(|w| !language.same(w, word)),
+            });
+        if edited {
+            self.answer_index = None;
+            self.answers.clear();
+            self.answer_words.clear();
+            self.guesses.clear();
+            self.started_at = None;
+        }
+        self
+    }
+
+    // Leaves the modes the board size can not play for its counted games:
+    // the archive, or the shuffle of sizes without one. A save of an older
+    // version may have other sizes in the archive mode, its game carries on.
+    fn fit_mode(&mut self) {
+        let archive = plays_archive(self.letters, self.boards);
+        let leave = match self.mode {
+            Mode::Archive => !archive,
+            Mode::Shuffle => archive,
+            Mode::Missed | Mode::Pick | Mode::Daily => !archive,
+            // absurdle plays a single board
+            Mode::Absurdle => self.boards > 1,
+            Mode::Practice => false,
+        };
+        if leave {
+            self.mode = main_mode(self.letters, self.boards);
+        }
+    }
+
+    // Rejects data that would break the game rather than fixing it up
+    fn validate(self) -> Result<Self, StorageError> {
+        if !(MIN_LETTERS..=MAX_LETTERS).contains(&self.letters) {
+            return Err(StorageError::Invalid(format!(
+                "{} letters, expected {} to {}",
+                self.letters, MIN_LETTERS, MAX_LETTERS
+            )));
+        }
+        if !BOARDS.contains(&self.boards) {
+            return Err(StorageError::Invalid(format!("{} boards", self.boards)));
+        }
+        if self.boards > 1 && self.attempts != board_attempts(self.boards) {
+            return Err(StorageError::Invalid(format!(
+                "{} attempts, {} boards have {}",
+                self.attempts,
+                self.boards,
+                board_attempts(self.boards)
+            )));
+        }
+        if self.boards == 1 && !(MIN_ATTEMPTS..=MAX_ATTEMPTS).contains(&self.attempts) {
+            return Err(StorageError::Invalid(format!(
+                "{} attempts, expected {} to {}",
+                self.attempts, MIN_ATTEMPTS, MAX_ATTEMPTS
+            )));
+        }
+        if self.results.len() != self.attempts + 1 {
+            return Err(StorageError::Invalid(format!(
+                "expected {} results, found {}",
+                self.attempts + 1,
+                self.results.len()
+            )));
+        }
+        for (key, stats) in &self.stats {
+            let size = key.rsplit(':').next().unwrap_or_default();
+            let attempts = size.split('x').nth(1).and_then(|a| a.parse::<usize>().ok());
+            if attempts.map(|a| a + 1) != Some(stats.results.len()) {
+                return Err(StorageError::Invalid(format!(
+                    "{} results for configuration \"{}\"",
+                    stats.results.len(),
+                    key
+                )));
             }
-            Err(_) => default_data,
         }
-    } else {
-        default_data
+        // absurdle plays on past the attempts of the board size
+        let rows = match self.mode {
+            Mode::Absurdle => ABSURDLE_ROWS,
+            _ => self.attempts,
+        };
+        if self.guesses.len() >= rows {
+            return Err(StorageError::Invalid(format!(
+                "{} guesses can not be a game in progress",
+                self.guesses.len()
+            )));
+        }
+        if self.word_index > PLAY_WORDS.len() {
+            return Err(StorageError::Invalid(format!(
+                "word index {} is past the last word ({})",
+                self.word_index,
+                PLAY_WORDS.len()
+            )));
+        }
+        // the words of another language are not loaded, its game is left on switching
+        let current = self.word_list == language().list_key();
+        let words = play_words(self.letters);
+        if let Some(index) = self
+            .answer_index
+            .iter()
+            .chain(&self.answers)
+            .find(|&&i| current && i >= words.len())
+        {
+            return Err(StorageError::Invalid(format!(
+                "word index {} is past the last word ({})",
+                index,
+                words.len()
+            )));
+        }
+        if let Some(index) = self.missed.iter().find(|&&i| i >= PLAY_WORDS.len()) {
+            return Err(StorageError::Invalid(format!(
+                "word index {} is past the last word ({})",
+                index,
+                PLAY_WORDS.len()
+            )));
+        }
+        if !self.answers.is_empty() && self.answers.len() != self.boards {
+            return Err(StorageError::Invalid(format!(
+                "{} answers for {} boards",
+                self.answers.len(),
+                self.boards
+            )));
+        }
+        let has_answer = match self.mode {
+            _ if self.boards > 1 => !self.answers.is_empty(),
+            Mode::Archive if self.letters == LETTERS && self.word_list.is_empty() => {
+                self.word_index < PLAY_WORDS.len()
+            }
+            Mode::Daily => false,
+            _ => self.answer_index.is_some(),
+        };
+        if !self.guesses.is_empty() && !has_answer {
+            return Err(StorageError::Invalid(
+                "guesses without a word in play".to_string(),
+            ));
+        }
+        if self.daily.guesses.len() > MAX_ATTEMPTS {
+            return Err(StorageError::Invalid(format!(
+                "{} daily guesses",
+                self.daily.guesses.len()
+            )));
+        }
+        // the daily word always has five letters of English
+        let bad_guess = |g: &&String, letters: usize| {
+            g.chars().count() != letters
+                || !g.chars().all(|c| c.is_alphabetic() && uppercase(c) == c)
+        };
+        let bad_letter = |g: &&String| {
+            current
+                && g.chars()
+                    .any(|c| language().letter(&c.to_string()) != Some(c))
+        };
+        if let Some(guess) = (self.guesses.iter())
+            .find(|g| bad_guess(g, self.letters) || bad_letter(g))
+            .or_else(|| self.daily.guesses.iter().find(|g| bad_guess(g, LETTERS)))
+        {
+            return Err(StorageError::Invalid(format!("bad guess \"{}\"", guess)));
+        }
+
+        Ok(Self {
+            version: SAVE_VERSION,
+            ..self
+        })
     }
 }
 
-pub fn save(results: &[u32], streak: (u32, u32), word_index: usize) -> std::io::Result<()> {
-    let content = format!(
-        "{}\n{}:{}\n{}",
-        results
-            .iter()
-            .map(|v| v.to_string())
-            .collect::<Vec<_>>()
-            .join(":"),
-        streak.0,
-        streak.1,
-        word_index
-    );
-    write(".rewordle", content)
-}
-
-pub fn read() -> Vec<String> {
-    let default_data: Vec<String> = vec![
-        "0:0:0:0:0:0:0".to_string(),
-        "0:0".to_string(),
-        "0".to_string(),
+// Only the version, read first so a newer file is reported as such
+// rather than as whatever field it no longer has
+#[derive(Deserialize)]
+struct VersionProbe {
+    version: u32,
+}
+
+// -----------------------------
+// Errors
+// -----------------------------
+
+#[derive(Debug)]
+pub enum StorageError {
+    Io(std::io::Error),             // file could not be read or written
+    Parse(String),                  // file is not valid save data
+    Invalid(String),                // file parsed but holds impossible values
+    Version(u32),                   // file written by a newer rewordle
+    Legacy(usize, String),          // line of the old format that does not match
+    At(PathBuf, Box<StorageError>), // any of the above, in this file
+}
+
+impl StorageError {
+    fn at(self, path: &Path) -> Self {
+        StorageError::At(path.to_path_buf(), Box::new(self))
+    }
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StorageError::Io(e) => write!(f, "could not be accessed: {}", e),
+            StorageError::Parse(e) => write!(f, "is malformed: {}", e),
+            StorageError::Invalid(e) => write!(f, "is invalid: {}", e),
+            StorageError::Version(v) => write!(
+                f,
+                "is version {}, this rewordle only reads up to version {}",
+                v, SAVE_VERSION
+            ),
+            StorageError::Legacy(n, line) => write!(f, "line {} is malformed: \"{}\"", n, line),
+            StorageError::At(path, e) => write!(f, "{} {}", path.display(), e),
+        }
+    }
+}
+
+impl From<std::io::Error> for StorageError {
+    fn from(e: std::io::Error) -> Self {
+        StorageError::Io(e)
+    }
+}
+
+// -----------------------------
+// Legacy Format
+// -----------------------------
+
+// Line based format used before versioning:
+// results, streak and word index
+fn migrate_legacy(content: &str) -> Result<SaveData, StorageError> {
+    let formats = [
+        Regex::new(r"^\d+(:\d+){6}$").unwrap(), // results
+        Regex::new(r"^\d+:\d+$").unwrap(),      // streak
+        Regex::new(r"^\d+$").unwrap(),          // word index
     ];
 
-    let data = load_rewordle(default_data);
+    let lines: Vec<&str> = content.lines().map(|s| s.trim()).collect();
+    for (n, (line, format)) in lines.iter().zip(&formats).enumerate() {
+        if !format.is_match(line) {
+            return Err(StorageError::Legacy(n + 1, line.to_string()));
+        }
+    }
+    if lines.len() != formats.len() {
+        return Err(StorageError::Parse(format!(
+            "expected {} lines, found {}",
+            formats.len(),
+            lines.len()
+        )));
+    }
+
+    let numbers = |s: &str| -> Vec<u32> { s.split(':').map(|v| v.parse().unwrap_or(0)).collect() };
+    let streak = numbers(lines[1]);
+
+    SaveData {
+        version: SAVE_VERSION,
+        results: numbers(lines[0]),
+        streak: (streak[0], streak[1]),
+        word_index: lines[2].parse().unwrap_or(0),
+        ..SaveData::default()
+    }
+    .validate()
+}
+
+// -----------------------------
+// Read / Write
+// -----------------------------
 
-    data
+pub fn save(data: &SaveData) -> Result<(), StorageError> {
+    let content =
+        serde_json::to_string_pretty(data).map_err(|e| StorageError::Parse(e.to_string()))?;
+
+    let path = save_path();
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        create_dir_all(dir).map_err(|e| StorageError::from(e).at(dir))?;
+    }
+    write(path, content).map_err(|e| StorageError::from(e).at(path))
+}
+
+pub fn save_state(state: &AppState) -> Result<(), StorageError> {
+    save(&SaveData::from_state(state))
+}
+
+// Current format or the old line format
+fn parse(content: &str) -> Result<SaveData, StorageError> {
+    if content.trim_start().starts_with('{') {
+        let probe = serde_json::from_str::<VersionProbe>(content)
+            .map_err(|e| StorageError::Parse(e.to_string()))?;
+        if probe.version > SAVE_VERSION {
+            return Err(StorageError::Version(probe.version));
+        }
+
+        serde_json::from_str::<SaveData>(content)
+            .map_err(|e| StorageError::Parse(e.to_string()))?
+            .drop_edited_game()
+            .validate()
+    } else {
+        migrate_legacy(content)
+    }
+}
+
+// Reads one file, rewriting it to the save path when not in the current format
+fn load(path: &Path) -> Result<SaveData, StorageError> {
+    let content = read_to_string(path).map_err(|e| StorageError::from(e).at(path))?;
+    let data = parse(&content).map_err(|e| e.at(path))?;
+
+    if path != save_path() || !content.trim_start().starts_with('{') {
+        save(&data)?;
+    }
+    Ok(data)
+}
+
+// No file means a new player, anything unreadable is reported
+pub fn read() -> Result<SaveData, StorageError> {
+    let path = save_path();
+    if path.exists() {
+        return load(path);
+    }
+
+    // one-time pick up of a save left in the working directory
+    let legacy = Path::new(LEGACY_FILE);
+    if legacy.exists() {
+        return load(legacy);
+    }
+
+    Ok(SaveData::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_three_line_file_is_migrated() {
+        let data = parse("3:5:2:1:0:0:4\n2:7\n42\n").unwrap();
+        assert_eq!(data.version, SAVE_VERSION);
+        assert_eq!(data.results, [3, 5, 2, 1, 0, 0, 4]);
+        assert_eq!(data.streak, (2, 7));
+        assert_eq!(data.word_index, 42);
+        assert_eq!(
+            SaveData {
+                results: data.results.clone(),
+                streak: data.streak,
+                word_index: data.word_index,
+                ..SaveData::default()
+            },
+            data
+        );
+    }
+
+    #[test]
+    fn other_line_files_are_reported() {
+        // hard mode and guesses were never saved in the line format
+        assert!(matches!(
+            parse("0:0:0:0:0:0:0\n0:0\n0\n0\n1\nCRANE\n"),
+            Err(StorageError::Parse(_))
+        ));
+        assert!(matches!(
+            parse("0:0:0:0:0:0:0\n0:0\n"),
+            Err(StorageError::Parse(_))
+        ));
+        assert!(matches!(
+            parse("0:0:0:0:0:0:0\n0-0\n0\n"),
+            Err(StorageError::Legacy(2, line)) if line == "0-0"
+        ));
+    }
+
+    #[test]
+    fn a_first_version_save_is_read() {
+        let content = r#"{
+            "version": 1,
+            "results": [0, 1, 2, 0, 0, 0, 1],
+            "streak": [2, 3],
+            "word_index": 4,
+            "hard_mode": true,
+            "hard_wins": 1,
+            "guesses": ["CRANE"]
+        }"#;
+        let data = parse(content).unwrap();
+        assert_eq!(data.version, SAVE_VERSION);
+        assert_eq!(data.guesses, ["CRANE"]);
+        assert_eq!(data.mode, Mode::Archive);
+        assert_eq!((data.letters, data.attempts, data.boards), (5, 6, 1));
+    }
+
+    #[test]
+    fn a_newer_save_is_reported() {
+        let content = format!(r#"{{"version": {}, "renamed": []}}"#, SAVE_VERSION + 1);
+        assert!(matches!(
+            parse(&content),
+            Err(StorageError::Version(v)) if v == SAVE_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn a_save_round_trips() {
+        let data = SaveData {
+            results: vec![1, 0, 3, 0, 0, 0, 2],
+            streak: (1, 4),
+            word_index: 12,
+            guesses: vec!["CRANE".to_string(), "SLOTH".to_string()],
+            ..SaveData::default()
+        };
+        let content = serde_json::to_string_pretty(&data).unwrap();
+        assert_eq!(parse(&content).unwrap(), data);
+    }
+
+    #[test]
+    fn impossible_values_are_rejected() {
+        let invalid = |data: SaveData| matches!(data.validate(), Err(StorageError::Invalid(_)));
+        assert!(invalid(SaveData {
+            results: vec![0; 6],
+            ..SaveData::default()
+        }));
+        assert!(invalid(SaveData {
+            letters: MAX_LETTERS + 1,
+            ..SaveData::default()
+        }));
+        assert!(invalid(SaveData {
+            guesses: vec!["CRANE".to_string(); ATTEMPTS],
+            ..SaveData::default()
+        }));
+        assert!(invalid(SaveData {
+            guesses: vec!["crane".to_string()],
+            ..SaveData::default()
+        }));
+        assert!(invalid(SaveData {
+            guesses: vec!["CRAN".to_string()],
+            ..SaveData::default()
+        }));
+        assert!(invalid(SaveData {
+            word_index: PLAY_WORDS.len() + 1,
+            ..SaveData::default()
+        }));
+        assert!(invalid(SaveData {
+            mode: Mode::Practice,
+            guesses: vec!["CRANE".to_string()],
+            ..SaveData::default()
+        }));
+        assert!(SaveData::default().validate().is_ok());
+    }
 }
@@ -0,0 +1,209 @@
+// Games played headless: scripted input goes through the same elements and
+// event loop as in a terminal, then the drawn cells are checked
+
+use std::fs::{read_to_string, remove_dir_all};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::backend::{install, HeadlessBackend, Input};
+use crate::config::{Config, Timings};
+use crate::elements::{button_share, APP_HEIGHT, APP_WIDTH};
+use crate::language::language;
+use crate::storage::{set_test_save_path, SaveData};
+use crate::theme::Theme;
+use crate::tui_engine::{self, Glyph, Style};
+use crate::{exit_ui, rewordle_screen, start_state, AppState, GameStatus};
+
+static WIDTH: u16 = 100;
+static HEIGHT: u16 = 30;
+static TICKS: usize = 20; // ticks after a guess, for its reveal to play out
+
+// A directory of its own for the save and history of a test, out of the
+// player's and removed once the test is over
+struct SaveDir(PathBuf);
+
+impl SaveDir {
+    fn new() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let n = NEXT.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("rewordle-test-{}-{}", std::process::id(), n));
+        set_test_save_path(dir.join("save.json"));
+        Self(dir)
+    }
+}
+
+impl Drop for SaveDir {
+    fn drop(&mut self) {
+        remove_dir_all(&self.0).ok();
+    }
+}
+
+// A new player on the first archive word, CIGAR, with animations off
+fn config(theme: &str) -> Config {
+    Config {
+        theme: Theme::named(theme).unwrap(),
+        tick_rate: Duration::from_millis(1),
+        timings: Timings {
+            flip: Duration::ZERO,
+            shake: Duration::ZERO,
+        },
+        ..Config::default()
+    }
+}
+
+// Types a word and submits it
+fn guess(backend: &mut HeadlessBackend, word: &str) {
+    backend.type_text(word);
+    backend.script([Some(Input::key("enter"))]);
+    backend.script(std::iter::repeat_n(None, TICKS));
+}
+
+// Runs the game until the script is over, returns its state and the screen
+fn play(config: &Config, backend: HeadlessBackend) -> (AppState, Vec<Vec<Glyph>>) {
+    let grid = backend.grid();
+    install(backend);
+
+    let state = start_state(SaveData::default(), config);
+    let keys = config.layout.keyboard(&language().alphabet);
+    let elements = rewordle_screen::build(&keys);
+    let keymap = state.keys.keymap();
+    let state = tui_engine::run(
+        state,
+        elements,
+        Some(config.tick_rate),
+        Some(&keymap),
+        Some(&exit_ui),
+    );
+
+    let cells = grid.borrow().clone();
+    (state, cells)
+}
+
+// Where `text` first shows on screen, as (x, y)
+fn find(cells: &[Vec<Glyph>], text: &str) -> Option<(usize, usize)> {
+    let text: Vec<char> = text.chars().collect();
+    HeadlessBackend::text(cells)
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| {
+            let row: Vec<char> = row.chars().collect();
+            let x = row.windows(text.len()).position(|w| w == text)?;
+            Some((x, y))
+        })
+}
+
+// Styles of the letters of a board row showing `word`
+fn tiles(cells: &[Vec<Glyph>], word: &str) -> Vec<Style> {
+    let row: String = word.chars().map(|c| format!("│ {} │", c)).collect();
+    let (x, y) = find(cells, &row).unwrap_or_else(|| panic!("no row {}", word));
+    (0..word.len())
+        .map(|i| cells[y][x + 2 + i * 5].style)
+        .collect()
+}
+
+// Style of a letter of the on-screen keyboard
+fn key(cells: &[Vec<Glyph>], letter: char) -> Style {
+    let (x, y) = find(cells, &format!("│{}│", letter)).unwrap();
+    cells[y][x + 1].style
+}
+
+#[test]
+fn cigar_is_won_on_the_second_guess() {
+    let saves = SaveDir::new();
+    let config = config("classic");
+    let theme = config.theme;
+    let mut backend = HeadlessBackend::new(WIDTH, HEIGHT);
+    guess(&mut backend, "crane");
+    guess(&mut backend, "cigar");
+    let (state, cells) = play(&config, backend);
+
+    assert_eq!(state.game, GameStatus::Won);
+    assert_eq!(state.answer, "CIGAR");
+
+    let bg = |styles: Vec<Style>| styles.iter().map(|s| s.bg).collect::<Vec<_>>();
+    let (exact, exists, absent) = (theme.exact.bg, theme.exists.bg, theme.absent.bg);
+    assert_eq!(
+        bg(tiles(&cells, "CRANE")),
+        [exact, exists, exists, absent, absent]
+    );
+    assert_eq!(bg(tiles(&cells, "CIGAR")), [exact; 5]);
+
+    for letter in "CIGAR".chars() {
+        assert_eq!(key(&cells, letter).bg, exact, "key {}", letter);
+    }
+    for letter in "NE".chars() {
+        assert_eq!(key(&cells, letter).bg, absent, "key {}", letter);
+    }
+    assert_eq!(key(&cells, 'Q'), Style::default());
+
+    for stat in ["Played: 1", "Win %: 100", "Streak: 1", "Max Streak: 1"] {
+        assert!(find(&cells, stat).is_some(), "no \"{}\"", stat);
+    }
+
+    // the one game of this test, the others save elsewhere
+    let history = read_to_string(saves.0.join("history.jsonl")).unwrap();
+    assert_eq!(history.lines().count(), 1);
+}
+
+#[test]
+fn monochrome_marks_letters_without_color() {
+    let _saves = SaveDir::new();
+    let config = config("monochrome");
+    let mut backend = HeadlessBackend::new(WIDTH, HEIGHT);
+    guess(&mut backend, "crane");
+    let (state, cells) = play(&config, backend);
+
+    assert_eq!(state.game, GameStatus::InPlay);
+    let styles = tiles(&cells, "CRANE");
+    assert!(styles.iter().all(|s| s.fg.is_none() && s.bg.is_none()));
+    let marks: Vec<(bool, bool)> = styles.iter().map(|s| (s.bold, s.underline)).collect();
+    assert_eq!(
+        marks,
+        [
+            (true, true),
+            (false, true),
+            (false, true),
+            (false, false),
+            (false, false)
+        ]
+    );
+    assert!(key(&cells, 'C').bold && key(&cells, 'C').underline);
+}
+
+#[test]
+fn a_word_not_in_the_list_stays_in_play() {
+    let _saves = SaveDir::new();
+    let config = config("classic");
+    let mut backend = HeadlessBackend::new(WIDTH, HEIGHT);
+    guess(&mut backend, "cgrai");
+    let (state, cells) = play(&config, backend);
+
+    // the row shakes and keeps the word to be fixed, nothing is colored
+    assert_eq!(state.in_play, 0);
+    assert_eq!(state.status[0].iter().collect::<String>(), "CGRAI");
+    assert!(tiles(&cells, "CGRAI").iter().all(|s| s.bg.is_none()));
+    assert!(key(&cells, 'C').bg.is_none());
+}
+
+#[test]
+fn share_copies_the_grid_of_the_game() {
+    let _saves = SaveDir::new();
+    let config = config("classic");
+    let mut backend = HeadlessBackend::new(WIDTH, HEIGHT);
+    guess(&mut backend, "crane");
+    guess(&mut backend, "cigar");
+    let clipboard = backend.clipboard();
+
+    // the share button shows once the game is over, in the centered window
+    let x = (WIDTH - APP_WIDTH) / 2 + button_share::X;
+    let y = (HEIGHT - APP_HEIGHT) / 2 + button_share::Y;
+    backend.script([Some(Input::click(x, y))]);
+    let (state, _) = play(&config, backend);
+
+    assert_eq!(state.message.as_deref(), Some("Copied to clipboard"));
+    let text = clipboard.borrow();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.first(), Some(&"Wordle 1 2/6"));
+    assert_eq!(lines.last(), Some(&"🟩🟩🟩🟩🟩"));
+}
@@ -0,0 +1,142 @@
+use crate::tui_engine::{Color, Style};
+
+// -----------------------------
+// Theme
+// -----------------------------
+// How the three letter statuses are drawn, on the board, the keyboard
+// and the review alike.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub exact: Style,  // right letter in the right place
+    pub exists: Style, // right letter in another place
+    pub absent: Style, // letter not in the answer
+}
+
+static BLACK: Color = Color::Indexed(16);
+static WHITE: Color = Color::Indexed(231);
+static TRUE_WHITE: Color = Color::Rgb(255, 255, 255);
+
+// Names of the first 16 terminal colors, "bright-" ones from 8 up
+pub static COLOR_NAMES: &[&str] = &[
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+// A palette entry, `bg` behind a letter drawn in `fg`
+const fn paint(fg: Option<Color>, bg: Option<Color>, bold: bool, underline: bool) -> Style {
+    Style {
+        fg,
+        bg,
+        bold,
+        underline,
+        inverse: false,
+    }
+}
+
+// Built-in themes, in the order the theme key goes through them
+pub static THEMES: &[Theme] = &[
+    // the terminal's own green, yellow and grey
+    Theme {
+        name: "classic",
+        exact: paint(None, Some(Color::Indexed(2)), false, false),
+        exists: paint(None, Some(Color::Indexed(3)), false, false),
+        absent: paint(None, Some(Color::Indexed(8)), false, false),
+    },
+    // orange and blue, apart for every kind of color blindness
+    Theme {
+        name: "colorblind",
+        exact: paint(Some(BLACK), Some(Color::Indexed(208)), false, false),
+        exists: paint(Some(WHITE), Some(Color::Indexed(33)), false, false),
+        absent: paint(None, Some(Color::Indexed(8)), false, false),
+    },
+    // bright colors under bold black letters, absent letters white on black
+    Theme {
+        name: "high-contrast",
+        exact: paint(Some(BLACK), Some(Color::Indexed(46)), true, false),
+        exists: paint(Some(BLACK), Some(Color::Indexed(226)), true, false),
+        absent: paint(Some(WHITE), Some(BLACK), false, false),
+    },
+    // no color at all: bold and underlined, underlined, plain
+    Theme {
+        name: "monochrome",
+        exact: paint(None, None, true, true),
+        exists: paint(None, None, false, true),
+        absent: paint(None, None, false, false),
+    },
+    // the colors of the original game, for terminals with 24-bit color
+    Theme {
+        name: "truecolor",
+        exact: paint(
+            Some(TRUE_WHITE),
+            Some(Color::Rgb(106, 170, 100)),
+            true,
+            false,
+        ),
+        exists: paint(
+            Some(TRUE_WHITE),
+            Some(Color::Rgb(201, 180, 88)),
+            true,
+            false,
+        ),
+        absent: paint(
+            Some(TRUE_WHITE),
+            Some(Color::Rgb(120, 124, 126)),
+            true,
+            false,
+        ),
+    },
+];
+
+// A color of the config: 0 to 255, "#rrggbb", or a name such as "blue",
+// "bright-blue" or "grey"
+pub fn color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let part = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::Rgb(part(0)?, part(2)?, part(4)?));
+    }
+    if let Ok(index) = value.parse::<u8>() {
+        return Some(Color::Indexed(index));
+    }
+    let value = value.to_lowercase();
+    if value == "grey" || value == "gray" {
+        return Some(Color::Indexed(8));
+    }
+    let (name, offset) = match value.strip_prefix("bright-") {
+        Some(name) => (name, 8),
+        None => (value.as_str(), 0),
+    };
+    let index = COLOR_NAMES.iter().position(|&n| n == name)?;
+    Some(Color::Indexed(index as u8 + offset))
+}
+
+impl Theme {
+    // A built-in theme by name
+    pub fn named(name: &str) -> Option<Theme> {
+        THEMES.iter().find(|theme| theme.name == name).copied()
+    }
+
+    // The style of a letter status (2 exact, 1 exists, 0 not)
+    pub fn status(&self, status: u8) -> Style {
+        match status {
+            2 => self.exact,
+            1 => self.exists,
+            _ => self.absent,
+        }
+    }
+
+    // The theme after this one in `themes`, back to the first after the last
+    pub fn next(&self, themes: &[Theme]) -> Theme {
+        let index = themes.iter().position(|theme| theme.name == self.name);
+        themes[index.map_or(0, |i| (i + 1) % themes.len())]
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        THEMES[0]
+    }
+}
@@ -1,28 +1,137 @@
 use std::{
-    cell::{Cell, RefCell}, // Interior mutability
-    io::{stdout, Write},   // For printing to terminal
-    rc::Rc,                // Shared ownership of Elements
-    time::Duration,
+    cell::{Cell, RefCell},     // Interior mutability
+    collections::VecDeque,     // Animation frame queue
+    rc::Rc,                    // Shared ownership of Elements
+    time::{Duration, Instant}, // Animation timing
 };
 
-use crossterm::{
-    cursor::MoveTo,
-    execute,
-    terminal::{size, Clear, ClearType}, // Clear terminal
-};
-
-use terminal_style::format::stylable::Stylable;
+use crate::backend; // Terminal access
 
 use crate::event_loop::{start, Listener}; // Event loop runner & callbacks
 
+// -----------------------------
+// Style
+// -----------------------------
+// How a glyph is drawn. Kept as data so styles can be inspected, combined
+// and swapped, escape sequences are only written out by the backend.
+
+/// A terminal color: an index into the 256 color palette, or 24-bit RGB
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Color {
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+/// Colors and attributes of a glyph, the default is the terminal's own
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub underline: bool,
+    pub inverse: bool,
+}
+
+#[allow(dead_code)] // Not every attribute is used by the game
+impl Style {
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    pub fn inverse(mut self) -> Self {
+        self.inverse = true;
+        self
+    }
+
+    /// `over` laid on top: the colors it sets win, attributes add up
+    pub fn patch(self, over: Style) -> Self {
+        Self {
+            fg: over.fg.or(self.fg),
+            bg: over.bg.or(self.bg),
+            bold: self.bold || over.bold,
+            underline: self.underline || over.underline,
+            inverse: self.inverse || over.inverse,
+        }
+    }
+
+    /// SGR sequence setting this style on a reset terminal, empty when plain
+    pub fn sgr(&self) -> String {
+        let mut codes: Vec<String> = Vec::new();
+        if self.bold {
+            codes.push("1".into());
+        }
+        if self.underline {
+            codes.push("4".into());
+        }
+        if self.inverse {
+            codes.push("7".into());
+        }
+        for (layer, color) in [(38, self.fg), (48, self.bg)] {
+            match color {
+                Some(Color::Indexed(n)) => codes.push(format!("{};5;{}", layer, n)),
+                Some(Color::Rgb(r, g, b)) => codes.push(format!("{};2;{};{};{}", layer, r, g, b)),
+                None => {}
+            }
+        }
+
+        if codes.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", codes.join(";"))
+        }
+    }
+}
+
+// -----------------------------
+// Glyph
+// -----------------------------
+
+/// A single terminal cell: one character and its style
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Glyph {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Glyph {
+    pub fn new(ch: char, style: Style) -> Self {
+        Self { ch, style }
+    }
+
+    pub fn blank() -> Self {
+        Self::new(' ', Style::default())
+    }
+}
+
+/// A row of glyphs spelling `text`, all in one style
+pub fn glyphs(text: &str, style: Style) -> Vec<Glyph> {
+    text.chars().map(|ch| Glyph::new(ch, style)).collect()
+}
+
 // -----------------------------
 // Look
 // -----------------------------
-// Represents a 2D grid of cells, each a String (can be multi-character)
+// Represents a 2D grid of glyphs, one per terminal column
 // Use RefCell for interior mutability
 #[derive(Clone, Default)]
 pub struct Look {
-    cells: RefCell<Vec<Vec<String>>>,
+    cells: RefCell<Vec<Vec<Glyph>>>,
 }
 
 impl Look {
@@ -34,7 +143,7 @@ impl Look {
     }
 
     // Borrow cells immutably
-    pub fn cells(&self) -> std::cell::Ref<'_, Vec<Vec<String>>> {
+    pub fn cells(&self) -> std::cell::Ref<'_, Vec<Vec<Glyph>>> {
         self.cells.borrow()
     }
 
@@ -43,16 +152,36 @@ impl Look {
         let new_look = new_look.into();
         self.cells.replace(new_look.cells.into_inner());
     }
+
+    // The same look with `style` laid over every glyph
+    pub fn styled(self, style: Style) -> Self {
+        let mut cells = self.cells.into_inner();
+        for glyph in cells.iter_mut().flatten() {
+            glyph.style = glyph.style.patch(style);
+        }
+        Self {
+            cells: RefCell::new(cells),
+        }
+    }
 }
 
 // -----------------------------
 // Conversions to Look
 // -----------------------------
+// Text converts to plain glyphs, a string of several characters
+// spreads over as many columns
+
+fn plain_row<T: AsRef<str>>(row: &[T]) -> Vec<Glyph> {
+    row.iter()
+        .flat_map(|s| glyphs(s.as_ref(), Style::default()))
+        .collect()
+}
+
 impl From<&str> for Look {
     fn from(s: &str) -> Self {
-        let rows: Vec<Vec<String>> = s
+        let rows: Vec<Vec<Glyph>> = s
             .lines()
-            .map(|line| line.chars().map(|c| c.to_string()).collect())
+            .map(|line| glyphs(line, Style::default()))
             .collect();
         Self {
             cells: RefCell::new(rows),
@@ -69,7 +198,7 @@ impl From<String> for Look {
 impl From<&[String]> for Look {
     fn from(arr: &[String]) -> Self {
         Self {
-            cells: RefCell::new(vec![arr.to_vec()]),
+            cells: RefCell::new(vec![plain_row(arr)]),
         }
     }
 }
@@ -77,7 +206,7 @@ impl From<&[String]> for Look {
 impl From<&[Vec<String>]> for Look {
     fn from(arr: &[Vec<String>]) -> Self {
         Self {
-            cells: RefCell::new(arr.to_vec()),
+            cells: RefCell::new(arr.iter().map(|row| plain_row(row)).collect()),
         }
     }
 }
@@ -85,7 +214,7 @@ impl From<&[Vec<String>]> for Look {
 impl From<&[&str]> for Look {
     fn from(arr: &[&str]) -> Self {
         Self {
-            cells: RefCell::new(vec![arr.iter().map(|s| s.to_string()).collect()]),
+            cells: RefCell::new(vec![plain_row(arr)]),
         }
     }
 }
@@ -93,11 +222,7 @@ impl From<&[&str]> for Look {
 impl From<&[Vec<&str>]> for Look {
     fn from(arr: &[Vec<&str>]) -> Self {
         Self {
-            cells: RefCell::new(
-                arr.iter()
-                    .map(|row| row.iter().map(|s| s.to_string()).collect())
-                    .collect(),
-            ),
+            cells: RefCell::new(arr.iter().map(|row| plain_row(row)).collect()),
         }
     }
 }
@@ -105,7 +230,7 @@ impl From<&[Vec<&str>]> for Look {
 impl From<Vec<String>> for Look {
     fn from(row: Vec<String>) -> Self {
         Self {
-            cells: RefCell::new(vec![row]),
+            cells: RefCell::new(vec![plain_row(&row)]),
         }
     }
 }
@@ -113,7 +238,7 @@ impl From<Vec<String>> for Look {
 impl From<Vec<Vec<String>>> for Look {
     fn from(cells: Vec<Vec<String>>) -> Self {
         Self {
-            cells: RefCell::new(cells),
+            cells: RefCell::new(cells.iter().map(|row| plain_row(row)).collect()),
         }
     }
 }
@@ -121,81 +246,34 @@ impl From<Vec<Vec<String>>> for Look {
 impl From<Vec<Vec<&str>>> for Look {
     fn from(cells: Vec<Vec<&str>>) -> Self {
         Self {
-            cells: RefCell::new(
-                cells
-                    .into_iter()
-                    .map(|row| row.into_iter().map(|s| s.to_string()).collect())
-                    .collect(),
-            ),
+            cells: RefCell::new(cells.iter().map(|row| plain_row(row)).collect()),
         }
     }
 }
 
-// Single-row construction from iterator
-impl<T: ToString> FromIterator<T> for Look {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let row: Vec<String> = iter.into_iter().map(|item| item.to_string()).collect();
+impl From<Vec<Glyph>> for Look {
+    fn from(row: Vec<Glyph>) -> Self {
         Self {
             cells: RefCell::new(vec![row]),
         }
     }
 }
 
-impl<'a> Stylable for &'a Look {
-    type Output = Look;
-
-    fn apply<F>(&self, f: F) -> Self::Output
-    where
-        F: Fn(&str) -> String,
-    {
-        (*self).apply(f)
-    }
-
-    fn apply_result<F, E>(&self, f: F) -> Result<Self::Output, E>
-    where
-        F: Fn(&str) -> Result<String, E>,
-        E: std::fmt::Debug,
-    {
-        (*self).apply_result(f)
-    }
-}
-
-impl Stylable for Look {
-    type Output = Look;
-
-    fn apply<F>(&self, f: F) -> Self::Output
-    where
-        F: Fn(&str) -> String,
-    {
-        let styled = self
-            .cells()
-            .iter()
-            .map(|row| row.iter().map(|s| f(s)).collect::<Vec<_>>())
-            .collect::<Vec<Vec<_>>>();
-
-        Look {
-            cells: RefCell::new(styled),
+impl From<Vec<Vec<Glyph>>> for Look {
+    fn from(cells: Vec<Vec<Glyph>>) -> Self {
+        Self {
+            cells: RefCell::new(cells),
         }
     }
+}
 
-    fn apply_result<F, E>(&self, f: F) -> Result<Self::Output, E>
-    where
-        F: Fn(&str) -> Result<String, E>,
-        E: std::fmt::Debug,
-    {
-        let mut styled_rows = Vec::with_capacity(self.cells().len());
-
-        for row in self.cells().iter() {
-            let mut new_row = Vec::with_capacity(row.len());
-            for s in row {
-                new_row.push(f(s)?);
-            }
-            styled_rows.push(new_row);
+// Single-row construction from iterator
+impl<T: ToString> FromIterator<T> for Look {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let row: Vec<String> = iter.into_iter().map(|item| item.to_string()).collect();
+        Self {
+            cells: RefCell::new(vec![plain_row(&row)]),
         }
-
-        Ok(Look {
-            cells: RefCell::new(styled_rows),
-        })
     }
 }
 
@@ -239,20 +317,37 @@ impl<'a, S> Default for Elements<'a, S> {
     }
 }
 
+// -----------------------------
+// Animation
+// -----------------------------
+// A queue of frames, each a Look shown for a given duration.
+// Frames are advanced by the runner on every loop tick, so an animating
+// element never blocks input or the drawing of other elements.
+#[derive(Default)]
+pub struct Animation {
+    frames: RefCell<VecDeque<(Look, Duration)>>,
+    due: Cell<Option<Instant>>, // when the frame currently shown expires
+}
+
 // -----------------------------
 // Element
 // -----------------------------
+// Callback types
+pub type EventCallback<'a, S> = Box<dyn Fn(&Element<'a, S>, &mut S, &EventData) + 'a>;
+pub type StateCallback<'a, S> = Box<dyn Fn(&Element<'a, S>, &S) + 'a>;
+
 pub struct Element<'a, S> {
-    pub x: Cell<u16>, // Position X
-    pub y: Cell<u16>, // Position Y
-    pub look: Look,   // Visual representation
+    pub x: Cell<u16>,         // Position X
+    pub y: Cell<u16>,         // Position Y
+    pub look: Look,           // Visual representation
+    pub animation: Animation, // Pending animation frames
 
     // Optional callbacks for events
-    pub on_loop: Option<Box<dyn Fn(&Element<'a, S>, &mut S, &EventData) + 'a>>,
-    pub on_keypress: Option<Box<dyn Fn(&Element<'a, S>, &mut S, &EventData) + 'a>>,
-    pub on_move: Option<Box<dyn Fn(&Element<'a, S>, &mut S, &EventData) + 'a>>,
-    pub on_click: Option<Box<dyn Fn(&Element<'a, S>, &mut S, &EventData) + 'a>>,
-    pub on_state: Option<Box<dyn Fn(&Element<'a, S>, &S) + 'a>>,
+    pub on_loop: Option<EventCallback<'a, S>>,
+    pub on_keypress: Option<EventCallback<'a, S>>,
+    pub on_move: Option<EventCallback<'a, S>>,
+    pub on_click: Option<EventCallback<'a, S>>,
+    pub on_state: Option<StateCallback<'a, S>>,
 }
 
 impl<'a, S> Element<'a, S> {
@@ -261,6 +356,7 @@ impl<'a, S> Element<'a, S> {
             x: Cell::new(x),
             y: Cell::new(y),
             look,
+            animation: Animation::default(),
             on_loop: None,
             on_keypress: None,
             on_move: None,
@@ -268,6 +364,54 @@ impl<'a, S> Element<'a, S> {
             on_state: None,
         }
     }
+
+    // Queue frames to be shown one after the other
+    pub fn animate(&self, frames: Vec<(Look, Duration)>) {
+        self.animation.frames.borrow_mut().extend(frames);
+    }
+
+    // True while frames are queued or the last frame is still showing
+    pub fn is_animating(&self) -> bool {
+        !self.animation.frames.borrow().is_empty() || self.animation.due.get().is_some()
+    }
+
+    // Show the next frame if the current one expired
+    // Returns true when the look changed and needs drawing
+    pub fn advance_animation(&self) -> bool {
+        let now = Instant::now();
+        if let Some(due) = self.animation.due.get() {
+            if now < due {
+                return false;
+            }
+        }
+
+        match self.animation.frames.borrow_mut().pop_front() {
+            Some((look, duration)) => {
+                self.look.update(look);
+                self.animation.due.set(Some(now + duration));
+                true
+            }
+            None => {
+                self.animation.due.set(None);
+                false
+            }
+        }
+    }
+
+    // Jump straight to the last frame
+    // Returns true when the look changed and needs drawing
+    pub fn skip_animation(&self) -> bool {
+        let last = self.animation.frames.borrow_mut().drain(..).last();
+        let was_animating = self.animation.due.take().is_some();
+
+        match last {
+            Some((look, _)) => {
+                self.look.update(look);
+                true
+            }
+            None => was_animating,
+        }
+    }
 }
 
 impl<'a, S> Default for Element<'a, S> {
@@ -281,7 +425,7 @@ impl<'a, S> Default for Element<'a, S> {
 // -----------------------------
 
 // Check if mouse is over the element
-// Assumes uniform row widths and single-character cells for precise detection
+// Assumes uniform row widths, each glyph is one column
 pub fn mouse_over<S>(el: &Element<S>, event: &EventData) -> bool {
     // Ensure valid mouse coordinates
     let (mx, my) = match (event.x, event.y) {
@@ -305,71 +449,161 @@ pub fn mouse_over<S>(el: &Element<S>, event: &EventData) -> bool {
 
 /// Returns the current terminal width in columns
 pub fn columns() -> u16 {
-    size()
-        .map(|(cols, _rows)| cols)
-        .expect("Failed to get terminal size")
+    backend::with(|b| b.size()).0
 }
 
 /// Returns the current terminal height in rows
 pub fn rows() -> u16 {
-    size()
-        .map(|(_cols, rows)| rows)
-        .expect("Failed to get terminal size")
+    backend::with(|b| b.size()).1
+}
+
+// -----------------------------
+// Screen Buffer
+// -----------------------------
+// Elements draw into a back buffer. Once per frame `render` compares it
+// with the front buffer (what the terminal currently shows) and writes
+// only the cells that changed.
+
+#[derive(Default)]
+struct Screen {
+    width: u16,
+    height: u16,
+    back: Vec<Vec<Glyph>>,  // what elements drew
+    front: Vec<Vec<Glyph>>, // what the terminal shows
+    cursor: (u16, u16),     // position used by clear_below
+    stale: bool,            // terminal content unknown, clear before next render
+}
+
+impl Screen {
+    // Matches buffers to the terminal size, keeping back buffer content that still fits
+    fn fit(&mut self) {
+        let (width, height) = backend::with(|b| b.size());
+        if (width, height) == (self.width, self.height) {
+            return;
+        }
+
+        let blank_row = vec![Glyph::blank(); width as usize];
+
+        self.back.resize(height as usize, blank_row.clone());
+        for row in self.back.iter_mut() {
+            row.resize(width as usize, Glyph::blank());
+        }
+        self.front = vec![blank_row; height as usize];
+
+        self.width = width;
+        self.height = height;
+        self.stale = true;
+    }
+
+    fn clear_from(&mut self, x: u16, y: u16) {
+        for (row_idx, row) in self.back.iter_mut().enumerate().skip(y as usize) {
+            let start = if row_idx == y as usize { x as usize } else { 0 };
+            for glyph in row.iter_mut().skip(start) {
+                *glyph = Glyph::blank();
+            }
+        }
+    }
+
+    fn put(&mut self, x: u16, y: u16, glyph: Glyph) {
+        if let Some(cell) = self
+            .back
+            .get_mut(y as usize)
+            .and_then(|row| row.get_mut(x as usize))
+        {
+            *cell = glyph;
+        }
+    }
 }
 
-// Moves the terminal cursor to (x, y)
+thread_local! {
+    static SCREEN: RefCell<Screen> = RefCell::new(Screen::default());
+}
+
+// Moves the buffer cursor to (x, y)
 pub fn go_to(x: u16, y: u16) {
-    let mut out = stdout();
-    execute!(out, MoveTo(x, y)).expect("Failed to move cursor");
+    SCREEN.with(|screen| screen.borrow_mut().cursor = (x, y));
 }
 
-// Clears terminal below cursor
+// Clears buffer below cursor
 pub fn clear_below() {
-    let mut out = stdout();
-    execute!(out, Clear(ClearType::FromCursorDown)).expect("Failed to clear terminal below cursor");
+    SCREEN.with(|screen| {
+        let mut screen = screen.borrow_mut();
+        let (x, y) = screen.cursor;
+        screen.clear_from(x, y);
+    });
 }
 
-// Clears entire terminal
+// Clears entire buffer
 pub fn clear_screen() {
-    let mut stdout = stdout();
-    execute!(stdout, Clear(ClearType::All), MoveTo(0, 0)).expect("Failed to clear terminal");
+    SCREEN.with(|screen| {
+        let mut screen = screen.borrow_mut();
+        screen.clear_from(0, 0);
+        screen.cursor = (0, 0);
+    });
+}
+
+// Puts text on the clipboard through the terminal
+pub fn copy_to_clipboard(text: &str) {
+    backend::with(|b| b.copy(text));
+}
+
+// Writes the difference between back and front buffers to the terminal
+pub fn render() {
+    SCREEN.with(|screen| {
+        let mut screen = screen.borrow_mut();
+
+        // terminal resized: its content is unknown, start from a clean slate
+        screen.fit();
+        if screen.stale {
+            backend::with(|b| b.clear());
+            screen.stale = false;
+        }
+
+        let mut changes = Vec::new();
+        for (y, (back_row, front_row)) in screen.back.iter().zip(&screen.front).enumerate() {
+            for (x, (glyph, shown)) in back_row.iter().zip(front_row).enumerate() {
+                if glyph != shown {
+                    changes.push((x as u16, y as u16, *glyph));
+                }
+            }
+        }
+
+        if !changes.is_empty() {
+            backend::with(|b| b.draw(&changes));
+            screen.front = screen.back.clone();
+        }
+    });
 }
 
 // -----------------------------
 // Drawing
 // -----------------------------
 
-// Draws a single element - common use
+// Draws a single element into the back buffer - common use
 pub fn draw<S>(el: &Element<S>) {
     let look_ref = el.look.cells();
-    let mut out = stdout(); // Do not lock stdout. Needed for event capture.
 
-    for (row_idx, row) in look_ref.iter().enumerate() {
-        execute!(out, MoveTo(el.x.get(), el.y.get() + row_idx as u16)).unwrap();
-        for cell in row {
-            write!(out, "{}", cell).unwrap(); // Write each cell directly
-        }
-    }
+    SCREEN.with(|screen| {
+        let mut screen = screen.borrow_mut();
+        screen.fit(); // a draw may come before the first render
 
-    out.flush().unwrap();
+        for (row_idx, row) in look_ref.iter().enumerate() {
+            let y = el.y.get() + row_idx as u16;
+            let mut x = el.x.get();
+
+            for &glyph in row {
+                screen.put(x, y, glyph);
+                x = x.saturating_add(1);
+            }
+        }
+    });
 }
 
 // Draw all top-level elements (no nested elements)
 pub fn draw_all<S>(elements: &Elements<S>) {
-    let mut out = stdout(); // Do not lock stdout. Needed for event capture.
-
     for el in elements.inner.borrow().iter() {
-        let look_ref = el.look.cells();
-
-        for (row_idx, row) in look_ref.iter().enumerate() {
-            execute!(out, MoveTo(el.x.get(), el.y.get() + row_idx as u16)).unwrap();
-            for cell in row {
-                write!(out, "{}", cell).unwrap();
-            }
-        }
+        draw(el);
     }
-
-    out.flush().unwrap();
 }
 
 // -----------------------------
@@ -392,12 +626,24 @@ where
                         cb(el, state, &event);
                     }
                 }
+                // advance animations after callbacks so new frames show right away
+                for el in elements_borrow.iter() {
+                    if el.advance_animation() {
+                        draw(el);
+                    }
+                }
             }
         }),
         on_keypress: Box::new({
             let el_ref = el_ref.clone();
             move |state: &mut S, event| {
                 let elements_borrow = el_ref.iter();
+                // any key skips running animations
+                for el in elements_borrow.iter() {
+                    if el.skip_animation() {
+                        draw(el);
+                    }
+                }
                 for el in elements_borrow.iter() {
                     if let Some(cb) = &el.on_keypress {
                         cb(el, state, &event);
@@ -438,6 +684,8 @@ where
                 }
             }
         }),
+        // everything drawn during the iteration reaches the terminal at once
+        on_frame: Box::new(render),
     };
 
     listeners.push(listener);
@@ -451,6 +699,7 @@ pub fn run<'a, S>(
     state: S,
     elements: Elements<'a, S>,
     tick_rate: Option<Duration>,
+    keymap: Option<&Keymap>,
     alt_exit: Option<&dyn Fn(&S) -> bool>,
 ) -> S
 // <-- return the final state
@@ -465,9 +714,11 @@ where
 
     let tick_rate = tick_rate.unwrap_or_else(|| Duration::from_millis(33));
 
+    let keymap = keymap.cloned().unwrap_or_default();
+
     // simply return the result of start
-    start(state, &mut listeners, tick_rate, alt_exit)
+    start(state, &mut listeners, tick_rate, &keymap, alt_exit)
 }
 
-// Re-export EventData for convenience
-pub use crate::event_loop::EventData;
+// Re-export event types for convenience
+pub use crate::event_loop::{Binding, EventData, Keymap};
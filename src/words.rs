@@ -1,3 +1,5 @@
+use crate::language::language;
+
 pub static PLAY_WORDS: &[&str] = &[
     "CIGAR", "REBUT", "SISSY", "HUMPH", "AWAKE", "BLUSH", "FOCAL", "EVADE", "NAVAL", "SERVE",
     "HEATH", "DWARF", "MODEL", "KARMA", "STINK", "GRADE", "QUIET", "BENCH", "ABATE", "FEIGN",
@@ -1299,3 +1301,2126 @@ pub static VALID_WORDS: &[&str] = &[
     "ZOPPO", "ZORIL", "ZORIS", "ZORRO", "ZOUKS", "ZOWEE", "ZOWIE", "ZULUS", "ZUPAN", "ZUPAS",
     "ZUPPA", "ZURFS", "ZUZIM", "ZYGAL", "ZYGON", "ZYMES", "ZYMIC",
 ];
+pub static WORDS_4: &[&str] = &[
+    "ABLE", "ACID", "AGED", "ALSO", "AREA", "ARMY", "AWAY", "BABY", "BACK", "BALL", "BAND", "BANK",
+    "BASE", "BATH", "BEAR", "BEAT", "BEEN", "BEER", "BELL", "BELT", "BEST", "BILL", "BIRD", "BLOW",
+    "BLUE", "BOAT", "BODY", "BOMB", "BOND", "BONE", "BOOK", "BOOM", "BORN", "BOSS", "BOTH", "BOWL",
+    "BULK", "BURN", "BUSH", "BUSY", "CAFE", "CAKE", "CALL", "CALM", "CAME", "CAMP", "CARD", "CARE",
+    "CART", "CASE", "CASH", "CAST", "CELL", "CHAT", "CHEF", "CHIP", "CITY", "CLAY", "CLUB", "COAL",
+    "COAT", "CODE", "COLD", "COME", "COOK", "COOL", "COPE", "COPY", "CORE", "CORN", "COST", "CREW",
+    "CROP", "CURE", "DARK", "DATA", "DATE", "DAWN", "DEAD", "DEAL", "DEAR", "DEBT", "DECK", "DEEP",
+    "DEER", "DESK", "DIAL", "DIET", "DIRT", "DISH", "DISK", "DIVE", "DOES", "DONE", "DOOR", "DOSE",
+    "DOWN", "DRAW", "DREW", "DROP", "DRUG", "DRUM", "DUAL", "DUCK", "DUST", "DUTY", "EACH", "EARN",
+    "EASE", "EAST", "EASY", "ECHO", "EDGE", "ELSE", "EVEN", "EVER", "EVIL", "EXIT", "FACE", "FACT",
+    "FAIL", "FAIR", "FALL", "FAME", "FARM", "FAST", "FATE", "FEAR", "FEED", "FEEL", "FEET", "FELL",
+    "FELT", "FILE", "FILL", "FILM", "FIND", "FINE", "FIRE", "FIRM", "FISH", "FIVE", "FLAG", "FLAT",
+    "FLED", "FLEW", "FLOW", "FOLK", "FOOD", "FOOT", "FORD", "FORM", "FORT", "FOUR", "FREE", "FROM",
+    "FUEL", "FULL", "FUND", "GAIN", "GAME", "GATE", "GAVE", "GEAR", "GIFT", "GIRL", "GIVE", "GLAD",
+    "GOAL", "GOES", "GOLD", "GOLF", "GONE", "GOOD", "GRAY", "GREW", "GREY", "GRID", "GRIN", "GRIP",
+    "GROW", "GULF", "HAIR", "HALF", "HALL", "HAND", "HANG", "HARD", "HARM", "HATE", "HAVE", "HEAD",
+    "HEAR", "HEAT", "HELD", "HELL", "HELP", "HERE", "HERO", "HIGH", "HILL", "HINT", "HIRE", "HOLD",
+    "HOLE", "HOLY", "HOME", "HOPE", "HORN", "HOST", "HOUR", "HUGE", "HUNG", "HUNT", "HURT", "IDEA",
+    "INCH", "INTO", "IRON", "ITEM", "JAZZ", "JOIN", "JOKE", "JUMP", "JURY", "JUST", "KEEN", "KEEP",
+    "KEPT", "KICK", "KIND", "KING", "KISS", "KNEE", "KNEW", "KNIT", "KNOT", "KNOW", "LACK", "LADY",
+    "LAID", "LAKE", "LAMP", "LAND", "LANE", "LAST", "LATE", "LEAD", "LEAF", "LEAN", "LEFT", "LEND",
+    "LENS", "LESS", "LIFE", "LIFT", "LIKE", "LIME", "LINE", "LINK", "LION", "LIST", "LIVE", "LOAD",
+    "LOAN", "LOCK", "LOGO", "LONG", "LOOK", "LORD", "LOSE", "LOSS", "LOST", "LOUD", "LOVE", "LUCK",
+    "LUNG", "MADE", "MAIL", "MAIN", "MAKE", "MALE", "MALL", "MANY", "MARK", "MASS", "MEAL", "MEAN",
+    "MEAT", "MEET", "MELT", "MENU", "MERE", "MESS", "MILD", "MILE", "MILK", "MILL", "MIND", "MINE",
+    "MISS", "MODE", "MOOD", "MOON", "MORE", "MOST", "MOVE", "MUCH", "MUST", "MYTH", "NAME", "NAVY",
+    "NEAR", "NEAT", "NECK", "NEED", "NEWS", "NEXT", "NICE", "NINE", "NONE", "NOSE", "NOTE", "NOUN",
+    "ODDS", "ONCE", "ONLY", "ONTO", "OPEN", "ORAL", "OVEN", "OVER", "PACE", "PACK", "PAGE", "PAID",
+    "PAIN", "PAIR", "PALM", "PARK", "PART", "PASS", "PAST", "PATH", "PEAK", "PICK", "PILE", "PINE",
+    "PINK", "PIPE", "PLAN", "PLAY", "PLOT", "PLUG", "PLUS", "POEM", "POET", "POLE", "POLL", "POND",
+    "POOL", "POOR", "PORT", "POSE", "POST", "POUR", "PRAY", "PULL", "PURE", "PUSH", "QUIT", "RACE",
+    "RAIL", "RAIN", "RANK", "RARE", "RATE", "READ", "REAL", "RELY", "RENT", "REST", "RICE", "RICH",
+    "RIDE", "RING", "RISE", "RISK", "ROAD", "ROCK", "ROLE", "ROLL", "ROOF", "ROOM", "ROOT", "ROPE",
+    "ROSE", "RULE", "RUSH", "SAFE", "SAID", "SAKE", "SALE", "SALT", "SAME", "SAND", "SAVE", "SEAL",
+    "SEAT", "SEED", "SEEK", "SEEM", "SEEN", "SELF", "SELL", "SEND", "SENT", "SHIP", "SHOE", "SHOP",
+    "SHOT", "SHOW", "SHUT", "SICK", "SIDE", "SIGN", "SILK", "SING", "SINK", "SITE", "SIZE", "SKIN",
+    "SLIP", "SLOW", "SNAP", "SNOW", "SOAP", "SOFT", "SOIL", "SOLD", "SOLE", "SOME", "SONG", "SOON",
+    "SORT", "SOUL", "SOUP", "SPIN", "SPOT", "STAR", "STAY", "STEP", "STIR", "STOP", "SUCH", "SUIT",
+    "SURE", "SWIM", "TAIL", "TAKE", "TALE", "TALK", "TALL", "TANK", "TAPE", "TASK", "TEAM", "TEAR",
+    "TELL", "TEND", "TENT", "TERM", "TEST", "TEXT", "THAN", "THAT", "THEM", "THEN", "THEY", "THIN",
+    "THIS", "THUS", "TIDE", "TINY", "TONE", "TOOK", "TOOL", "TOUR", "TOWN", "TREE", "TRIP", "TRUE",
+    "TUBE", "TUNE", "TURN", "TWIN", "TYPE", "UNIT", "UPON", "USED", "USER", "VAST", "VERY", "VOTE",
+    "WAGE", "WAIT", "WAKE", "WALK", "WALL", "WANT", "WARM", "WARN", "WASH", "WAVE", "WEAK", "WEAR",
+    "WEEK", "WELL", "WENT", "WERE", "WEST", "WHAT", "WHEN", "WHOM", "WIDE", "WIFE", "WILD", "WILL",
+    "WIND", "WINE", "WING", "WIRE", "WISE", "WISH", "WITH", "WOLF", "WOOD", "WOOL", "WORD", "WORE",
+    "WORK", "WORN", "WRAP", "YARD", "YEAH", "YEAR", "YELL", "ZERO", "ZONE",
+];
+pub static WORDS_6: &[&str] = &[
+    "ABSORB", "ACCEPT", "ACCESS", "ACROSS", "ACTION", "ACTIVE", "ACTUAL", "ADMIRE", "ADVICE",
+    "AFFECT", "AFFORD", "AGENCY", "AGENDA", "ALMOST", "ALWAYS", "AMOUNT", "ANIMAL", "ANNUAL",
+    "ANSWER", "ANYONE", "ANYWAY", "APPEAL", "APPEAR", "ARRIVE", "ARTIST", "ASPECT", "ASSUME",
+    "ATTACK", "ATTEND", "AUTHOR", "AUTUMN", "AVENUE", "BACKED", "BANANA", "BARELY", "BASKET",
+    "BATTLE", "BEAUTY", "BECOME", "BEFORE", "BEHALF", "BEHIND", "BELIEF", "BELONG", "BETTER",
+    "BEYOND", "BISHOP", "BITTER", "BLONDE", "BOTTLE", "BOTTOM", "BOUGHT", "BRANCH", "BREATH",
+    "BRIDGE", "BRIGHT", "BROKEN", "BUDGET", "BURDEN", "BUREAU", "BUTTON", "CAMERA", "CANCER",
+    "CANDLE", "CANVAS", "CARBON", "CAREER", "CARPET", "CASTLE", "CASUAL", "CAUGHT", "CENTER",
+    "CENTRE", "CHANCE", "CHANGE", "CHARGE", "CHOICE", "CHOOSE", "CHOSEN", "CHURCH", "CIRCLE",
+    "CLIENT", "CLOSED", "CLOSER", "COFFEE", "COLLAR", "COLUMN", "COMBAT", "COMEDY", "COMMIT",
+    "COMMON", "COOKIE", "COPPER", "CORNER", "COTTON", "COUNTY", "COUPLE", "COURSE", "COUSIN",
+    "CREATE", "CREDIT", "CRISIS", "CUSTOM", "DAMAGE", "DANGER", "DEALER", "DEBATE", "DECADE",
+    "DECIDE", "DEFEAT", "DEFEND", "DEFINE", "DEGREE", "DEMAND", "DEPEND", "DEPUTY", "DESERT",
+    "DESIGN", "DESIRE", "DETAIL", "DEVICE", "DIFFER", "DINNER", "DIRECT", "DIVIDE", "DOCTOR",
+    "DOLLAR", "DOMAIN", "DOUBLE", "DRAGON", "DRIVER", "DURING", "EASILY", "EATING", "EDITOR",
+    "EFFECT", "EFFORT", "EIGHTH", "EITHER", "ELEVEN", "EMERGE", "EMPIRE", "EMPLOY", "ENABLE",
+    "ENDING", "ENERGY", "ENGAGE", "ENGINE", "ENOUGH", "ENSURE", "ENTIRE", "ENTITY", "EQUITY",
+    "ESCAPE", "ESTATE", "ETHNIC", "EVOLVE", "EXCEED", "EXCEPT", "EXCUSE", "EXPAND", "EXPECT",
+    "EXPERT", "EXPORT", "EXTEND", "EXTENT", "FABRIC", "FACING", "FACTOR", "FAIRLY", "FALLEN",
+    "FAMILY", "FAMOUS", "FARMER", "FATHER", "FELLOW", "FEMALE", "FIGURE", "FILTER", "FINGER",
+    "FINISH", "FISCAL", "FLIGHT", "FLOWER", "FOLLOW", "FOREST", "FORGET", "FORMAL", "FORMAT",
+    "FORMER", "FOSTER", "FOURTH", "FREEZE", "FRIEND", "FROZEN", "FUTURE", "GALAXY", "GARAGE",
+    "GARDEN", "GATHER", "GENDER", "GENIUS", "GENTLE", "GIVING", "GLANCE", "GLOBAL", "GOLDEN",
+    "GOVERN", "GROWTH", "GUILTY", "GUITAR", "HANDLE", "HAPPEN", "HARBOR", "HARDLY", "HEALTH",
+    "HEAVEN", "HEIGHT", "HIDDEN", "HIGHLY", "HOCKEY", "HOLDER", "HONEST", "HORROR", "HUNGER",
+    "HUNTER", "IGNORE", "IMPACT", "IMPORT", "INCOME", "INDEED", "INFANT", "INFORM", "INJURY",
+    "INSECT", "INSIDE", "INSIST", "INTEND", "INVEST", "ISLAND", "ITSELF", "JACKET", "JUNGLE",
+    "JUNIOR", "KIDNEY", "KILLER", "LADDER", "LATELY", "LATTER", "LAUNCH", "LAWYER", "LEADER",
+    "LEAGUE", "LEGACY", "LEGEND", "LENGTH", "LESSON", "LETTER", "LIKELY", "LIQUID", "LISTEN",
+    "LITTLE", "LIVING", "LOCATE", "LONELY", "LOVELY", "MAINLY", "MANAGE", "MANNER", "MARBLE",
+    "MARGIN", "MARKET", "MASTER", "MATTER", "MEADOW", "MEDIUM", "MEMBER", "MEMORY", "MENTAL",
+    "MERELY", "METHOD", "MIDDLE", "MINUTE", "MIRROR", "MOBILE", "MODERN", "MODEST", "MOMENT",
+    "MONKEY", "MOSTLY", "MOTHER", "MOTION", "MURDER", "MUSEUM", "MUTUAL", "MYSELF", "NARROW",
+    "NATION", "NATURE", "NEARBY", "NEARLY", "NEEDLE", "NEPHEW", "NOBODY", "NORMAL", "NOTICE",
+    "NUMBER", "OBJECT", "OBTAIN", "OCCUPY", "OFFICE", "ONLINE", "OPPOSE", "OPTION", "ORANGE",
+    "ORIGIN", "OUTPUT", "OXYGEN", "PALACE", "PARENT", "PARTLY", "PATENT", "PATROL", "PEPPER",
+    "PERIOD", "PERMIT", "PERSON", "PHRASE", "PICKUP", "PILLOW", "PLANET", "PLAYER", "PLEASE",
+    "PLENTY", "POCKET", "POETRY", "POLICE", "POLICY", "POTATO", "POWDER", "PRAYER", "PREFER",
+    "PRETTY", "PRIEST", "PRINCE", "PRISON", "PROFIT", "PROPER", "PROVEN", "PUBLIC", "PURPLE",
+    "PURSUE", "PUZZLE", "RABBIT", "RACING", "RANDOM", "RARELY", "RATHER", "RATING", "READER",
+    "REALLY", "REASON", "RECALL", "RECENT", "RECORD", "REDUCE", "REFORM", "REGION", "RELATE",
+    "RELIEF", "REMAIN", "REMOTE", "REMOVE", "RENDER", "REPAIR", "REPEAT", "REPORT", "RESCUE",
+    "RESIST", "RESORT", "RESULT", "RETAIN", "RETIRE", "RETURN", "REVEAL", "REVIEW", "REWARD",
+    "RHYTHM", "RIBBON", "RIDING", "ROCKET", "RUBBER", "SAFETY", "SALMON", "SAMPLE", "SAVING",
+    "SCHEME", "SCHOOL", "SCREEN", "SCRIPT", "SEARCH", "SEASON", "SECOND", "SECRET", "SECTOR",
+    "SECURE", "SELECT", "SELLER", "SENIOR", "SERIES", "SERVER", "SETTLE", "SEVERE", "SHADOW",
+    "SHOULD", "SHOWER", "SIGNAL", "SILENT", "SILVER", "SIMPLE", "SINGER", "SINGLE", "SISTER",
+    "SKETCH", "SLIGHT", "SMOOTH", "SOCCER", "SOCIAL", "SOLELY", "SOUGHT", "SOURCE", "SPEECH",
+    "SPIRIT", "SPREAD", "SPRING", "SQUARE", "STABLE", "STATUE", "STEADY", "STRAIN", "STREAM",
+    "STREET", "STRESS", "STRICT", "STRIKE", "STRING", "STROKE", "STRONG", "STUDIO", "SUBMIT",
+    "SUDDEN", "SUFFER", "SUMMER", "SUMMIT", "SUPPLY", "SURELY", "SURVEY", "SWITCH", "SYMBOL",
+    "SYSTEM", "TABLET", "TALENT", "TARGET", "TEMPLE", "TENANT", "TENDER", "TENNIS", "THEORY",
+    "THIRTY", "THREAD", "THREAT", "THROAT", "TICKET", "TIMBER", "TISSUE", "TOILET", "TOMATO",
+    "TONGUE", "TOWARD", "TRAVEL", "TREATY", "TROOPS", "TROPHY", "TUNNEL", "TWELVE", "TWENTY",
+    "UNLESS", "UNLIKE", "UPDATE", "USEFUL", "VALLEY", "VARIED", "VENDOR", "VERSUS", "VICTIM",
+    "VISION", "VISUAL", "VOLUME", "WALKER", "WALLET", "WANDER", "WARMTH", "WEALTH", "WEAPON",
+    "WEEKLY", "WEIGHT", "WIDELY", "WINDOW", "WINNER", "WINTER", "WISDOM", "WITHIN", "WONDER",
+    "WOODEN", "WORKER", "WRITER", "YELLOW",
+];
+pub static WORDS_7: &[&str] = &[
+    "ABILITY", "ABSENCE", "ACADEMY", "ACCOUNT", "ACHIEVE", "ACQUIRE", "ADDRESS", "ADVANCE",
+    "ADVISER", "AGAINST", "AIRLINE", "AIRPORT", "ALCOHOL", "ALREADY", "AMAZING", "ANALYST",
+    "ANCIENT", "ANOTHER", "ANXIETY", "ANYBODY", "ANYMORE", "APPLIED", "ARRANGE", "ARTICLE",
+    "ASSAULT", "ATTEMPT", "ATTRACT", "AUCTION", "AVERAGE", "BALANCE", "BARRIER", "BATTERY",
+    "BEDROOM", "BENEFIT", "BETWEEN", "BICYCLE", "BILLION", "BIOLOGY", "BLANKET", "BOMBING",
+    "BROTHER", "CABINET", "CAPABLE", "CAPITAL", "CAPTAIN", "CAPTURE", "CAREFUL", "CARRIER",
+    "CATALOG", "CEILING", "CENTRAL", "CENTURY", "CERTAIN", "CHAMBER", "CHANNEL", "CHAPTER",
+    "CHARITY", "CHICKEN", "CHRONIC", "CIRCUIT", "CITIZEN", "CLASSIC", "CLIMATE", "CLOTHES",
+    "CLUSTER", "COASTAL", "COLLECT", "COLLEGE", "COMBINE", "COMFORT", "COMMAND", "COMMENT",
+    "COMPARE", "COMPETE", "COMPLEX", "CONCEPT", "CONCERN", "CONCERT", "CONDUCT", "CONFIRM",
+    "CONNECT", "CONSIST", "CONTACT", "CONTAIN", "CONTENT", "CONTEST", "CONTEXT", "CONTROL",
+    "CONVERT", "CORRECT", "COUNCIL", "COUNTER", "COUNTRY", "COURAGE", "CRYSTAL", "CULTURE",
+    "CURIOUS", "CURRENT", "CYCLING", "DAMAGED", "DEALING", "DECLINE", "DEFAULT", "DEFENSE",
+    "DEFICIT", "DELIVER", "DENSITY", "DEPOSIT", "DESKTOP", "DESPITE", "DESTROY", "DEVELOP",
+    "DIAMOND", "DIGITAL", "DISPLAY", "DISTANT", "DIVERSE", "DIVORCE", "DRAWING", "DYNAMIC",
+    "EASTERN", "ECONOMY", "EDITION", "ELDERLY", "ELEMENT", "EMOTION", "ENHANCE", "EVENING",
+    "EXACTLY", "EXAMINE", "EXAMPLE", "EXCITED", "EXCLUDE", "EXHIBIT", "EXPENSE", "EXPLAIN",
+    "EXPLORE", "EXPRESS", "EXTREME", "FACTORY", "FAILURE", "FASHION", "FEATURE", "FEDERAL",
+    "FEELING", "FICTION", "FIFTEEN", "FINANCE", "FINDING", "FISHING", "FITNESS", "FOREIGN",
+    "FOREVER", "FORMULA", "FORTUNE", "FORWARD", "FOUNDER", "FREEDOM", "FUNDING", "FUNERAL",
+    "GALLERY", "GATEWAY", "GENERAL", "GENETIC", "GENUINE", "GESTURE", "GLIMPSE", "GROWING",
+    "HABITAT", "HANDFUL", "HARMONY", "HEALTHY", "HEARING", "HEAVILY", "HELPFUL", "HERSELF",
+    "HIGHWAY", "HIMSELF", "HISTORY", "HOLIDAY", "HOUSING", "HOWEVER", "HUNDRED", "HUNTING",
+    "HUSBAND", "ILLNESS", "IMAGINE", "IMPRESS", "IMPROVE", "INCLUDE", "INITIAL", "INQUIRY",
+    "INSIGHT", "INSPIRE", "INSTALL", "INSTANT", "INSTEAD", "INTENSE", "INTERIM", "INVOLVE",
+    "JOURNAL", "JOURNEY", "JUSTICE", "KITCHEN", "LANDING", "LARGELY", "LASTING", "LEADING",
+    "LEATHER", "LECTURE", "LIBERAL", "LIBRARY", "LICENSE", "LIGHTLY", "LIMITED", "MACHINE",
+    "MANAGER", "MANSION", "MARRIED", "MASSIVE", "MAXIMUM", "MEANING", "MEASURE", "MEDICAL",
+    "MEETING", "MENTION", "MESSAGE", "MILLION", "MINERAL", "MINIMAL", "MINIMUM", "MISSING",
+    "MISSION", "MISTAKE", "MIXTURE", "MONITOR", "MORNING", "MUSICAL", "MYSTERY", "NATURAL",
+    "NEITHER", "NETWORK", "NOTHING", "NOWHERE", "NUCLEAR", "OBSERVE", "OBVIOUS", "OFFENSE",
+    "OFFICER", "OPERATE", "OPINION", "ORGANIC", "OUTCOME", "OUTDOOR", "OUTSIDE", "OVERALL",
+    "PACKAGE", "PAINTER", "PARKING", "PARTNER", "PASSAGE", "PASSION", "PATIENT", "PATTERN",
+    "PAYMENT", "PENALTY", "PENSION", "PERFECT", "PERFORM", "PERHAPS", "PICTURE", "PIONEER",
+    "PLASTIC", "PLEASED", "POPULAR", "PORTION", "POVERTY", "PRECISE", "PREDICT", "PREMIER",
+    "PREMIUM", "PREPARE", "PRESENT", "PREVENT", "PRIMARY", "PRINTER", "PRIVACY", "PRIVATE",
+    "PROBLEM", "PROCEED", "PROCESS", "PRODUCE", "PRODUCT", "PROFILE", "PROGRAM", "PROJECT",
+    "PROMISE", "PROTECT", "PROTEIN", "PROTEST", "PROVIDE", "PUBLISH", "PURPOSE", "QUALIFY",
+    "QUALITY", "QUARTER", "QUICKLY", "RADICAL", "RAILWAY", "READILY", "REALITY", "RECEIVE",
+    "RECOVER", "REFLECT", "REGULAR", "RELATED", "RELEASE", "REMAINS", "REMOVAL", "REPLACE",
+    "REQUEST", "REQUIRE", "RESERVE", "RESOLVE", "RESPECT", "RESPOND", "RESTORE", "RETREAT",
+    "REVENUE", "REVERSE", "ROUGHLY", "ROUTINE", "RUNNING", "SATISFY", "SCIENCE", "SECTION",
+    "SEGMENT", "SERIOUS", "SERVICE", "SESSION", "SETTING", "SEVENTH", "SEVERAL", "SHELTER",
+    "SHERIFF", "SILENCE", "SIMILAR", "SITTING", "SOCIETY", "SOLDIER", "SOMEHOW", "SPEAKER",
+    "SPECIAL", "SPONSOR", "STATION", "STORAGE", "STRANGE", "STRETCH", "STUDENT", "STUDIED",
+    "SUBJECT", "SUCCEED", "SUCCESS", "SUGGEST", "SUMMARY", "SUPPORT", "SUPPOSE", "SUPREME",
+    "SURFACE", "SURGERY", "SURPLUS", "SURVIVE", "SUSPECT", "SUSTAIN", "TEACHER", "TENSION",
+    "TERRAIN", "THEATER", "THERAPY", "THEREBY", "THOUGHT", "THROUGH", "TOBACCO", "TONIGHT",
+    "TOTALLY", "TOURISM", "TOURIST", "TOWARDS", "TRAFFIC", "TRAGEDY", "TROUBLE", "TYPICAL",
+    "UNIFORM", "UNKNOWN", "UNUSUAL", "UPGRADE", "UPSCALE", "UTILITY", "VARIETY", "VARIOUS",
+    "VEHICLE", "VENTURE", "VERSION", "VETERAN", "VICTORY", "VILLAGE", "VIOLENT", "VIRTUAL",
+    "VISIBLE", "VISITOR", "WEALTHY", "WEATHER", "WEBSITE", "WEDDING", "WEEKEND", "WELCOME",
+    "WELFARE", "WESTERN", "WHEREAS", "WHETHER", "WILLING", "WINNING", "WITHOUT", "WITNESS",
+    "WORRIED", "WRITING", "WRITTEN",
+];
+pub static WORDS_8: &[&str] = &[
+    "ABSOLUTE", "ABSTRACT", "ACADEMIC", "ACCEPTED", "ACCIDENT", "ACCURATE", "ACQUIRED", "ACTIVITY",
+    "ACTUALLY", "ADDITION", "ADEQUATE", "ADJACENT", "ADJUSTED", "ADVANCED", "ADVISORY", "ADVOCATE",
+    "AFFECTED", "AIRCRAFT", "ALLIANCE", "ALTHOUGH", "ALUMINUM", "ANALYSIS", "ANNOUNCE", "ANYTHING",
+    "ANYWHERE", "APPARENT", "APPETITE", "APPROACH", "APPROVAL", "ARGUMENT", "ARTISTIC", "ASSEMBLY",
+    "ASSUMING", "ATHLETIC", "ATTACHED", "ATTITUDE", "ATTORNEY", "AUDIENCE", "AUTONOMY", "AVIATION",
+    "BACHELOR", "BACKWARD", "BACTERIA", "BASEBALL", "BATHROOM", "BECOMING", "BIRTHDAY", "BOUNDARY",
+    "BREAKING", "BREEDING", "BUILDING", "BULLETIN", "BUSINESS", "CALENDAR", "CAMPAIGN", "CAPACITY",
+    "CASUALTY", "CATCHING", "CATEGORY", "CAUTIOUS", "CEREMONY", "CHAIRMAN", "CHAMPION", "CHEMICAL",
+    "CHILDREN", "CIRCULAR", "CIVILIAN", "CLINICAL", "CLOTHING", "COLLAPSE", "COLONIAL", "COLORFUL",
+    "COMMENCE", "COMMERCE", "COMPLAIN", "COMPLETE", "COMPOSED", "COMPOUND", "COMPRISE", "COMPUTER",
+    "CONCLUDE", "CONCRETE", "CONFLICT", "CONFUSED", "CONGRESS", "CONSIDER", "CONSTANT", "CONSUMER",
+    "CONTINUE", "CONTRACT", "CONTRARY", "CONTRAST", "CONVINCE", "CORRIDOR", "COVERAGE", "COVERING",
+    "CREATION", "CREATIVE", "CRIMINAL", "CRITICAL", "CROSSING", "CULTURAL", "CURRENCY", "CUSTOMER",
+    "DATABASE", "DAUGHTER", "DAYLIGHT", "DEADLINE", "DECIDING", "DECISION", "DECREASE", "DEFERRED",
+    "DEFINITE", "DELICATE", "DELIVERY", "DESCRIBE", "DESIGNER", "DETAILED", "DIABETES", "DIALOGUE",
+    "DIAMETER", "DIRECTLY", "DIRECTOR", "DISABLED", "DISASTER", "DISCOUNT", "DISCOVER", "DISORDER",
+    "DISPOSAL", "DISTANCE", "DISTINCT", "DISTRICT", "DIVIDEND", "DIVISION", "DOCTRINE", "DOCUMENT",
+    "DOMESTIC", "DOMINANT", "DOWNLOAD", "DOWNTOWN", "DRAMATIC", "DRINKING", "DROPPING", "DURATION",
+    "DYNAMICS", "EARNINGS", "ECONOMIC", "EDUCATED", "ELECTION", "ELECTRIC", "ELEPHANT", "EMERGING",
+    "EMPHASIS", "EMPLOYEE", "EMPLOYER", "ENGAGING", "ENGINEER", "ENORMOUS", "ENTIRELY", "ENTRANCE",
+    "ENVELOPE", "EQUALITY", "EQUATION", "ESTIMATE", "EVALUATE", "EVENTUAL", "EVERYDAY", "EVERYONE",
+    "EVIDENCE", "EXCHANGE", "EXCITING", "EXERCISE", "EXISTING", "EXPANDED", "EXPECTED", "EXPEDITE",
+    "EXPLICIT", "EXPOSURE", "EXTENDED", "EXTERNAL", "FACILITY", "FAMILIAR", "FEATURED", "FEEDBACK",
+    "FESTIVAL", "FINISHED", "FIREWALL", "FLEXIBLE", "FLOATING", "FOOTBALL", "FOOTHILL", "FORECAST",
+    "FOREMOST", "FORMERLY", "FOURTEEN", "FRACTION", "FREQUENT", "FRIENDLY", "FRONTIER", "FUNCTION",
+    "GENERATE", "GENEROUS", "GENETICS", "GIGANTIC", "GOODNESS", "GRADUATE", "GRAPHICS", "GRATEFUL",
+    "GUARDIAN", "GUIDANCE", "HANDLING", "HARDWARE", "HERITAGE", "HIGHLAND", "HISTORIC", "HOMELESS",
+    "HOMEPAGE", "HOSPITAL", "HUMANITY", "IDENTIFY", "IDENTITY", "IDEOLOGY", "IMPERIAL", "INCIDENT",
+    "INCLUDED", "INCREASE", "INDICATE", "INDIRECT", "INDUSTRY", "INFORMAL", "INFORMED", "INHERENT",
+    "INITIATE", "INNOCENT", "INSPIRED", "INSTANCE", "INTEGRAL", "INTENDED", "INTERACT", "INTEREST",
+    "INTERIOR", "INTERNAL", "INTERVAL", "INTIMATE", "INVASION", "INVOLVED", "ISOLATED", "JUDGMENT",
+    "JUDICIAL", "JUNCTION", "KEYBOARD", "LANDLORD", "LANGUAGE", "LAUGHTER", "LEARNING", "LEVERAGE",
+    "LIFETIME", "LIGHTING", "LIKEWISE", "LIMITING", "LITERARY", "LOCATION", "MAGAZINE", "MAGNETIC",
+    "MAINTAIN", "MAJORITY", "MARGINAL", "MARRIAGE", "MATERIAL", "MATURITY", "MAXIMIZE", "MEANTIME",
+    "MEASURED", "MEDICINE", "MEDIEVAL", "MEMORIAL", "MERCHANT", "MIDNIGHT", "MILITARY", "MINISTER",
+    "MINORITY", "MODERATE", "MOLECULE", "MOMENTUM", "MONETARY", "MONOPOLY", "MORTGAGE", "MOUNTAIN",
+    "MOVEMENT", "MULTIPLE", "NATIONAL", "NEGATIVE", "NEIGHBOR", "NINETEEN", "NOTEBOOK", "NUMEROUS",
+    "OBITUARY", "OBSERVER", "OCCASION", "OFFERING", "OFFICIAL", "OPERATOR", "OPPONENT", "OPPOSITE",
+    "OPTIMISM", "OPTIONAL", "ORDINARY", "ORGANIZE", "ORIGINAL", "OUTBREAK", "OVERCOME", "OVERLOOK",
+    "OVERSEAS", "PAINTING", "PARALLEL", "PARENTAL", "PARTICLE", "PASSWORD", "PATIENCE", "PEACEFUL",
+    "PERIODIC", "PERSONAL", "PERSUADE", "PETITION", "PHYSICAL", "PIPELINE", "PLANNING", "PLATFORM",
+    "PLEASANT", "PLEASURE", "POLITICS", "PORTABLE", "PORTRAIT", "POSITION", "POSITIVE", "POSSIBLE",
+    "POWERFUL", "PRACTICE", "PRECIOUS", "PREGNANT", "PRESENCE", "PRESERVE", "PRESSING", "PRESSURE",
+    "PREVIOUS", "PRINCESS", "PRINTING", "PRIORITY", "PROBABLE", "PROBABLY", "PRODUCER", "PROFOUND",
+    "PROGRESS", "PROPERTY", "PROPOSAL", "PROSPECT", "PROTOCOL", "PROVIDED", "PROVIDER", "PROVINCE",
+    "PUBLICLY", "PURCHASE", "QUANTITY", "QUESTION", "RATIONAL", "REACTION", "RECEIVED", "RECENTLY",
+    "RECORDER", "RECOVERY", "REGIONAL", "REGISTER", "RELATION", "RELATIVE", "RELEVANT", "RELIABLE",
+    "RELIGION", "REMEMBER", "RENOWNED", "REPEATED", "REPORTER", "REPUBLIC", "REQUIRED", "RESEARCH",
+    "RESERVED", "RESIDENT", "RESOURCE", "RESPONSE", "RESTRICT", "REVISION", "RHETORIC", "ROMANTIC",
+    "SAMPLING", "SCHEDULE", "SCRUTINY", "SEASONAL", "SECURITY", "SELECTED", "SENTENCE", "SEPARATE",
+    "SEQUENCE", "SERGEANT", "SHOOTING", "SHOPPING", "SHORTAGE", "SHOULDER", "SIMPLIFY", "SITUATED",
+    "SLIGHTLY", "SOFTWARE", "SOLUTION", "SOMEBODY", "SOMEWHAT", "SOUTHERN", "SPEAKING", "SPECIFIC",
+    "SPECTRUM", "SPELLING", "SPORTING", "STANDARD", "STANDING", "STARTING", "STEERING", "STRATEGY",
+    "STRENGTH", "STRIKING", "STRUGGLE", "STUNNING", "SUBURBAN", "SUITABLE", "SUPERIOR", "SUPPOSED",
+    "SURPRISE", "SURVIVAL", "SWEEPING", "SWIMMING", "SYMBOLIC", "SYMPATHY", "SYNDROME", "TACTICAL",
+    "TAILORED", "TAXPAYER", "TEACHING", "TEENAGER", "TELEGRAM", "TERMINAL", "TERRIBLE", "THINKING",
+    "THIRTEEN", "THOROUGH", "THOUSAND", "TOGETHER", "TOMORROW", "TOUCHING", "TRACKING", "TRAINING",
+    "TRANSFER", "TRAVELED", "TREASURY", "TRIANGLE", "TROPICAL", "TURNOVER", "ULTIMATE", "UMBRELLA",
+    "UNIVERSE", "UNLIKELY", "VALUABLE", "VARIABLE", "VERTICAL", "VINEYARD", "VIOLENCE", "VOLATILE",
+    "WARRANTY", "WEAKNESS", "WHATEVER", "WHENEVER", "WHEREVER", "WILDLIFE", "WIRELESS", "WITHDRAW",
+    "WOODLAND", "WORKSHOP", "YOURSELF",
+];
+
+pub static GUESSES_4: &[&str] = &[
+    "ABAC", "ABBA", "ABBE", "ABER", "ABET", "ABIE", "ABLY", "ABOX", "ABRI", "ABUT", "ABYE", "ACAI",
+    "ACCA", "ACER", "ACHE", "ACME", "ACNE", "ACRE", "ACRO", "ACTS", "ACUT", "ACYL", "ADAW", "ADAY",
+    "ADDS", "ADIO", "ADIT", "ADZE", "AEDE", "AEGI", "AEON", "AERO", "AFAR", "AFRO", "AGAR", "AGEE",
+    "AGER", "AGES", "AGHA", "AGIO", "AGLU", "AGLY", "AGMA", "AGON", "AGRO", "AGUE", "AIDO", "AIGA",
+    "AIMS", "AIRN", "AIRS", "AIRT", "AIRY", "AITU", "AJAR", "AJIE", "AKEE", "AKIN", "AKKA", "ALAP",
+    "ALAY", "ALCO", "ALEF", "ALEW", "ALFA", "ALGA", "ALIF", "ALKO", "ALLI", "ALME", "ALOD", "ALOE",
+    "ALOO", "ALTO", "ALUM", "AMAH", "AMBO", "AMEN", "AMIC", "AMID", "AMIN", "AMLA", "AMMO", "AMOK",
+    "AMYL", "ANEW", "ANGA", "ANIL", "ANKH", "ANKU", "ANLA", "ANOA", "ANTA", "ANTE", "ANTI", "ANTS",
+    "AONA", "APAY", "APER", "APHI", "APOD", "APRE", "APSE", "APSI", "APSO", "AQUA", "ARAK", "ARAR",
+    "ARBA", "ARCO", "ARCU", "ARET", "ARGU", "ARID", "ARIL", "ARLE", "ARMS", "ARNA", "ARPA", "ARRA",
+    "ARRI", "ARSE", "ARSI", "ARTI", "ARTS", "ARTY", "ARUM", "ARVO", "ARYL", "ASCU", "ASHE", "ASHY",
+    "ASKO", "ASKS", "ASPI", "ASSE", "ATAP", "ATLA", "ATMA", "ATMO", "ATOC", "ATOK", "ATOM", "ATRY",
+    "ATUA", "AUKS", "AULA", "AULD", "AULO", "AUNE", "AUNT", "AURE", "AURI", "AUTO", "AVEL", "AVEN",
+    "AVER", "AVGA", "AVID", "AVOW", "AWDL", "AWED", "AWOL", "AXIL", "AXIS", "AXLE", "AXON", "AYAH",
+    "AYIN", "AYRE", "AZAN", "AZON", "AZYM", "BAAL", "BABA", "BABE", "BABU", "BACH", "BAEL", "BAFF",
+    "BAFT", "BAGH", "BAGS", "BAHT", "BAHU", "BAIL", "BAIT", "BAJU", "BAKE", "BALA", "BALD", "BALE",
+    "BALK", "BALM", "BALU", "BANC", "BANE", "BANG", "BANN", "BANT", "BAPU", "BARD", "BARE", "BARF",
+    "BARK", "BARM", "BARN", "BARP", "BARS", "BASI", "BASK", "BASS", "BAST", "BATE", "BATS", "BATT",
+    "BAUD", "BAUK", "BAUR", "BAWD", "BAWK", "BAWL", "BAWN", "BAWR", "BAYE", "BAYT", "BEAD", "BEAK",
+    "BEAL", "BEAM", "BEAN", "BECK", "BEDE", "BEDS", "BEEF", "BEEP", "BEES", "BEET", "BEGA", "BEIN",
+    "BELD", "BEMA", "BEND", "BENE", "BENI", "BENT", "BERE", "BERG", "BERK", "BERM", "BESE", "BETA",
+    "BETE", "BEZE", "BHAI", "BHAT", "BHEL", "BHUT", "BIAS", "BIBB", "BIBE", "BICE", "BIDE", "BIDI",
+    "BIDS", "BIER", "BIFF", "BIGG", "BIGO", "BIKE", "BILE", "BILK", "BIMA", "BIND", "BINE", "BING",
+    "BINK", "BINT", "BIOG", "BIRK", "BIRL", "BIRO", "BIRR", "BISE", "BISK", "BITE", "BITO", "BITS",
+    "BITT", "BIZE", "BLAB", "BLAD", "BLAE", "BLAG", "BLAH", "BLAM", "BLAS", "BLAT", "BLAW", "BLAY",
+    "BLEB", "BLEE", "BLET", "BLEY", "BLIN", "BLIP", "BLIT", "BLOB", "BLOC", "BLOG", "BLOT", "BLUB",
+    "BLUD", "BLUR", "BOAB", "BOAK", "BOAR", "BOAS", "BOBA", "BOBO", "BOCK", "BOEP", "BOET", "BOFF",
+    "BOGG", "BOGU", "BOHO", "BOIL", "BOKE", "BOKO", "BOLA", "BOLD", "BOLE", "BOLL", "BOLO", "BOLT",
+    "BOLU", "BOMA", "BONA", "BONG", "BONK", "BONS", "BONU", "BONY", "BOOB", "BOOH", "BOOL", "BOON",
+    "BOOR", "BOOT", "BORA", "BORD", "BORE", "BORK", "BORM", "BORT", "BOSK", "BOTA", "BOTE", "BOTT",
+    "BOUK", "BOUN", "BOUT", "BOWE", "BOWR", "BOXE", "BOYF", "BOYG", "BOYO", "BOYS", "BOZO", "BRAE",
+    "BRAG", "BRAK", "BRAN", "BRAT", "BRAW", "BRAY", "BRED", "BREI", "BREN", "BRER", "BREW", "BREY",
+    "BRIE", "BRIG", "BRIK", "BRIM", "BRIN", "BRIO", "BRIT", "BROD", "BROG", "BROO", "BROW", "BRUT",
+    "BUAT", "BUBA", "BUBU", "BUDA", "BUDI", "BUDO", "BUFF", "BUFO", "BUGS", "BUHL", "BUHR", "BUIK",
+    "BUIL", "BUKE", "BULB", "BULL", "BUMF", "BUMP", "BUNA", "BUND", "BUNG", "BUNK", "BUNN", "BUNS",
+    "BUNT", "BUOY", "BURA", "BURB", "BURD", "BURG", "BURK", "BURP", "BURR", "BUSE", "BUSK", "BUST",
+    "BUTE", "BUTT", "BUZZ", "BYDE", "BYKE", "BYRE", "BYRL", "BYTE", "CABA", "CACA", "CACK", "CADI",
+    "CAFF", "CAGE", "CAGY", "CAID", "CALF", "CALK", "CALO", "CALP", "CAMA", "CAMO", "CAMU", "CANE",
+    "CANG", "CANN", "CANT", "CANY", "CAPA", "CAPE", "CAPH", "CAPO", "CAPS", "CARB", "CARK", "CARN",
+    "CARP", "CARR", "CARS", "CASA", "CASK", "CASU", "CATE", "CATS", "CAUK", "CAUL", "CAUM", "CAUP",
+    "CAUS", "CAVA", "CAVE", "CAVY", "CAWK", "CEDE", "CEDI", "CEIL", "CELT", "CENT", "CEPE", "CERE",
+    "CERO", "CERT", "CETE", "CHAI", "CHAL", "CHAM", "CHAO", "CHAP", "CHAR", "CHAV", "CHAW", "CHAY",
+    "CHEM", "CHEW", "CHIB", "CHIC", "CHIK", "CHIN", "CHIT", "CHIV", "CHOC", "CHOG", "CHON", "CHOP",
+    "CHOW", "CHUB", "CHUG", "CHUM", "CHUT", "CIDE", "CIEL", "CILL", "CINE", "CINQ", "CION", "CIRC",
+    "CIRE", "CIRL", "CIST", "CITE", "CIVE", "CLAD", "CLAE", "CLAG", "CLAM", "CLAN", "CLAP", "CLAT",
+    "CLAW", "CLEF", "CLEG", "CLEM", "CLEW", "CLIE", "CLIP", "CLIT", "CLOD", "CLOG", "CLON", "CLOP",
+    "CLOT", "CLOU", "CLOW", "CLOY", "CLUE", "COBB", "COCA", "COCK", "COCO", "CODA", "COED", "COFF",
+    "COHO", "COIF", "COIL", "COIN", "COIR", "COIT", "COKE", "COKY", "COLA", "COLL", "COLY", "COMA",
+    "COMB", "COMM", "COMP", "COMU", "CONE", "CONF", "CONK", "CONU", "CONY", "COOF", "COOM", "COON",
+    "COOP", "COOT", "CORD", "CORK", "CORM", "CORP", "COSE", "COTE", "COTH", "COTT", "COUA", "COUD",
+    "COUP", "COUR", "COVE", "COWK", "COWL", "COWP", "COWS", "COXE", "COZE", "CRAB", "CRAG", "CRAM",
+    "CRAN", "CRAP", "CRAW", "CRAY", "CRED", "CREE", "CREM", "CREP", "CRIA", "CRIB", "CRIE", "CRIM",
+    "CRIO", "CRIP", "CRIT", "CROC", "CROG", "CRON", "CROS", "CROW", "CRUD", "CRUE", "CUBE", "CUBS",
+    "CUFF", "CUIF", "CUIT", "CUKE", "CULL", "CULM", "CULT", "CUNT", "CUPS", "CURB", "CURD", "CURF",
+    "CURL", "CURN", "CURR", "CURS", "CUSK", "CUSP", "CUTE", "CUTI", "CUTS", "CUZE", "CYAN", "CYCA",
+    "CYMA", "CYME", "CYST", "CYTE", "CZAR", "DAAL", "DACE", "DACK", "DADA", "DADO", "DAFF", "DAFT",
+    "DAGO", "DAHL", "DAIL", "DALI", "DALT", "DAME", "DAMN", "DAMP", "DANG", "DANK", "DANT", "DARB",
+    "DARG", "DARI", "DARN", "DART", "DASH", "DATO", "DAUB", "DAUD", "DAUR", "DAUT", "DAWD", "DAWK",
+    "DAWT", "DAYS", "DAZE", "DEAF", "DEAW", "DEBE", "DEBU", "DECO", "DEED", "DEEM", "DEEN", "DEET",
+    "DEEV", "DEFI", "DEFT", "DEFY", "DEGA", "DEGU", "DEID", "DEIL", "DEKE", "DELE", "DELF", "DELI",
+    "DELO", "DELT", "DEME", "DEMO", "DENE", "DENI", "DENS", "DENT", "DENY", "DERE", "DERM", "DERN",
+    "DERO", "DERV", "DESI", "DEVA", "DEVI", "DEVO", "DEWY", "DEXE", "DGAG", "DHAK", "DHAL", "DHOL",
+    "DHOW", "DIBB", "DICE", "DICK", "DICT", "DIDO", "DIEB", "DIEL", "DIFF", "DIKA", "DIKE", "DILL",
+    "DIME", "DIMP", "DINE", "DING", "DINK", "DINT", "DIOL", "DIRE", "DIRL", "DISA", "DISC", "DITA",
+    "DITE", "DITT", "DIVA", "DIVI", "DIVO", "DIYA", "DJIN", "DMOD", "DOAB", "DOAT", "DOBE", "DOCO",
+    "DOCU", "DODO", "DOEK", "DOER", "DOFF", "DOGE", "DOGS", "DOIT", "DOJO", "DOLE", "DOLL", "DOLO",
+    "DOLT", "DOME", "DOOB", "DOOK", "DOOL", "DOOM", "DOPA", "DOPE", "DOPY", "DORB", "DORE", "DORK",
+    "DORM", "DORP", "DORR", "DORT", "DORY", "DOSA", "DOTE", "DOTY", "DOUC", "DOUK", "DOUM", "DOUP",
+    "DOUR", "DOUT", "DOUX", "DOVE", "DOWD", "DOWF", "DOWL", "DOWP", "DOWT", "DOWY", "DOXE", "DOZE",
+    "DOZY", "DRAB", "DRAG", "DRAM", "DRAP", "DRAT", "DRAY", "DREE", "DREG", "DREK", "DREY", "DRIB",
+    "DRIE", "DRIP", "DROW", "DRUB", "DRYA", "DRYS", "DUAD", "DUAN", "DUAR", "DUCE", "DUCT", "DUDE",
+    "DUEL", "DUET", "DUFF", "DUFU", "DUIT", "DUKA", "DULE", "DULL", "DULY", "DUMA", "DUMB", "DUMP",
+    "DUNE", "DUNK", "DUNS", "DUNT", "DUPE", "DURA", "DURE", "DURN", "DURO", "DURR", "DUSK", "DUXE",
+    "DWAM", "DYAD", "DYER", "DYKE", "DYNE", "DZHO", "EALE", "EARD", "EARS", "EAVE", "EBON", "ECAD",
+    "ECHE", "ECHT", "ECRU", "EDGY", "EDIT", "EELS", "EELY", "EERY", "EEVN", "EGAD", "EGAL", "EGER",
+    "EGGS", "EGMA", "EIDO", "EILD", "EKKA", "ELAN", "ELHI", "ELMY", "ELOP", "ELVE", "EMAC", "EMBU",
+    "EMEU", "EMIC", "EMIR", "EMIT", "EMPT", "EMUS", "EMYD", "ENDS", "ENEW", "ENOL", "ENOW", "EORL",
+    "EPEE", "EPHA", "EPIC", "EPRI", "EQUE", "ERAS", "EREV", "ERGO", "ERHU", "ERNE", "ERSE", "ERUV",
+    "ESNE", "ESSE", "ETAT", "ETEN", "ETHO", "ETIC", "ETNA", "ETUI", "EUGH", "EURO", "EVET", "EWER",
+    "EXAM", "EXEC", "EXON", "EXPO", "EXUL", "EYED", "EYER", "EYES", "EYOT", "EYRA", "EYRE", "FADE",
+    "FADO", "FAFF", "FAIK", "FAIN", "FAKE", "FAND", "FANE", "FANG", "FANK", "FANO", "FANS", "FARD",
+    "FARE", "FARL", "FARO", "FART", "FAUN", "FAUT", "FAVA", "FAVE", "FAVU", "FAWN", "FAXE", "FAZE",
+    "FEAL", "FEAT", "FECE", "FECK", "FEEB", "FEEN", "FEER", "FEES", "FEME", "FEND", "FENI", "FENK",
+    "FENT", "FEOD", "FERE", "FERM", "FEST", "FETA", "FETE", "FETT", "FETU", "FEUD", "FEZE", "FIAR",
+    "FIAT", "FICE", "FICO", "FICU", "FIDE", "FIDO", "FIEF", "FIER", "FIFE", "FIFI", "FIGO", "FIKE",
+    "FILK", "FILO", "FINI", "FINK", "FINO", "FINS", "FIQH", "FIRK", "FIRN", "FIRS", "FISC", "FISK",
+    "FIST", "FITS", "FITT", "FIXE", "FLAB", "FLAK", "FLAM", "FLAN", "FLAP", "FLAW", "FLAX", "FLAY",
+    "FLEA", "FLEE", "FLEG", "FLEY", "FLIC", "FLIE", "FLIM", "FLIP", "FLIR", "FLIT", "FLOB", "FLOC",
+    "FLOE", "FLOG", "FLOP", "FLUB", "FLUE", "FLYS", "FOAL", "FOAM", "FOCU", "FOGS", "FOGY", "FOHN",
+    "FOID", "FOIL", "FOIN", "FOLD", "FOME", "FOND", "FONE", "FONT", "FOOL", "FORB", "FORE", "FORK",
+    "FOUD", "FOUL", "FOUN", "FOWL", "FOXE", "FOXY", "FOZY", "FRAB", "FRAG", "FRAP", "FRAT", "FRAU",
+    "FRAY", "FRES", "FRET", "FRIB", "FRIE", "FRIG", "FRIT", "FROE", "FROG", "FRON", "FROW", "FRUG",
+    "FUCK", "FUCU", "FUFF", "FUGU", "FUJI", "FUMA", "FUME", "FUMY", "FUNG", "FURL", "FURR", "FURY",
+    "FUSE", "FUSK", "FUSS", "FUST", "FUZE", "FYCE", "FYKE", "FYLE", "FYRD", "GADE", "GADI", "GAFF",
+    "GAGA", "GAID", "GAIR", "GAIT", "GAJO", "GALL", "GAMA", "GAMB", "GAMP", "GAMY", "GANG", "GANT",
+    "GAOL", "GAPE", "GAPO", "GAPS", "GAPY", "GARB", "GARE", "GARI", "GARM", "GASE", "GASP", "GAST",
+    "GATH", "GAUD", "GAUM", "GAUP", "GAUR", "GAWD", "GAWK", "GAWP", "GAZE", "GEAL", "GEAN", "GEAT",
+    "GECK", "GEEK", "GEEP", "GEIT", "GELD", "GELT", "GENT", "GENU", "GERE", "GERM", "GEST", "GETA",
+    "GEUM", "GHAT", "GHEE", "GIBE", "GIBU", "GIGA", "GILA", "GILD", "GILL", "GILT", "GIMP", "GING",
+    "GINK", "GIRD", "GIRN", "GIRO", "GIRR", "GIRT", "GISM", "GIST", "GITE", "GLAM", "GLAN", "GLED",
+    "GLEE", "GLEG", "GLEI", "GLEY", "GLIA", "GLIB", "GLIM", "GLIT", "GLOB", "GLOM", "GLOP", "GLOW",
+    "GLUE", "GLUG", "GLUM", "GLUT", "GNAR", "GNAT", "GNAW", "GNOW", "GNUS", "GOAD", "GOAF", "GOAR",
+    "GOAT", "GOBI", "GOBO", "GOBY", "GOEL", "GOER", "GOFF", "GOGO", "GOJI", "GOLE", "GOLP", "GONG",
+    "GONK", "GONY", "GOOF", "GOOG", "GOOK", "GOOL", "GOON", "GOOP", "GOOR", "GORA", "GORE", "GORI",
+    "GORM", "GORP", "GORY", "GOTH", "GOUK", "GOUT", "GOWD", "GOWF", "GOWK", "GOWL", "GOWN", "GOXE",
+    "GRAB", "GRAD", "GRAM", "GRAN", "GRAV", "GREE", "GREN", "GRIG", "GRIM", "GRIT", "GROG", "GROK",
+    "GROT", "GRRL", "GRUB", "GRUE", "GRUM", "GUAN", "GUAR", "GUCK", "GUDE", "GUFF", "GUGA", "GUID",
+    "GULA", "GULE", "GULL", "GULP", "GUMP", "GUNG", "GUNK", "GUNS", "GURL", "GURN", "GURU", "GUSH",
+    "GYAL", "GYAN", "GYBE", "GYMP", "GYNO", "GYPO", "GYRE", "GYRO", "GYRU", "GYTE", "GYVE", "HAAF",
+    "HAAR", "HABU", "HACK", "HADE", "HAEM", "HAET", "HAFF", "HAFT", "HAGG", "HAHA", "HAIK", "HAIL",
+    "HAIN", "HAJE", "HAJI", "HAKA", "HAKE", "HAKU", "HALE", "HALM", "HALO", "HALT", "HAME", "HANT",
+    "HAPU", "HARE", "HARK", "HARL", "HARN", "HARO", "HARP", "HART", "HASH", "HASK", "HASP", "HAST",
+    "HATS", "HAUD", "HAUF", "HAUL", "HAUN", "HAWK", "HAWM", "HAZE", "HAZY", "HEAL", "HEAP", "HEAV",
+    "HEBE", "HECK", "HEED", "HEEL", "HEFT", "HEID", "HEIL", "HEIR", "HELE", "HELM", "HELO", "HEME",
+    "HEMP", "HEND", "HENS", "HENT", "HERD", "HERL", "HERM", "HERN", "HERS", "HESP", "HEST", "HETE",
+    "HETH", "HEXE", "HICK", "HIDE", "HIEM", "HIKE", "HILT", "HILU", "HIND", "HING", "HIOI", "HIPS",
+    "HISS", "HIST", "HIVE", "HOAR", "HOBO", "HOCK", "HOCU", "HOER", "HOGG", "HOGH", "HOGS", "HOIK",
+    "HOKA", "HOKE", "HOKI", "HOLK", "HOLM", "HOLO", "HOLT", "HOMA", "HOMO", "HOMY", "HOND", "HONE",
+    "HONK", "HOOD", "HOOF", "HOOK", "HOON", "HOOP", "HOOR", "HOOT", "HORA", "HORI", "HORK", "HOSE",
+    "HOUF", "HOUT", "HOVE", "HOWF", "HOWK", "HOWL", "HOXE", "HOYA", "HUCK", "HUED", "HUER", "HUFF",
+    "HUGS", "HUHU", "HUIA", "HULA", "HULE", "HULK", "HULL", "HUMA", "HUMF", "HUMP", "HUMU", "HUNK",
+    "HURD", "HURL", "HUSH", "HUSK", "HUSO", "HWYL", "HYEN", "HYKE", "HYLA", "HYLE", "HYMN", "HYPE",
+    "HYPO", "HYTE", "IAMB", "IBEX", "IBIS", "ICED", "ICER", "ICES", "ICHE", "ICKY", "ICON", "ICTU",
+    "IDEE", "IDLE", "IDLY", "IDOL", "IDYL", "IFFY", "IGLU", "IKAN", "IKAT", "IKON", "ILEU", "ILLY",
+    "IMAM", "IMID", "IMPI", "IMPS", "INCU", "INFO", "INKY", "INLY", "INTI", "IOTA", "IRID", "ISBA",
+    "ISHE", "ISLE", "ITCH", "IVIE", "IXIA", "IZAR", "JAAP", "JAFA", "JAGA", "JAGG", "JAIL", "JAMB",
+    "JAMS", "JAPE", "JARK", "JARL", "JARP", "JASP", "JATO", "JAUK", "JAUP", "JAVA", "JEAT", "JEDI",
+    "JEEL", "JEEP", "JEER", "JEFE", "JEHU", "JELL", "JEON", "JERK", "JEST", "JESU", "JETE", "JIAO",
+    "JIBB", "JIBE", "JIFF", "JILT", "JIMP", "JINK", "JINN", "JIRD", "JISM", "JIVE", "JOBE", "JOBS",
+    "JOCK", "JOCO", "JOLE", "JOLL", "JOLT", "JOMO", "JOOK", "JOTA", "JOUG", "JOUK", "JOUR", "JOWL",
+    "JUBA", "JUBE", "JUCO", "JUDA", "JUDO", "JUJU", "JUKE", "JUKU", "JUNK", "JUPE", "JURE", "JUTE",
+    "JUVE", "KACK", "KADI", "KAGO", "KAGU", "KAID", "KAIE", "KAIF", "KAIK", "KAIL", "KAIM", "KAIN",
+    "KAKA", "KAKI", "KAMA", "KAME", "KANA", "KANG", "KANT", "KAON", "KAPA", "KAPH", "KAPU", "KARK",
+    "KARN", "KARO", "KART", "KATA", "KAVA", "KAWA", "KAYO", "KAZI", "KBAR", "KECK", "KEEF", "KEEK",
+    "KEEL", "KEET", "KEIR", "KELL", "KELP", "KELT", "KEMB", "KEMP", "KENO", "KEPI", "KERB", "KERF",
+    "KERN", "KERO", "KEST", "KETA", "KETE", "KETO", "KEXE", "KEYS", "KHAF", "KHAN", "KHAT", "KHET",
+    "KHOR", "KHUD", "KIBE", "KIEF", "KIER", "KIEV", "KIKE", "KILL", "KILN", "KILO", "KILP", "KILT",
+    "KINE", "KINK", "KINO", "KIPE", "KIPP", "KIRN", "KIST", "KITE", "KITH", "KIVA", "KIWI", "KLAP",
+    "KLIK", "KNAG", "KNAP", "KNAR", "KNOB", "KNOP", "KNUB", "KNUR", "KNUT", "KOAN", "KOAP", "KOBO",
+    "KOEL", "KOFF", "KOHA", "KOHL", "KOJI", "KOKA", "KOLA", "KOLO", "KONK", "KOOK", "KOPH", "KORE",
+    "KORO", "KORU", "KOSE", "KOTO", "KRAB", "KRAI", "KRAN", "KRAY", "KSAR", "KUDO", "KUDU", "KUFI",
+    "KUIA", "KUKU", "KULA", "KUMI", "KUMY", "KURI", "KURU", "KUTA", "KUTI", "KUTU", "KUZU", "KYAK",
+    "KYAR", "KYAT", "KYBO", "KYND", "KYPE", "KYTE", "LABI", "LACE", "LADE", "LAER", "LAIC", "LAIK",
+    "LAIR", "LAKH", "LAKY", "LALL", "LAMA", "LAMB", "LAME", "LANK", "LANT", "LAPI", "LAPS", "LARD",
+    "LARE", "LARG", "LARI", "LARK", "LARN", "LASE", "LASH", "LASS", "LATH", "LATU", "LAUD", "LAUF",
+    "LAVA", "LAVE", "LAWK", "LAWN", "LAWS", "LAXE", "LAZE", "LAZO", "LAZY", "LEAK", "LEAL", "LEAM",
+    "LEAP", "LEAR", "LEAT", "LEDE", "LEEK", "LEEP", "LEER", "LEET", "LEGE", "LEGS", "LEHR", "LEIR",
+    "LEME", "LENE", "LENG", "LENI", "LENO", "LERE", "LERP", "LESE", "LEST", "LETT", "LEUD", "LEVA",
+    "LEVE", "LEVO", "LEWD", "LEWI", "LEXE", "LEZE", "LIAR", "LICK", "LIDO", "LIEF", "LIER", "LIEU",
+    "LILL", "LILO", "LILT", "LIMA", "LIMB", "LIMN", "LIMO", "LIMP", "LIMY", "LING", "LINT", "LINY",
+    "LIPA", "LIPE", "LIPO", "LIPS", "LIRA", "LIRK", "LISK", "LISP", "LITE", "LITH", "LOAF", "LOAM",
+    "LOBE", "LOBO", "LOBU", "LOCH", "LOCI", "LOCO", "LOCU", "LODE", "LOFT", "LOGE", "LOGY", "LOID",
+    "LOIN", "LOIR", "LOKE", "LOLL", "LOME", "LOND", "LONE", "LOOF", "LOOM", "LOON", "LOOP", "LOOT",
+    "LOPE", "LORN", "LOTA", "LOTE", "LOTH", "LOTO", "LOTS", "LOTU", "LOUI", "LOUN", "LOUP", "LOUR",
+    "LOUS", "LOUT", "LOWN", "LOWP", "LOWT", "LOXE", "LUAU", "LUBE", "LUCE", "LUDE", "LUDO", "LUFF",
+    "LUGE", "LULL", "LUMA", "LUMB", "LUMP", "LUNE", "LUNK", "LUNT", "LUNY", "LUPU", "LURE", "LURK",
+    "LUSH", "LUSK", "LUST", "LUSU", "LUTE", "LUXE", "LWEI", "LYAM", "LYME", "LYNE", "LYNX", "LYRE",
+    "LYSE", "LYSI", "LYTE", "MAAR", "MABE", "MACA", "MACE", "MACH", "MAGE", "MAGG", "MAGU", "MAID",
+    "MAIK", "MAIM", "MAIR", "MAKI", "MAKO", "MALA", "MALI", "MALM", "MALT", "MALU", "MAMA", "MANE",
+    "MANG", "MANI", "MANO", "MANU", "MAPS", "MARE", "MARL", "MARM", "MARS", "MART", "MASA", "MASE",
+    "MASK", "MAST", "MASU", "MATE", "MATH", "MAUL", "MAUN", "MAUT", "MAVI", "MAWK", "MAWN", "MAWR",
+    "MAXE", "MAXI", "MAYO", "MAZE", "MAZY", "MEAD", "MEBO", "MECH", "MECK", "MEED", "MEEK", "MEER",
+    "MEFF", "MEIN", "MELA", "MELD", "MELL", "MEME", "MEMO", "MEND", "MENE", "MENG", "MEOU", "MEOW",
+    "MERC", "MERK", "MESA", "MESE", "MESH", "METE", "METH", "METI", "MEVE", "MEWL", "MEZE", "MICE",
+    "MICK", "MICO", "MIDI", "MIEN", "MIFF", "MIGG", "MIHA", "MIHI", "MILF", "MILT", "MIME", "MINI",
+    "MINK", "MINO", "MINT", "MINU", "MIRE", "MIRK", "MIRO", "MIRV", "MIRY", "MISE", "MISO", "MIST",
+    "MITE", "MITI", "MITT", "MIXE", "MOAN", "MOAT", "MOBE", "MOCH", "MOCK", "MODU", "MOER", "MOFO",
+    "MOHO", "MOIL", "MOIT", "MOJO", "MOKE", "MOKI", "MOKO", "MOLA", "MOLD", "MOLE", "MOLL", "MOLT",
+    "MOLY", "MOME", "MOMU", "MONG", "MONK", "MONO", "MOOB", "MOOK", "MOOL", "MOOP", "MOOR", "MOOT",
+    "MOPE", "MOPU", "MOPY", "MORN", "MORT", "MOSK", "MOSS", "MOTE", "MOTH", "MOTI", "MOTT", "MOTU",
+    "MOUE", "MOUL", "MOUN", "MOUP", "MOWA", "MOXA", "MOYA", "MOYL", "MOZE", "MOZO", "MUCK", "MUCU",
+    "MUFF", "MUGG", "MUID", "MUIL", "MUIR", "MULE", "MULL", "MUMM", "MUMP", "MUMU", "MUNG", "MUNI",
+    "MUNT", "MUON", "MURA", "MURE", "MURK", "MURR", "MUSE", "MUSK", "MUSO", "MUSS", "MUTE", "MUTI",
+    "MUTT", "MUXE", "MYNA", "MYOP", "MYXO", "MZEE", "NAAM", "NAAN", "NABE", "NABI", "NABK", "NAFF",
+    "NAGA", "NAIF", "NAIK", "NAIL", "NALA", "NAMU", "NANO", "NAPA", "NAPE", "NARA", "NARC", "NARD",
+    "NARE", "NARI", "NARK", "NATE", "NATI", "NAVE", "NAZE", "NAZI", "NEAP", "NEEM", "NEEP", "NEGU",
+    "NEIF", "NELI", "NEMA", "NEMN", "NENE", "NEON", "NERD", "NERK", "NERT", "NESH", "NEST", "NETE",
+    "NETS", "NETT", "NEUK", "NEUM", "NEVE", "NEVU", "NEWB", "NEWT", "NEXU", "NIDE", "NIDU", "NIEF",
+    "NIFE", "NIFF", "NIGH", "NILL", "NIMB", "NIMP", "NIPA", "NIRL", "NISI", "NISU", "NITE", "NIXE",
+    "NOCK", "NODE", "NODU", "NOGG", "NOIL", "NOIR", "NOLE", "NOLL", "NOLO", "NOME", "NOMO", "NONG",
+    "NONI", "NOOB", "NOOK", "NOON", "NOOP", "NORI", "NORK", "NORM", "NOSY", "NOUL", "NOUP", "NOWL",
+    "NOWT", "NOXE", "NOYE", "NUDE", "NUFF", "NUKE", "NULL", "NUMB", "NURD", "NURL", "NURR", "NUTS",
+    "OAKS", "OASE", "OASI", "OAST", "OATH", "OAVE", "OBEY", "OBIA", "OBIT", "OBOE", "OBOL", "OCHE",
+    "OCTA", "ODAH", "ODAL", "ODOR", "ODYL", "OFAY", "OFFE", "OGAM", "OGEE", "OGLE", "OGRE", "OHIA",
+    "OILY", "OINK", "OINT", "OKAY", "OKEH", "OKRA", "OKTA", "OLEO", "OLID", "OLIO", "OLLA", "OLPE",
+    "OMBU", "OMEN", "OMIT", "OMOV", "ONCU", "ONER", "ONES", "ONKU", "OONT", "OORY", "OOSE", "OOZE",
+    "OOZY", "OPAH", "OPPO", "ORCA", "ORDO", "ORFE", "ORLE", "ORNI", "ORRI", "ORYX", "ORZO", "OTIC",
+    "OUEN", "OULK", "OUMA", "OUPA", "OUPH", "OUST", "OUTR", "OUTS", "OUZO", "OVAL", "OVEL", "OWIE",
+    "OWLS", "OWNS", "OWRE", "OXER", "OXID", "OXIE", "OXIM", "OYER", "PAAL", "PAAN", "PACA", "PACO",
+    "PACT", "PADI", "PAIK", "PAIL", "PALA", "PALE", "PALI", "PALL", "PALP", "PALS", "PALY", "PANA",
+    "PAND", "PANE", "PANG", "PANS", "PANT", "PAPA", "PAPE", "PARA", "PARD", "PARE", "PARI", "PARP",
+    "PARR", "PASE", "PATE", "PATT", "PATU", "PATY", "PAUA", "PAVE", "PAVI", "PAWA", "PAWK", "PAWL",
+    "PAWN", "PAXE", "PAYS", "PEAG", "PEAL", "PEAN", "PEAR", "PEAT", "PEBA", "PECH", "PECK", "PEDE",
+    "PEDI", "PEEK", "PEEL", "PEEN", "PEEP", "PEER", "PEGH", "PEIN", "PEKE", "PELA", "PELE", "PELF",
+    "PELL", "PELT", "PEND", "PENE", "PENI", "PENK", "PENN", "PENS", "PENT", "PEON", "PEPO", "PERC",
+    "PERE", "PERI", "PERK", "PERM", "PERN", "PERP", "PERT", "PERV", "PESO", "PEST", "PETS", "PHON",
+    "PHOT", "PHUT", "PIAL", "PIAN", "PICA", "PIED", "PIER", "PIES", "PIET", "PIGS", "PIKA", "PIKE",
+    "PIKI", "PILI", "PILL", "PILU", "PILY", "PIMA", "PIMP", "PINA", "PINS", "PINT", "PINY", "PION",
+    "PIOU", "PIOY", "PIPA", "PIPI", "PIPS", "PIPY", "PIRL", "PIRN", "PISE", "PISO", "PITA", "PITH",
+    "PIUM", "PIXE", "PIZE", "PLAA", "PLAC", "PLAP", "PLAT", "PLEA", "PLEB", "PLEW", "PLIE", "PLIM",
+    "PLOD", "PLOP", "PLOW", "PLOY", "PLUE", "PLUM", "POCK", "POEP", "POGO", "POIN", "POKE", "POLI",
+    "POLK", "POLO", "POLT", "POLY", "POME", "POMO", "POMP", "PONE", "PONG", "PONK", "PONT", "PONY",
+    "POOD", "POOF", "POOH", "POOK", "POON", "POOP", "POOT", "POPE", "POPS", "PORE", "PORK", "PORN",
+    "POTE", "POTS", "POTT", "POUF", "POUK", "POUT", "POWN", "POXE", "PRAD", "PRAM", "PRAO", "PRAT",
+    "PRAU", "PREE", "PREM", "PREP", "PREY", "PRIE", "PRIG", "PRIM", "PROA", "PROB", "PROD", "PROF",
+    "PROG", "PROM", "PROP", "PROV", "PROW", "PSOA", "PUBE", "PUBI", "PUCE", "PUCK", "PUDU", "PUER",
+    "PUFF", "PUHA", "PUIR", "PUJA", "PUKA", "PUKE", "PUKU", "PULA", "PULE", "PULI", "PULK", "PULP",
+    "PULU", "PUMA", "PUMP", "PUNA", "PUNG", "PUNK", "PUNT", "PUNY", "PUPA", "PUPU", "PURI", "PURL",
+    "PURR", "PUSE", "PUSS", "PUTS", "PUTT", "PYAT", "PYET", "PYIC", "PYIN", "PYNE", "PYOT", "PYRE",
+    "PYRO", "PYXE", "PYXI", "QADI", "QAID", "QOPH", "QUAD", "QUAG", "QUAI", "QUAT", "QUAY", "QUEY",
+    "QUID", "QUIM", "QUIN", "QUIP", "QUIZ", "QUOD", "QUOP", "RABI", "RACK", "RACY", "RAFF", "RAFT",
+    "RAGA", "RAGE", "RAGG", "RAGI", "RAGU", "RAIA", "RAID", "RAIK", "RAIT", "RAJA", "RAJE", "RAKE",
+    "RAKI", "RAKU", "RALE", "RAMI", "RAMP", "RAMU", "RAND", "RANG", "RANI", "RANT", "RAPE", "RAPT",
+    "RARK", "RASE", "RASH", "RASP", "RATA", "RATO", "RATS", "RATU", "RAUN", "RAVE", "RAWN", "RAXE",
+    "RAYA", "RAZE", "REAI", "REAK", "REAM", "REAN", "REAP", "REAR", "REBU", "RECK", "REDD", "REDE",
+    "REDO", "REEF", "REEK", "REEL", "REEN", "REFI", "REGE", "REGO", "REIF", "REIK", "REIN", "REKE",
+    "REND", "RENO", "REPO", "REPP", "RESE", "RESU", "REXE", "REZE", "RHIE", "RIAD", "RIAL", "RIBA",
+    "RIBE", "RIBS", "RIDG", "RIEL", "RIEM", "RIFE", "RIFF", "RIFT", "RIGG", "RILE", "RILL", "RIME",
+    "RIMU", "RIMY", "RIND", "RINE", "RINK", "RIOT", "RIPE", "RIPP", "RISP", "RISU", "RITE", "RITT",
+    "RIVE", "RIZA", "ROAM", "ROAN", "ROAR", "ROBE", "RODE", "ROHE", "ROID", "ROIL", "ROIN", "ROJI",
+    "ROKE", "ROMP", "RONE", "RONT", "ROOD", "ROOK", "ROON", "ROOP", "ROPY", "RORE", "RORT", "ROST",
+    "ROTA", "ROTE", "ROTI", "ROTL", "ROTO", "ROUE", "ROUL", "ROUM", "ROUP", "ROUT", "ROVE", "ROWT",
+    "RUBE", "RUBS", "RUBU", "RUCK", "RUDA", "RUDD", "RUDE", "RUDI", "RUER", "RUFF", "RUGS", "RUIN",
+    "RUKH", "RULY", "RUME", "RUMP", "RUND", "RUNE", "RUNG", "RUNS", "RUNT", "RURP", "RURU", "RUSA",
+    "RUSE", "RUSK", "RUST", "RYAL", "RYKE", "RYND", "RYOT", "SAAG", "SABE", "SACK", "SADI", "SADO",
+    "SAGA", "SAGO", "SAIC", "SAIL", "SAIM", "SAIN", "SAIR", "SAKI", "SALA", "SALD", "SALP", "SAMA",
+    "SAMP", "SANE", "SANT", "SARD", "SARK", "SARO", "SARU", "SATE", "SATI", "SAUT", "SAXE", "SAYS",
+    "SAZE", "SCAB", "SCAD", "SCAG", "SCAM", "SCAN", "SCAR", "SCAT", "SCAW", "SCOG", "SCOP", "SCOW",
+    "SCUD", "SCUG", "SCUL", "SCUM", "SCUP", "SCUR", "SCUT", "SCYE", "SEAM", "SEAR", "SEAS", "SECH",
+    "SECT", "SEDE", "SEEL", "SEEP", "SEER", "SEGO", "SEIF", "SEIL", "SEIR", "SEKO", "SEKT", "SELE",
+    "SEME", "SEMI", "SENE", "SEPT", "SERE", "SERF", "SERK", "SERR", "SETS", "SETT", "SEXE", "SEXT",
+    "SHAG", "SHAH", "SHAK", "SHAL", "SHAM", "SHAP", "SHAR", "SHAW", "SHED", "SHET", "SHEW", "SHIE",
+    "SHIL", "SHIM", "SHIR", "SHIT", "SHIV", "SHOD", "SHOG", "SHOO", "SHRI", "SHUL", "SHUN", "SHWA",
+    "SIAL", "SIBB", "SICE", "SIDA", "SIEN", "SIFT", "SIGH", "SIJO", "SIKA", "SIKE", "SILD", "SILE",
+    "SILL", "SILO", "SILT", "SIMI", "SIMP", "SIND", "SINE", "SINH", "SINU", "SIPE", "SIRE", "SIRI",
+    "SISE", "SIST", "SITS", "SITU", "SIXE", "SIZY", "SKAG", "SKAT", "SKAW", "SKED", "SKEE", "SKEG",
+    "SKEN", "SKEO", "SKEP", "SKER", "SKET", "SKEW", "SKID", "SKIE", "SKIM", "SKIO", "SKIP", "SKIT",
+    "SKOG", "SKOL", "SKUA", "SKUG", "SKYF", "SKYR", "SLAB", "SLAE", "SLAG", "SLAM", "SLAP", "SLAT",
+    "SLAW", "SLAY", "SLEB", "SLED", "SLEW", "SLEY", "SLID", "SLIM", "SLIT", "SLOB", "SLOE", "SLOG",
+    "SLOP", "SLOT", "SLUB", "SLUE", "SLUG", "SLUM", "SLUR", "SLUT", "SMEE", "SMEW", "SMIR", "SMIT",
+    "SMOG", "SMUG", "SMUR", "SMUT", "SNAB", "SNAG", "SNAR", "SNAW", "SNEB", "SNED", "SNEE", "SNIB",
+    "SNIE", "SNIG", "SNIP", "SNIT", "SNOB", "SNOD", "SNOG", "SNOT", "SNUB", "SNUG", "SNYE", "SOAK",
+    "SOAR", "SOBA", "SOCA", "SOCE", "SOCK", "SODA", "SOFA", "SOJA", "SOJU", "SOKE", "SOLA", "SOLO",
+    "SOLU", "SOMA", "SONE", "SONS", "SOOL", "SOOM", "SOOP", "SOOT", "SOPH", "SORA", "SORB", "SORD",
+    "SORE", "SORN", "SORU", "SOTH", "SOUK", "SOUM", "SOUN", "SOUR", "SOUS", "SOUT", "SOWF", "SOWL",
+    "SOWM", "SOWN", "SOWP", "SOWS", "SOYA", "SPAE", "SPAG", "SPAM", "SPAN", "SPAR", "SPAT", "SPAW",
+    "SPAY", "SPEC", "SPEK", "SPEO", "SPET", "SPEW", "SPIC", "SPIE", "SPIF", "SPIK", "SPIM", "SPIT",
+    "SPIV", "SPOD", "SPRY", "SPUD", "SPUE", "SPUG", "SPUR", "STAB", "STAG", "STAP", "STAT", "STAW",
+    "STED", "STEE", "STEM", "STEN", "STET", "STEW", "STEY", "STIE", "STIM", "STOA", "STOB", "STOT",
+    "STOW", "STUB", "STUD", "STUM", "STUN", "STYE", "SUBA", "SUCK", "SUDD", "SUDS", "SUER", "SUET",
+    "SUGH", "SUGO", "SUID", "SUKH", "SULK", "SULU", "SUMI", "SUMO", "SUMP", "SUNI", "SUNK", "SUNN",
+    "SUNS", "SUPE", "SURA", "SURD", "SURF", "SUSE", "SUSU", "SWAB", "SWAD", "SWAG", "SWAM", "SWAN",
+    "SWAP", "SWAT", "SWAY", "SWEE", "SWEY", "SWIE", "SWIG", "SWIT", "SWOB", "SWOP", "SWOT", "SYCE",
+    "SYEN", "SYKE", "SYLI", "SYNC", "SYND", "SYNE", "SYPE", "SYPH", "TAAL", "TABE", "TABI", "TABU",
+    "TACE", "TACH", "TACK", "TACO", "TACT", "TAEL", "TAGS", "TAHA", "TAHR", "TAIG", "TAIN", "TAIT",
+    "TAJE", "TAKA", "TAKI", "TALA", "TALC", "TALU", "TAME", "TAMP", "TANG", "TANH", "TAPA", "TAPI",
+    "TAPS", "TAPU", "TARE", "TARN", "TARO", "TARP", "TART", "TASE", "TATH", "TATT", "TATU", "TAUT",
+    "TAVA", "TAWA", "TAWT", "TAXE", "TAXI", "TAXU", "TEAD", "TEAK", "TEAL", "TEAT", "TECH", "TEEL",
+    "TEEM", "TEEN", "TEER", "TEFF", "TEGG", "TEGU", "TEHR", "TEIL", "TEIN", "TELE", "TELO", "TEME",
+    "TEMP", "TENE", "TENS", "TEPA", "TERE", "TERF", "TERN", "TERT", "TETE", "TETH", "TEXA", "TEXE",
+    "THAR", "THAW", "THEE", "THEW", "THIG", "THIO", "THOU", "THRE", "THUD", "THUG", "TIAN", "TIAR",
+    "TICE", "TICK", "TIDY", "TIED", "TIER", "TIFF", "TIFO", "TIFT", "TIGE", "TIKA", "TIKE", "TIKI",
+    "TILE", "TILL", "TILT", "TIME", "TIMP", "TIND", "TINE", "TING", "TINK", "TINT", "TIPI", "TIPS",
+    "TIRE", "TIRL", "TIRO", "TIRR", "TITI", "TIYN", "TIZE", "TOAD", "TOCK", "TOCO", "TODY", "TOEA",
+    "TOED", "TOES", "TOEY", "TOFF", "TOFT", "TOFU", "TOGA", "TOGE", "TOHO", "TOIL", "TOIT", "TOKE",
+    "TOKO", "TOLA", "TOLE", "TOLL", "TOLT", "TOLU", "TOMB", "TOME", "TOMO", "TONG", "TONK", "TONS",
+    "TONU", "TOOM", "TOON", "TOOT", "TOPE", "TOPH", "TOPI", "TOPO", "TOPS", "TORC", "TORE", "TORN",
+    "TORO", "TORR", "TORT", "TORU", "TOSA", "TOSE", "TOSS", "TOTE", "TOUK", "TOUN", "TOUT", "TOWT",
+    "TOWY", "TOYO", "TOYS", "TOZE", "TRAB", "TRAD", "TRAM", "TRAP", "TRAT", "TRAY", "TREF", "TREK",
+    "TREM", "TRES", "TRET", "TREW", "TRIE", "TRIG", "TRIM", "TRIN", "TRIO", "TROD", "TROG", "TROI",
+    "TRON", "TROT", "TROW", "TRUG", "TRYP", "TSAR", "TUBA", "TUBS", "TUCK", "TUFA", "TUFF", "TUFT",
+    "TULE", "TUMP", "TUNA", "TUND", "TUNG", "TURD", "TURF", "TURK", "TURM", "TURP", "TURR", "TUSK",
+    "TUTU", "TUXE", "TWAE", "TWAL", "TWAT", "TWAY", "TWEE", "TWIG", "TWIT", "TWOS", "TYEE", "TYER",
+    "TYKE", "TYMP", "TYNE", "TYPO", "TYPP", "TYRE", "TYRO", "TZAR", "UDAL", "UDON", "UGLY", "ULAN",
+    "ULNA", "ULVA", "UMBO", "UMMA", "UMPH", "UMRA", "UNAI", "UNAU", "UNCE", "UNCO", "UNCU", "UNDA",
+    "UNDO", "UPDO", "URAO", "URDY", "UREA", "URGE", "URIC", "URVA", "USES", "UVEA", "VADE", "VAGU",
+    "VAIL", "VAIN", "VAIR", "VAKA", "VALE", "VALI", "VAMP", "VANE", "VANG", "VANT", "VAPE", "VARA",
+    "VARE", "VARU", "VASE", "VATU", "VAUT", "VAXE", "VEAL", "VEEP", "VEER", "VEGA", "VEGE", "VEGO",
+    "VEIL", "VEIN", "VELD", "VELE", "VELL", "VEND", "VENT", "VENU", "VERB", "VERT", "VEST", "VEXE",
+    "VIAL", "VIBE", "VICE", "VIER", "VIEW", "VIFF", "VIGA", "VILE", "VILL", "VINE", "VINO", "VINT",
+    "VIOL", "VIRE", "VIRL", "VIRU", "VISA", "VISE", "VIVE", "VLEI", "VLIE", "VLOG", "VOAR", "VOCE",
+    "VOID", "VOIP", "VOLE", "VOLK", "VOLT", "VRIL", "VROU", "VROW", "VUGG", "VUGH", "VULN", "WAAC",
+    "WACK", "WADD", "WADI", "WADT", "WAFF", "WAFT", "WAIF", "WAIL", "WAIN", "WAIR", "WAKA", "WAKF",
+    "WALD", "WALE", "WALI", "WALY", "WAME", "WAMU", "WAND", "WANE", "WANG", "WANK", "WAQF", "WARB",
+    "WARE", "WARK", "WARP", "WARS", "WART", "WARY", "WASE", "WASM", "WASP", "WAST", "WATT", "WAUK",
+    "WAUL", "WAUR", "WAVY", "WAWA", "WAWE", "WAWL", "WAXE", "WAXY", "WAYS", "WEAL", "WEAN", "WEBB",
+    "WEBS", "WEED", "WEEL", "WEEM", "WEEN", "WEEP", "WEER", "WEET", "WEFT", "WEID", "WEIL", "WEIR",
+    "WEKA", "WELD", "WELK", "WELT", "WEMB", "WEND", "WERO", "WERY", "WETA", "WETS", "WEXE", "WHAM",
+    "WHAP", "WHET", "WHEW", "WHEY", "WHID", "WHIG", "WHIM", "WHIN", "WHIO", "WHIP", "WHIR", "WHIT",
+    "WHOL", "WHOP", "WHOW", "WHUP", "WICK", "WIEL", "WIKI", "WILE", "WILI", "WILT", "WILY", "WIMP",
+    "WINK", "WINN", "WINO", "WINS", "WINY", "WIPE", "WIRY", "WISP", "WISS", "WIST", "WITE", "WIVE",
+    "WIZE", "WOAD", "WOCK", "WOJU", "WOLD", "WOMB", "WONK", "WONT", "WOOF", "WOON", "WOOP", "WORM",
+    "WORT", "WOUL", "WREN", "WRIE", "WRIT", "WUDU", "WULL", "WUSE", "WYLE", "WYND", "WYNN", "WYTE",
+    "XERU", "XRAY", "XYST", "YAAR", "YABA", "YACK", "YAFF", "YAGE", "YAGI", "YALE", "YAMS", "YANK",
+    "YAPP", "YARK", "YARN", "YARR", "YATE", "YAUD", "YAUP", "YAWL", "YAWN", "YAWP", "YEAD", "YEAN",
+    "YECH", "YEDE", "YEED", "YEGG", "YELD", "YELK", "YELM", "YELP", "YELT", "YERD", "YERK", "YESE",
+    "YESK", "YEST", "YETI", "YETT", "YEUK", "YEVE", "YEXE", "YIKE", "YILL", "YIPE", "YIRD", "YIRK",
+    "YIRR", "YITE", "YLEM", "YLKE", "YMPE", "YOCK", "YODH", "YOGA", "YOGH", "YOGI", "YOKE", "YOLK",
+    "YOMP", "YONI", "YONK", "YOOF", "YOOP", "YORE", "YORK", "YORP", "YOUK", "YOUR", "YOWE", "YOWL",
+    "YUAN", "YUCA", "YUCK", "YUFT", "YUGA", "YUKE", "YULE", "YUMP", "YURT", "YUZU", "ZANY", "ZARF",
+    "ZARI", "ZATI", "ZAXE", "ZEAL", "ZEBU", "ZEDA", "ZEIN", "ZERK", "ZEST", "ZETA", "ZEXE", "ZEZE",
+    "ZIFF", "ZILA", "ZILL", "ZIMB", "ZINC", "ZINE", "ZING", "ZITI", "ZOBO", "ZOBU", "ZOEA", "ZONK",
+    "ZOOK", "ZOOM", "ZOON", "ZORI", "ZOUK", "ZULU", "ZUPA", "ZURF", "ZYME",
+];
+pub static GUESSES_6: &[&str] = &[
+    "ABASIC", "ABATED", "ABIENT", "ABJECT", "ABLAZE", "ABLEST", "ABLUSH", "ABOARD", "ABODES",
+    "ABORAL", "ABOUND", "ABRUPT", "ABSENT", "ABSURD", "ABULIC", "ABUSED", "ABUSES", "ACCORD",
+    "ACCUSE", "ACETIC", "ACHING", "ACIDIC", "ACIDLY", "ACINIC", "ACTING", "ACTORS", "ACUATE",
+    "ACUTER", "ADAGES", "ADAPTS", "ADDICT", "ADDING", "ADDLED", "ADIENT", "ADJUST", "ADMITS",
+    "ADNATE", "ADORED", "ADORES", "ADORNS", "ADROIT", "ADVISE", "AECIAL", "AERIAL", "AERILY",
+    "AFEARD", "AFFAIR", "AFFINE", "AFFIRM", "AFOCAL", "AFRAID", "AGAMIC", "AGAVES", "AGEDLY",
+    "AGENTS", "AGHAST", "AGLEAM", "AGLINT", "AGONAL", "AGONIC", "AGOUTI", "AGREED", "AGREES",
+    "AGUISH", "AIDFUL", "AILING", "AIMFUL", "AIMING", "AIRIER", "AIRILY", "AIRING", "AISLED",
+    "ALARMS", "ALCLAD", "ALGOID", "ALIBLE", "ALLAYS", "ALLEYS", "ALLIED", "ALLIES", "ALLOWS",
+    "ALLURE", "ALPACA", "ALPINE", "ALTARS", "ALTERN", "ALTERS", "ALULAR", "ALURED", "ALVINE",
+    "ALWITE", "AMAZED", "AMAZES", "AMBERY", "AMEBIC", "AMENDS", "AMIDIC", "AMINIC", "AMMONO",
+    "AMOEBA", "AMORAL", "AMPLER", "AMUSED", "AMUSES", "AMYLIC", "ANCHOR", "ANEMIC", "ANGERS",
+    "ANGLER", "ANGLES", "ANGORA", "ANIMAS", "ANISIC", "ANKLES", "ANNALS", "ANNOYS", "ANODIC",
+    "ANOMIC", "ANOXIC", "ANSATE", "ANTICS", "ANTRAL", "ANURIC", "AORTAL", "AORTIC", "APICAL",
+    "APNEAL", "APNEIC", "APODAL", "APPLES", "APRONS", "APROWL", "ARCANE", "ARCHED", "ARCHLY",
+    "ARCTIC", "ARDEID", "ARDENT", "AREADY", "ARGUED", "ARGUES", "ARIDLY", "ARIOSE", "ARISES",
+    "ARMIES", "ARMING", "ARMOUR", "AROUND", "AROUSE", "ARRANT", "ARREST", "ARROWS", "ARROWY",
+    "ARSENO", "ARSINO", "ARTFUL", "ARTIER", "ARTILY", "ASCEND", "ASEMIC", "ASHAKE", "ASHIER",
+    "ASKING", "ASPIRE", "ASPISH", "ASSETS", "ASSIST", "ASSORT", "ASSURE", "ASTHMA", "ASTRAL",
+    "ASTUTE", "ASWARM", "ATAVIC", "ATAXIC", "ATELIC", "ATOLLS", "ATOMIC", "ATONAL", "ATRIAL",
+    "ATTACH", "ATTENT", "ATTEST", "ATTICS", "ATTIRE", "AUGURS", "AUNTLY", "AUROUS", "AVAILS",
+    "AVERSE", "AVIDLY", "AVOCET", "AVOIDS", "AVOWED", "AWAITS", "AWAKES", "AWEDLY", "AWEIGH",
+    "AWHILE", "AWHIRL", "AWLESS", "AXENIC", "AXISED", "AXLIKE", "AXONAL", "AZONAL", "AZONIC",
+    "AZOTED", "AZOTIC", "BABIES", "BABOON", "BACKER", "BADGER", "BADGES", "BAIRNS", "BAITED",
+    "BAKERS", "BALDLY", "BALLED", "BALSAM", "BAMBOO", "BANDED", "BANGUP", "BANKED", "BANKER",
+    "BANNER", "BARBED", "BARBEL", "BARBER", "BARBET", "BARDIC", "BAREST", "BARFLY", "BARGES",
+    "BARING", "BARRED", "BARREL", "BARREN", "BASELY", "BASEST", "BASINS", "BASSET", "BATHED",
+    "BATHES", "BATTER", "BEACHY", "BEADED", "BEAGLE", "BEAKED", "BEAMED", "BEARDS", "BEARER",
+    "BEASTS", "BEATEN", "BEDBUG", "BEDRID", "BEECHY", "BEETLE", "BEFITS", "BEGGAR", "BEGINS",
+    "BEHAVE", "BEINGS", "BELIED", "BELOID", "BELTED", "BENGAL", "BENIGN", "BENZAL", "BEREFT",
+    "BETRAY", "BIASED", "BIDDER", "BIDING", "BIFLEX", "BIFORM", "BIGEYE", "BIGGER", "BILLET",
+    "BINATE", "BIONIC", "BIOTIC", "BIRTHS", "BITING", "BITTED", "BLACKS", "BLADED", "BLADES",
+    "BLAMED", "BLAMES", "BLANKS", "BLASTS", "BLASTY", "BLEARY", "BLEBBY", "BLENDS", "BLENNY",
+    "BLINDS", "BLINKS", "BLITHE", "BLOCKY", "BLOODS", "BLOODY", "BLOOMY", "BLOTTO", "BLOTTY",
+    "BLOUSE", "BLOUSY", "BLOWED", "BLOWER", "BLOWSY", "BLOWZY", "BLUELY", "BLUFFY", "BLUISH",
+    "BLURRY", "BLURTS", "BOARDS", "BOASTS", "BOBCAT", "BODGER", "BODIED", "BODIES", "BODILY",
+    "BODING", "BOGUEY", "BOILED", "BOILER", "BOLDER", "BOLDLY", "BONDED", "BONIER", "BONITO",
+    "BONNEY", "BONOBO", "BONZER", "BOOKED", "BOOTED", "BOOTHS", "BOOZED", "BORDER", "BOREAL",
+    "BORING", "BORROW", "BOSKER", "BOSOMS", "BOSOMY", "BOSSES", "BOTCHY", "BOTFLY", "BOTHER",
+    "BOUGHS", "BOUNCE", "BOUNCY", "BOUNDE", "BOUNDS", "BOWELS", "BOWERS", "BOWERY", "BOWFIN",
+    "BOWING", "BOWLED", "BOXILY", "BOXING", "BOYISH", "BRACED", "BRACES", "BRAIDS", "BRAINY",
+    "BRAKES", "BRANKY", "BRANNY", "BRASHY", "BRATTY", "BRAVED", "BRAVER", "BRAWLY", "BRAWNY",
+    "BRAZEN", "BREADS", "BREAKS", "BREEDS", "BREEZE", "BREEZY", "BRIARY", "BRIBED", "BRIBES",
+    "BRICKS", "BRICKY", "BRIDAL", "BRIEFS", "BRIERS", "BRIERY", "BRINGS", "BROKER", "BROLLY",
+    "BROMIC", "BRONZE", "BRONZY", "BROOMS", "BROOMY", "BROTHY", "BROWNY", "BRUMAL", "BRUSHY",
+    "BRUTAL", "BRUTES", "BUBBLE", "BUBBLY", "BUBOED", "BUCCAL", "BUCKED", "BUCKET", "BUFFER",
+    "BUGGED", "BUILDS", "BULBAR", "BULBUL", "BULGED", "BULLET", "BUNCHY", "BUNDLE", "BUNKER",
+    "BUNTED", "BURBLY", "BURBOT", "BURGER", "BURNED", "BURSAL", "BURSTS", "BUSHED", "BUSHES",
+    "BUSIED", "BUSIER", "BUSIES", "BUSILY", "BUSKED", "BUSKIN", "BUSTLE", "BUTLER", "BUTTER",
+    "BUYERS", "BUZZED", "BYSSAL", "BYWAYS", "CABALS", "CABINS", "CABLES", "CACTUS", "CADENT",
+    "CADETS", "CADMIC", "CAECAL", "CAGIER", "CAGILY", "CAIMAN", "CAIRNY", "CALCED", "CALCIC",
+    "CALICO", "CALLED", "CALLOW", "CALMED", "CALMER", "CALMLY", "CALVES", "CALVUS", "CAMELS",
+    "CAMPOS", "CANALS", "CANCEL", "CANDID", "CANNED", "CANNON", "CANOES", "CANONS", "CANTIC",
+    "CANYON", "CAPERS", "CAPPED", "CARBOY", "CARESS", "CARING", "CARNAL", "CARROT", "CARVED",
+    "CASHED", "CASINO", "CATCHY", "CATTLE", "CAUDAL", "CAUSAL", "CAUSED", "CAUSES", "CAVATE",
+    "CAVIES", "CEASED", "CEASES", "CEDARN", "CEDARS", "CELERY", "CELIAC", "CELLAR", "CEMENT",
+    "CENSUS", "CENTUM", "CERCAL", "CEREAL", "CEROUS", "CHAFED", "CHAFES", "CHAFFY", "CHAINS",
+    "CHAIRS", "CHALKY", "CHALLY", "CHAMPY", "CHANCY", "CHANTS", "CHARMS", "CHARRY", "CHARTS",
+    "CHASMS", "CHASMY", "CHASTE", "CHATTY", "CHEATS", "CHECKS", "CHECKY", "CHEEKS", "CHEEKY",
+    "CHEERS", "CHEERY", "CHEESE", "CHEESY", "CHEMIC", "CHEQUY", "CHERRY", "CHERTY", "CHESTS",
+    "CHESTY", "CHICLY", "CHIEFS", "CHILLS", "CHILLY", "CHIMES", "CHINKS", "CHINTZ", "CHIRPS",
+    "CHIRPY", "CHOKED", "CHOKES", "CHOOSY", "CHOPPY", "CHORDS", "CHORIC", "CHUBBY", "CHUFFY",
+    "CHUMMY", "CHUNKS", "CHUNKY", "CHURLS", "CHYMIC", "CICADA", "CIGARS", "CINDER", "CISELA",
+    "CISTED", "CISTIC", "CITIED", "CITIES", "CITRIC", "CLAIMS", "CLAMMY", "CLANKS", "CLASPS",
+    "CLASSY", "CLAVAL", "CLAYEY", "CLEANS", "CLECHA", "CLEFTS", "CLERKS", "CLEVER", "CLIFFY",
+    "CLIMES", "CLINAL", "CLINGS", "CLINGY", "CLINIC", "CLIQUY", "CLOAKS", "CLOCKS", "CLODDY",
+    "CLOGGY", "CLONIC", "CLOQUE", "CLOSES", "CLOTHE", "CLOTHS", "CLOTTY", "CLOUDS", "CLOUDY",
+    "CLOVER", "CLOVES", "CLUBBY", "CLUCKY", "CLUMPS", "CLUMPY", "CLUMSY", "CLUTCH", "CNEMIC",
+    "COARSE", "COASTS", "COATED", "COBWEB", "COCCAL", "COCCIC", "COEVAL", "COGENT", "COILED",
+    "COINER", "COITAL", "COLDER", "COLDLY", "COLLIE", "COLONY", "COLORY", "COLOUR", "COMATE",
+    "COMBED", "COMELY", "COMERS", "COMING", "COMOSE", "COMPLY", "CONDOR", "CONGER", "CONVEX",
+    "COOING", "COOKED", "COOLED", "COOLER", "COOLLY", "COPIED", "COPIES", "COPING", "CORALS",
+    "CORDED", "CORDIS", "CORKED", "CORNED", "CORPSE", "COSEYS", "COSIER", "COSIES", "COSILY",
+    "COSMIC", "COSTAL", "COSTLY", "COUCHA", "COUGAR", "COUGHS", "COUNTS", "COUPED", "COURTS",
+    "COVERS", "COVERT", "COVEYS", "COWARD", "COWERS", "COWLED", "COYISH", "COYOTE", "COYPUS",
+    "COZEYS", "COZIER", "COZIES", "COZILY", "CRABBE", "CRABBY", "CRACKS", "CRADLE", "CRAFTS",
+    "CRAFTY", "CRAGGY", "CRANES", "CRANKS", "CRANKY", "CRATER", "CRAVED", "CRAVEN", "CRAWLS",
+    "CRAWLY", "CRAYON", "CRAZED", "CREAKY", "CREAMY", "CREASY", "CREEKS", "CREEPS", "CREEPY",
+    "CRESSY", "CRESTS", "CRESYL", "CRIBLA", "CRIERS", "CRIMES", "CRIMPY", "CRINAL", "CRINED",
+    "CRISIC", "CRISPY", "CRITIC", "CROAKS", "CROAKY", "CROSSE", "CROUCH", "CROUPY", "CROUSE",
+    "CROWDS", "CROWED", "CROWNS", "CRUDER", "CRUISE", "CRUMBS", "CRUMBY", "CRUMPY", "CRUNCH",
+    "CRURAL", "CRUSTS", "CRUSTY", "CRYING", "CUCKOO", "CUDDLY", "CULTIC", "CUNEAL", "CUPIDS",
+    "CUPPED", "CUPRIC", "CURIAL", "CURING", "CURLED", "CURLEW", "CURSED", "CURSES", "CURTLY",
+    "CURULE", "CURVED", "CURVES", "CUSPAL", "CUSPED", "CUSSED", "CUTCHA", "CUTELY", "CUTEST",
+    "CUTTER", "CYANIC", "CYCLES", "CYCLIC", "CYETIC", "CYMOID", "CYMOSE", "CYSTIC", "CYTOID",
+    "DADASS", "DAEDAL", "DAFTLY", "DAGAGA", "DAIMEN", "DAINTY", "DAMMED", "DAMODA", "DAMPED",
+    "DAMPER", "DAMPLY", "DANCED", "DANCER", "DANCES", "DANGED", "DANGLY", "DANKLY", "DAPPER",
+    "DARING", "DARKER", "DARKLY", "DARTER", "DASHED", "DASHES", "DASSIE", "DATING", "DAUBED",
+    "DAYFLY", "DAZZLE", "DEADLY", "DEAFEN", "DEAFLY", "DEARER", "DEARLY", "DEATHS", "DEBRIS",
+    "DECENT", "DECKED", "DEEPER", "DEEPLY", "DEFERS", "DEFIED", "DEFIES", "DEFTLY", "DEIFIC",
+    "DELAYS", "DELUXE", "DEMISE", "DEMONS", "DEMURE", "DENARY", "DENIAL", "DENIED", "DENIES",
+    "DENSER", "DENSUS", "DEPART", "DEPTHS", "DERIVE", "DERMAL", "DERMIC", "DESTRO", "DETACH",
+    "DETECT", "DEUCED", "DEVILS", "DEVISE", "DEVOID", "DEVOTE", "DEVOUT", "DEWILY", "DEXTRO",
+    "DHOOLY", "DIARCH", "DIDDLY", "DIESEL", "DIMMED", "DIMPLY", "DINGEY", "DINKLY", "DINKUM",
+    "DIOTIC", "DIPLEX", "DIPPER", "DIRELY", "DIREST", "DISARM", "DISHED", "DISHES", "DISMAL",
+    "DISTAL", "DIVERS", "DIVERT", "DIVEST", "DIVINE", "DIVING", "DIVISI", "DOABLE", "DOCILE",
+    "DODGED", "DODGES", "DOGGED", "DOILED", "DOINGS", "DOITED", "DONATE", "DONKEY", "DONSIE",
+    "DOOCED", "DOPIER", "DOPILY", "DORMIE", "DORSAD", "DORSAL", "DOTARD", "DOTIER", "DOTING",
+    "DOTTED", "DOUBLY", "DOUBTS", "DOUGHY", "DOURLY", "DOVISH", "DOWILY", "DOZENS", "DOZIER",
+    "DOZILY", "DRABLY", "DRAFFY", "DRAFTS", "DRAFTY", "DRAGGY", "DRAINS", "DRAWED", "DRAWER",
+    "DRAWLS", "DRAWLY", "DREAMS", "DREAMY", "DREARY", "DREGGY", "DREICH", "DRESSY", "DRIEST",
+    "DRIFTE", "DRIFTS", "DRIFTY", "DRINKS", "DRIPPY", "DRIVEN", "DRIVES", "DROLLY", "DRONES",
+    "DRONGO", "DROOLY", "DROOPS", "DROOPY", "DROPPE", "DROPSY", "DROSSY", "DROVER", "DROVES",
+    "DROWSY", "DRUMLY", "DRYING", "DUALLY", "DUCKIE", "DUDISH", "DUGONG", "DULCET", "DULLER",
+    "DUMBLY", "DUNLIN", "DUSTED", "DUSTER", "DUTIES", "DWELLS", "DYABLE", "EAGLES", "EARNED",
+    "EARTHS", "EARTHY", "EARWIG", "EASIER", "EATERS", "ECESIC", "ECHOIC", "EDGIER", "EDGILY",
+    "EDGING", "EELIER", "EERIER", "EERILY", "EFFETE", "EFFETS", "EGGNOG", "EGRETS", "EIGHTY",
+    "ELATED", "ELATER", "ELBOWS", "ELDERS", "ELMIER", "ELOPED", "ELUDED", "ELVISH", "EMBARK",
+    "EMBODY", "EMBOLY", "ENDEAR", "ENDERS", "ENDURE", "ENGAGA", "ENJOYS", "ENLIST", "ENOLIC",
+    "ENRAPT", "ENRICH", "ENROLL", "ENSUED", "ENSUES", "ENTERS", "EONIAN", "EPOCHS", "EQUALS",
+    "ERASED", "ERECTS", "ERMINE", "ERRANT", "ERRING", "ERRORS", "ESSAYS", "ESTEEM", "ETERNE",
+    "ETHICS", "EVADED", "EVENLY", "EVENTS", "EVILLY", "EVOKED", "EVOKES", "EXCESS", "EXCITE",
+    "EXERTS", "EXILED", "EXILES", "EXILIC", "EXISTE", "EXISTS", "EXOTIC", "EXPIRE", "EXPOSE",
+    "EXTANT", "EXTRAS", "EXUDED", "EXULTS", "EYEING", "FABLED", "FABLES", "FACETE", "FACIAL",
+    "FACILE", "FADING", "FAECAL", "FAILED", "FAINTS", "FAIRER", "FALCON", "FALSER", "FANGED",
+    "FANNED", "FARAND", "FARMED", "FASTER", "FATTER", "FAUCAL", "FAUCET", "FAULTS", "FAULTY",
+    "FAUNAL", "FAVOUR", "FAWNED", "FEARED", "FEASTS", "FEATLY", "FECKLY", "FECUND", "FEEBLE",
+    "FEEBLY", "FEEDER", "FEIGNS", "FEINTS", "FEIRIE", "FEISTY", "FELINE", "FELLED", "FELLER",
+    "FELONS", "FELONY", "FELSIC", "FENCED", "FENCES", "FENDER", "FENNEC", "FEODAL", "FERIAL",
+    "FERINE", "FERRET", "FERRIC", "FERVID", "FESTAL", "FEUDAL", "FEVERS", "FEWEST", "FEZZED",
+    "FICKLE", "FICKLY", "FIDDLE", "FIDDLY", "FIDGET", "FIELDS", "FIERCE", "FIFTHS", "FIGHTS",
+    "FILATE", "FILIAL", "FILING", "FILLED", "FILOSE", "FILTHY", "FILTRE", "FINELY", "FINERY",
+    "FINEST", "FINITE", "FINNED", "FIRING", "FIRMER", "FIRMLY", "FISHED", "FISHES", "FISTIC",
+    "FITCHY", "FITFUL", "FITTED", "FITTEN", "FITTER", "FIXING", "FIXITY", "FLABBY", "FLAGGY",
+    "FLAKES", "FLAMBA", "FLAMES", "FLANKS", "FLAPPY", "FLARES", "FLASHY", "FLATLY", "FLAVOR",
+    "FLAWED", "FLAXEN", "FLECKY", "FLEDGY", "FLEECY", "FLESHY", "FLEURY", "FLEXED", "FLIMSY",
+    "FLINGS", "FLINTY", "FLIRTS", "FLOATS", "FLOATY", "FLOCKS", "FLOCKY", "FLOODS", "FLOORS",
+    "FLORAL", "FLORID", "FLOSSY", "FLOURY", "FLOWED", "FLUENT", "FLUFFY", "FLUIDS", "FLUKEY",
+    "FLURRY", "FLUTED", "FLUTES", "FLUTEY", "FLYING", "FODGEL", "FOETAL", "FOETID", "FOGEYS",
+    "FOGGED", "FOGIES", "FOILED", "FOLDED", "FOLIAR", "FOLKSY", "FONDER", "FONDLY", "FONTAL",
+    "FOOTED", "FOOTER", "FORCED", "FORCES", "FOREGO", "FORGED", "FORKED", "FORMAE", "FORMED",
+    "FORMIC", "FORRES", "FOSSIL", "FOULED", "FOULLY", "FOUNDS", "FOVEAL", "FOWLER", "FOXIER",
+    "FOXILY", "FOZIER", "FRAMED", "FRAMES", "FREAKS", "FREAKY", "FREELY", "FRETTY", "FRIGID",
+    "FRILLS", "FRILLY", "FRINGE", "FRISKY", "FRITHS", "FRIZZY", "FROCKS", "FROGGY", "FROLIC",
+    "FRONDS", "FRONTE", "FRONTS", "FROSTS", "FROSTY", "FROTHY", "FROUSY", "FROUZY", "FROWNS",
+    "FROWSY", "FROWZY", "FRUGAL", "FRUITS", "FRUITY", "FRUMPY", "FSIEST", "FULGID", "FULLER",
+    "FULMAR", "FUMIER", "FUMING", "FUNDIC", "FUNEST", "FUNGAL", "FUNGIC", "FUNKED", "FURIES",
+    "FURRED", "FUSILE", "FUTILE", "GABLED", "GABLES", "GADFLY", "GADGET", "GAINED", "GAINER",
+    "GAINLY", "GAITED", "GALLEY", "GALLIC", "GALLOP", "GALORE", "GAMELY", "GAMIER", "GAMILY",
+    "GAMING", "GANGLY", "GANNET", "GAPING", "GARISH", "GARLIC", "GASLIT", "GASPED", "GAUCHE",
+    "GAZING", "GENIAL", "GENTIL", "GENTLY", "GENUAL", "GEODIC", "GERBIL", "GESTIC", "GHOSTS",
+    "GIANTS", "GIBBED", "GIBBON", "GIBING", "GIFTED", "GIGGLE", "GIGGLY", "GIMLET", "GIRTHS",
+    "GLADES", "GLADLY", "GLAIRY", "GLARED", "GLARES", "GLASSY", "GLAZED", "GLEAMS", "GLEBAL",
+    "GLEETY", "GLEGLY", "GLIBLY", "GLIDED", "GLIDER", "GLIDES", "GLISKY", "GLOBES", "GLOOMY",
+    "GLOSSY", "GLOVED", "GLOVES", "GLOWED", "GLUIER", "GLUILY", "GLUMLY", "GLUMPY", "GNARLY",
+    "GNATTY", "GNOMIC", "GOBLIN", "GOBONY", "GODWIT", "GOGGLY", "GOINGS", "GONIAC", "GONIAL",
+    "GOODLY", "GOOGLY", "GOOIER", "GOPHER", "GORGED", "GORGES", "GORIER", "GORILY", "GOSPEL",
+    "GOSSIP", "GOUTTA", "GOWANY", "GOYISH", "GRACED", "GRADES", "GRAFTS", "GRAINS", "GRAINY",
+    "GRAPES", "GRASPS", "GRASSY", "GRATED", "GRATER", "GRATES", "GRATIS", "GRAVEL", "GRAVER",
+    "GRAVES", "GRAVID", "GRAYLY", "GRAZED", "GRAZES", "GREASY", "GREATL", "GREEDY", "GREETS",
+    "GREYLY", "GRIEFS", "GRIEVE", "GRILLA", "GRIMED", "GRIMLY", "GRIPEY", "GRIPPY", "GRISLY",
+    "GRISON", "GRITTY", "GROANS", "GROCER", "GROGGY", "GROOMS", "GROOVY", "GROPED", "GROPES",
+    "GROTTY", "GROUND", "GROUPS", "GROUSE", "GROUTY", "GROVED", "GROVES", "GROWLS", "GROWLY",
+    "GRUBBY", "GRUFFY", "GRUMLY", "GRUMPY", "GRUNTS", "GUARDS", "GUESTS", "GUIDED", "GUIDES",
+    "GUINEA", "GULLED", "GUNNED", "GUNNEL", "GYROSE", "HABILE", "HABITS", "HACKLY", "HAEMAL",
+    "HAEMIC", "HAIRED", "HALEST", "HALLOO", "HALOED", "HALVES", "HAMMER", "HANDED", "HAPTIC",
+    "HARDEN", "HARDER", "HATING", "HATTER", "HAUNTS", "HAVING", "HAZARD", "HAZIER", "HAZILY",
+    "HEADED", "HEARER", "HEARTS", "HEARTY", "HEATED", "HEATER", "HEATHY", "HEAVED", "HEAVER",
+    "HEAVES", "HECTIC", "HEDGES", "HEELED", "HELMED", "HELMET", "HELPED", "HELPER", "HEMOID",
+    "HEMPEN", "HERALD", "HERESY", "HERMIT", "HEROES", "HEROIC", "HERONS", "HETERO", "HEWERS",
+    "HIEMAL", "HIGHER", "HINGES", "HINTED", "HIPPED", "HIPPER", "HIRING", "HISPID", "HISTIE",
+    "HITCHY", "HITHER", "HOARDS", "HOARSE", "HOGGED", "HOKILY", "HOLIER", "HOLIES", "HOLILY",
+    "HOLLOW", "HOLMIC", "HOMELY", "HOMIER", "HOMILY", "HONIED", "HOODED", "HOOFED", "HOOKED",
+    "HOOKER", "HOOPOE", "HOPING", "HORARY", "HORMIC", "HORNED", "HORNER", "HORNET", "HORRID",
+    "HORSES", "HORSEY", "HOSTLY", "HOTELS", "HOTTER", "HOUNDS", "HOUNDY", "HOURLY", "HOUSED",
+    "HOUSES", "HOVELS", "HOVERS", "HUBBLY", "HUGELY", "HUGEST", "HUMANE", "HUMBLE", "HUMBLY",
+    "HUMMEL", "HUNGRY", "HUNTED", "HURDLE", "HURTLY", "HUSHED", "HYBRID", "HYDRIC", "HYENIC",
+    "HYETAL", "HYPHAL", "IATRIC", "ICICLE", "ICIEST", "ICKIER", "ICKILY", "ICONIC", "IDEALS",
+    "IDIOTS", "IDLERS", "IDLEST", "IDLING", "IGUANA", "IMAGES", "IMBUED", "IMIDIC", "IMMANE",
+    "IMMUNE", "IMPALA", "IMPEDE", "IMPELS", "IMPEND", "IMPISH", "IMPOSE", "IMPURE", "INBOND",
+    "INBORN", "INBRED", "INCHES", "INCULT", "INDIES", "INDIGN", "INDOOR", "INFELT", "INFIRM",
+    "INHALE", "INJECT", "INJURE", "INKIER", "INLAID", "INLETS", "INMATE", "INMOST", "INNATE",
+    "INSANE", "INSTEA", "INSULT", "INTACT", "INTENT", "INTIME", "INTOED", "INTOWN", "INURED",
+    "INVITE", "INWARD", "IODOUS", "IRENIC", "IRIDIC", "IRITIC", "IRONED", "IRONIC", "ISLETS",
+    "ISSUED", "ISSUES", "JABIRU", "JACKAL", "JADISH", "JAEGER", "JAGGED", "JAGUAR", "JAILER",
+    "JAMMED", "JANGLY", "JAUNTY", "JEJUNE", "JENNET", "JERBOA", "JIGGLY", "JIMPLY", "JINGLY",
+    "JOBBED", "JOCOSE", "JOCUND", "JOINED", "JOINTS", "JOKERS", "JOKILY", "JOKING", "JOVIAL",
+    "JOWLED", "JOYFUL", "JOYOUS", "JUBATE", "JUGATE", "JUICES", "JUMBLE", "JUMPED", "JUNGLY",
+    "JURIES", "JURORS", "JUSTER", "JUSTLY", "KAKAPO", "KALONG", "KARMIC", "KEENER", "KEENLY",
+    "KEEPER", "KELPIE", "KERNEL", "KETTLE", "KICKED", "KILTED", "KINDER", "KINDLY", "KINGLY",
+    "KINKLY", "KISSED", "KISSES", "KITTEN", "KLUTZY", "KNACKS", "KNAGGY", "KNARRY", "KNAVES",
+    "KNEELS", "KNIGHT", "KNIVES", "KNOBBY", "KNOCKS", "KNOLLY", "KNOTTY", "KNOWED", "KNURLY",
+    "KODIAK", "KUTCHA", "LABILE", "LABOUR", "LACIER", "LACILY", "LACING", "LACKED", "LACTIC",
+    "LAKIER", "LAMELY", "LAMENT", "LAMEST", "LAMING", "LANATE", "LANDED", "LANELY", "LANGUR",
+    "LANKLY", "LANOSE", "LAPSED", "LAPSES", "LAPTOP", "LARGER", "LARINE", "LARVAL", "LASHED",
+    "LASTED", "LASTLY", "LATENT", "LATEST", "LATISH", "LAUGHS", "LAURIC", "LAVISH", "LAWFUL",
+    "LAYERS", "LAZIER", "LAZILY", "LEACHY", "LEADEN", "LEAFED", "LEALLY", "LEANLY", "LEARNS",
+    "LEASES", "LEAVED", "LEAVES", "LEDGER", "LEDGES", "LEGGED", "LEKKER", "LEMONS", "LEMONY",
+    "LENDER", "LENTEN", "LENTIC", "LESSER", "LETHAL", "LEVEES", "LEVELS", "LEVERS", "LEWDLY",
+    "LIABLE", "LIDDED", "LIEFLY", "LIENAL", "LIFTED", "LIGHTS", "LIKING", "LILACS", "LILIED",
+    "LIMBED", "LIMBER", "LIMBIC", "LIMIER", "LIMITS", "LIMPET", "LIMPID", "LIMPLY", "LIMPSY",
+    "LINEAL", "LINEAR", "LINENY", "LINGER", "LINIER", "LINING", "LINKED", "LINNET", "LIONLY",
+    "LIQUOR", "LISSOM", "LISTED", "LITHER", "LITHIC", "LITTEN", "LITTER", "LIVELY", "LIVERS",
+    "LIVERY", "LIVEST", "LIZARD", "LLANOS", "LOADED", "LOATHE", "LOAVES", "LOBATE", "LOBOSE",
+    "LOCKED", "LOCKET", "LOCUST", "LODGED", "LODGER", "LODGES", "LOGIER", "LOGILY", "LONGED",
+    "LONGER", "LONGLY", "LOOKED", "LOOKER", "LOOPED", "LOOSED", "LOOSER", "LORDLY", "LOSERS",
+    "LOSING", "LOSSES", "LOSTLY", "LOUCHE", "LOUDER", "LOUDLY", "LOUNGE", "LOUNGY", "LOUVAR",
+    "LOVERS", "LOVING", "LOWERS", "LOWERY", "LOWEST", "LOWISH", "LOWSER", "LUBRIC", "LUCENT",
+    "LUETIC", "LUMBAR", "LUMBER", "LUMPEN", "LUNIER", "LUNIES", "LUPOUS", "LURING", "LUSHLY",
+    "LUSTRE", "LUTEAL", "LUTOSE", "LUXURY", "LYRATE", "LYRICS", "MACLED", "MADDER", "MAGGOT",
+    "MAGNET", "MAGPIE", "MAIDEN", "MAILED", "MAKERS", "MAKING", "MAMMAL", "MANFUL", "MANGER",
+    "MANGEY", "MANNED", "MANQUA", "MANTIC", "MANTIS", "MAPPED", "MARBLY", "MARGAY", "MARISH",
+    "MARKED", "MARLED", "MARMOT", "MARSHY", "MARTEN", "MARTYR", "MASKED", "MASSES", "MATINS",
+    "MATRIX", "MATRON", "MATTED", "MATURE", "MAUNGY", "MAWGER", "MAYFLY", "MAZIER", "MAZILY",
+    "MEAGER", "MEAGRE", "MEANLY", "MEASLY", "MEATAL", "MEDFLY", "MEDLEY", "MEEKLY", "MEETLY",
+    "MELLOW", "MELONS", "MELTED", "MENSAL", "MEREST", "MERGED", "MERITS", "MESHES", "MESIAL",
+    "MESSES", "METALS", "METTLE", "MIFFED", "MIGHTY", "MILDER", "MILDLY", "MILKED", "MILLED",
+    "MIMICS", "MINCED", "MINDED", "MINERS", "MINING", "MINNOW", "MIRIER", "MISERS", "MISERY",
+    "MISTER", "MITRAL", "MITTEN", "MIXING", "MIZZLY", "MNEMIC", "MODELS", "MODIFY", "MODISH",
+    "MOIEST", "MOILER", "MOLEST", "MOLINE", "MOLTEN", "MONEYS", "MONIED", "MONTHS", "MOONED",
+    "MOPIER", "MOPING", "MORALS", "MORASS", "MORBID", "MORISH", "MORNAY", "MOROSE", "MORTAL",
+    "MORTAR", "MOTMOT", "MOUILL", "MOULDS", "MOULDY", "MOUNDS", "MOUNTS", "MOURNS", "MOUSEY",
+    "MOUTHS", "MOUTHY", "MOVING", "MUCHLY", "MUCOID", "MUCOUS", "MUDDLE", "MUDDLY", "MUDFAT",
+    "MUFFIN", "MULISH", "MULLET", "MULLEY", "MUMBLY", "MURKLY", "MURREY", "MUSCLE", "MUSCLY",
+    "MUSING", "MUSKOX", "MUSTER", "MUTELY", "MUTTER", "MUTTON", "MYOPIC", "MYSTIC", "MYXOID",
+    "NACRED", "NAIANT", "NAILED", "NAMELY", "NANOID", "NAPKIN", "NAPLES", "NARIAL", "NASIAL",
+    "NATANT", "NATIVE", "NAVIES", "NEAPED", "NEARED", "NEARER", "NEATLY", "NEBULE", "NEBULY",
+    "NECKED", "NEEDED", "NERVAL", "NERVES", "NETHER", "NETTED", "NETTLY", "NEUMIC", "NEURAL",
+    "NEVOID", "NEWEST", "NEWISH", "NICELY", "NICEST", "NICHER", "NICHES", "NIECES", "NIGGLY",
+    "NIGHTS", "NILGAI", "NIMBLE", "NIMBLY", "NIOBIC", "NITRIC", "NOBLER", "NOCENT", "NOCTIS",
+    "NODOSE", "NODOUS", "NOETIC", "NOISED", "NOISES", "NOMADS", "NONFAT", "NONRUN", "NONTAN",
+    "NOODLE", "NOOSES", "NOSIER", "NOSILY", "NOTCHY", "NOTHUS", "NOTING", "NOTOUR", "NOVELS",
+    "NOWAYS", "NUBBLY", "NUBILE", "NUDGED", "NUDGES", "NUMBAT", "NUMBLY", "NURSED", "NURSES",
+    "NUTANT", "NUTRIA", "NYMPHS", "OAFISH", "OBEYED", "OBLIGE", "OBLONG", "OBTECT", "OBTUSE",
+    "OCCURS", "OCEANS", "OCELOT", "OCHERY", "OCULAR", "ODDEST", "ODIOUS", "ODOURS", "ODYLIC",
+    "OFFERS", "OFFING", "OFFISH", "OILIER", "OILILY", "OLDEST", "OLEOYL", "ONAGER", "ONIONS",
+    "ONIONY", "ONWARD", "OOZIER", "OOZILY", "OPACUS", "OPAQUE", "OPENED", "OPENER", "OPENLY",
+    "OPERAS", "OPINED", "ORALLY", "ORANGY", "ORDERS", "ORGANS", "ORIELS", "ORIENT", "ORIOLE",
+    "ORNATE", "ORNERY", "ORPHAN", "OSMOUS", "OSPREY", "OSTEAL", "OTHERS", "OTIOSE", "OTTERS",
+    "OUGHTS", "OUNCES", "OUTFLY", "OVALLY", "OVERLY", "OVULAR", "OVULES", "OWLISH", "OWNERS",
+    "OWNING", "OXALIC", "OXIDES", "OXIDIC", "OXLIKE", "OYSTER", "OZONIC", "PACING", "PACKED",
+    "PACKER", "PACKLY", "PADDLE", "PADRES", "PAINED", "PAINTS", "PAIRED", "PALELY", "PALEST",
+    "PALISH", "PALLAS", "PALLID", "PALMAR", "PALMES", "PALTRY", "PAMPAS", "PANELS", "PANICS",
+    "PAPERS", "PAPERY", "PAPIST", "PARADE", "PARCEL", "PAREVE", "PARLEY", "PARROT", "PARTED",
+    "PARTIS", "PASSED", "PASSEE", "PASSER", "PASSES", "PASTED", "PATCHY", "PATRON", "PATTAE",
+    "PATTED", "PATTER", "PAUNCH", "PAUSAL", "PAUSED", "PAUSES", "PAVING", "PEACHY", "PEAKED",
+    "PEANUT", "PEBBLY", "PECKER", "PECTIC", "PEDATE", "PEEPED", "PELVIC", "PENCIL", "PENILE",
+    "PENNED", "PEOPLE", "PEPSIS", "PEPTIC", "PERILS", "PERISH", "PEROXY", "PERTLY", "PESTER",
+    "PETALS", "PETITE", "PETREL", "PETTED", "PHASES", "PHASIC", "PHATIC", "PHOBIC", "PHONAL",
+    "PHONIC", "PHOTIC", "PHYLAR", "PHYLIC", "PIANIC", "PIANOS", "PICKED", "PICKLE", "PICNIC",
+    "PICRIC", "PIDDLY", "PIECED", "PIECES", "PIGEON", "PIGLET", "PILING", "PILLAR", "PILOSE",
+    "PILOTS", "PIMPLY", "PINEAL", "PINIER", "PINING", "PINKLY", "PINNAL", "PINNED", "PIPIER",
+    "PIPING", "PIQUED", "PISSED", "PISTOL", "PITCHY", "PIZZAS", "PLACED", "PLACES", "PLACID",
+    "PLAGAL", "PLAICE", "PLAINS", "PLAITS", "PLANAR", "PLANED", "PLANES", "PLANKS", "PLANTS",
+    "PLANTY", "PLASHY", "PLATED", "PLATES", "PLAYED", "PLEADS", "PLEBBY", "PLEDGE", "PLIANT",
+    "PLICAL", "PLOUGH", "PLOVER", "PLUCKS", "PLUCKY", "PLUMED", "PLUMMY", "PLUNGE", "PLURAL",
+    "PLYING", "POACHY", "POCKED", "POETIC", "POINTS", "POISED", "POISON", "POKERS", "POKIER",
+    "POKIES", "POKILY", "POKING", "POLISH", "POLITE", "POLLED", "POMMAE", "PONCHO", "PONDER",
+    "PONIES", "POODLE", "POORER", "POORLY", "POPISH", "POROUS", "PORTAL", "PORTLY", "POSHLY",
+    "POSING", "POSSUM", "POSTAL", "POSTED", "POSTER", "POTENT", "POTTED", "POTTER", "POUNDS",
+    "POURED", "POWERS", "PRAISE", "PRANKS", "PRATTY", "PRAWNS", "PRAYED", "PREACH", "PREWAR",
+    "PRICEY", "PRICKS", "PRIMAL", "PRIMED", "PRIMER", "PRIMLY", "PRINTS", "PRIORY", "PRISSY",
+    "PRIZED", "PRIZES", "PROART", "PROLIX", "PROMPT", "PROOFS", "PROSSY", "PROTAX", "PROVED",
+    "PROVES", "PROWAR", "PROWED", "PSALMS", "PTOTIC", "PUDDLY", "PUFFER", "PUFFIN", "PUISNE",
+    "PULING", "PULLED", "PULSES", "PUMPED", "PUNCHY", "PUNIER", "PUNILY", "PUPILS", "PURELY",
+    "PUREST", "PURIFY", "PURITY", "PURPLY", "PURSED", "PURSER", "PURSES", "PUSHED", "PUSHES",
+    "PUSSLY", "PUTRID", "PYEMIC", "PYKNIC", "PYTHON", "QUAGGA", "QUAGGY", "QUAHOG", "QUAILS",
+    "QUAINT", "QUAKED", "QUAKES", "QUARTZ", "QUAVER", "QUEASY", "QUIETS", "QUILLS", "QUINCE",
+    "QUINTS", "QUIRKY", "QUIVER", "QUOTED", "QUOTES", "RACERS", "RACIAL", "RACIER", "RACILY",
+    "RACKED", "RACKET", "RACKLE", "RAGGED", "RAGING", "RAILED", "RAINED", "RAISED", "RAISES",
+    "RAKING", "RAKISH", "RAMMER", "RAMOSE", "RANCID", "RANGED", "RANGES", "RANKED", "RANKLY",
+    "RAPTLY", "RAPTOR", "RAREST", "RARING", "RASCAL", "RASHLY", "RATTLY", "RAVINE", "RAVING",
+    "RAVINS", "RAWEST", "RAWISH", "RAZORS", "READIL", "REALIT", "REALMS", "REBELS", "REBORN",
+    "RECESS", "RECIPE", "RECITE", "RECTAL", "RECURS", "REDDER", "REDEEM", "REFERS", "REFINE",
+    "REFUSE", "REGALE", "REGARD", "REGNAL", "REGRET", "REIGNS", "REJECT", "RELAXE", "RELAYS",
+    "RELICS", "RELIED", "RELIES", "RELISH", "REMAND", "REMIND", "REMISS", "REMORA", "RENEWS",
+    "RENOWN", "REOPEN", "REPAND", "REPAYS", "REPINE", "REPOSE", "RESIDE", "RESIGN", "RESTED",
+    "RETARD", "RETIAL", "RETRAL", "RETUSE", "REVELS", "REVIVE", "REVOLT", "RHEUMY", "RHINAL",
+    "RHODIC", "RHOTIC", "RHYMES", "RIBBED", "RICHLY", "RIDDLE", "RIDENT", "RIDERS", "RIDGES",
+    "RIFELY", "RIFLES", "RIGHTS", "RIMOSE", "RINGED", "RINGER", "RIPELY", "RIPENS", "RIPPLE",
+    "RIPPLY", "RISERS", "RISING", "RISKED", "RITUAL", "RIVALS", "ROBUST", "ROCKED", "RODENT",
+    "ROGUES", "ROLLED", "ROLLER", "ROOFED", "ROOKIE", "ROOMED", "ROOMER", "ROOTED", "ROPIER",
+    "ROPILY", "ROSILY", "ROSINY", "ROTATE", "ROTTED", "ROTTEN", "ROTUND", "ROUGED", "ROUGHS",
+    "ROUGHY", "ROUNDE", "ROUNDS", "ROUPET", "ROUSED", "ROUTED", "ROUTES", "ROVING", "RUBBED",
+    "RUBBLY", "RUBIED", "RUBIES", "RUDELY", "RUDEST", "RUDISH", "RUEFUL", "RUFFED", "RUFFLE",
+    "RUFFLY", "RUFOUS", "RUGGED", "RUGOSE", "RULERS", "RULING", "RUMBLY", "RUMPLY", "RUNWAY",
+    "RUSHED", "RUSHES", "RUSSET", "RUSTIC", "SABRES", "SACKED", "SACRAL", "SACRED", "SADDLE",
+    "SAFELY", "SAFEST", "SAGELY", "SAILED", "SAILOR", "SAINTS", "SALLOW", "SALTED", "SALUTE",
+    "SALVER", "SAMBAR", "SANDED", "SANELY", "SANEST", "SATINS", "SATINY", "SAUCER", "SAUGER",
+    "SAUNCY", "SAVAGE", "SAVORY", "SAWFLY", "SAYING", "SCABBY", "SCALED", "SCALES", "SCALEY",
+    "SCAMPS", "SCANTY", "SCARCE", "SCARED", "SCARFS", "SCENES", "SCENIC", "SCENTS", "SCIENC",
+    "SCIONS", "SCOLDS", "SCOOPS", "SCORED", "SCORES", "SCORNS", "SCOTER", "SCOURS", "SCOUTS",
+    "SCOWLS", "SCRAPE", "SCRAPS", "SCRAWL", "SCREAM", "SCREWS", "SCREWY", "SCRUBS", "SCUNGY",
+    "SEALED", "SEALER", "SEAMAN", "SEAMED", "SEATED", "SECUND", "SEDATE", "SEDGED", "SEEKER",
+    "SEEMED", "SEEMLY", "SEIZED", "SEIZES", "SEJANT", "SELDOM", "SENARY", "SENDER", "SENILE",
+    "SENSES", "SEPTAL", "SERENE", "SEROUS", "SERVAL", "SERVED", "SERVES", "SETOSE", "SETTER",
+    "SEVENS", "SHABBY", "SHADED", "SHADES", "SHAFTS", "SHAGGY", "SHAKEN", "SHAKES", "SHAMED",
+    "SHANKS", "SHAPED", "SHAPES", "SHARED", "SHARER", "SHARES", "SHARKS", "SHAVED", "SHAVEN",
+    "SHAVER", "SHAVES", "SHAWLS", "SHEATH", "SHEETS", "SHELLS", "SHIELD", "SHIEST", "SHIFTS",
+    "SHIFTY", "SHINER", "SHINES", "SHIRTS", "SHIRTY", "SHIVER", "SHOALS", "SHOALY", "SHOCKS",
+    "SHOOTS", "SHORES", "SHORTS", "SHOUTS", "SHOVED", "SHOVEL", "SHOWED", "SHREDS", "SHREWD",
+    "SHRIEK", "SHRIKE", "SHRILL", "SHRIMP", "SHRUBS", "SHRUGS", "SHYEST", "SICKLY", "SIDLED",
+    "SIDLES", "SIGHTS", "SIGNED", "SILKEN", "SIMPLY", "SINEWS", "SINEWY", "SINFUL", "SINGED",
+    "SINGLY", "SIRUPY", "SISKIN", "SIZIER", "SKATER", "SKILLY", "SKIMPY", "SKINNY", "SKIRTS",
+    "SKULKS", "SKULLS", "SKUNKS", "SLAGGY", "SLAKED", "SLANGY", "SLAVED", "SLAVES", "SLEAZY",
+    "SLEEKY", "SLEEPS", "SLEEPY", "SLEETY", "SLICED", "SLICES", "SLIDER", "SLIDES", "SLIEST",
+    "SLIMLY", "SLIMSY", "SLINGS", "SLINKY", "SLIPPY", "SLOGAN", "SLOPED", "SLOPES", "SLOPPY",
+    "SLOSHY", "SLOTHS", "SLOUCH", "SLOVEN", "SLOWER", "SLOWLY", "SMACKS", "SMALLS", "SMARMY",
+    "SMARTS", "SMARTY", "SMEARS", "SMEARY", "SMEEKY", "SMELLS", "SMELLY", "SMILED", "SMILES",
+    "SMOGGY", "SMOKED", "SMOKER", "SMOKES", "SMUGLY", "SNAGGY", "SNAILS", "SNAKES", "SNAPPY",
+    "SNARES", "SNARLS", "SNARLY", "SNAZZY", "SNEAKS", "SNEAKY", "SNEERS", "SNEEZE", "SNIDER",
+    "SNIFFS", "SNIFFY", "SNIPPY", "SNODLY", "SNOOKS", "SNOOPY", "SNOOTY", "SNOOZY", "SNORES",
+    "SNORTS", "SNOTTY", "SNOWED", "SNUBBY", "SNUFFY", "SNUGLY", "SOAKED", "SOARED", "SODDEN",
+    "SOFTER", "SOFTLY", "SOIGNA", "SOILED", "SOLEMN", "SOLVED", "SOLVES", "SOMBER", "SOMBRE",
+    "SOMERS", "SOOTHE", "SORBIC", "SORDID", "SORELY", "SOREST", "SORTED", "SOTTED", "SOULED",
+    "SOUNDS", "SOURED", "SOURLY", "SOUSED", "SOWING", "SPACED", "SPACES", "SPARED", "SPARES",
+    "SPARKS", "SPARRY", "SPARSE", "SPASMS", "SPEAKS", "SPEARS", "SPECKS", "SPEEDY", "SPELLS",
+    "SPENDS", "SPHERE", "SPHERY", "SPIDER", "SPIFFY", "SPIKED", "SPIKES", "SPINAL", "SPINED",
+    "SPINES", "SPIRAL", "SPIRES", "SPLASH", "SPLEEN", "SPOILS", "SPONGE", "SPONGY", "SPOOKY",
+    "SPOONS", "SPORAL", "SPORTS", "SPORTY", "SPOTTY", "SPOUTS", "SPRIER", "SPRITE", "SPRUCE",
+    "SPRYER", "SPRYLY", "SPYING", "SQUABS", "SQUALL", "SQUEAK", "SQUEAL", "SQUINT", "SQUSHY",
+    "STABLY", "STACKS", "STAFFS", "STAGER", "STAGES", "STAGEY", "STAINS", "STAIRS", "STAKED",
+    "STAKES", "STALED", "STALKS", "STALKY", "STAMPS", "STANCH", "STANDS", "STANZA", "STARCH",
+    "STARED", "STARES", "STARRY", "STARTE", "STARTS", "STATED", "STATES", "STATUS", "STAVED",
+    "STAVES", "STAYED", "STEALS", "STEAMS", "STEAMY", "STEDDY", "STEEDS", "STEELY", "STELAR",
+    "STEPPE", "STEREO", "STERIC", "STEWED", "STICKS", "STICKY", "STILLY", "STILTS", "STINGS",
+    "STINGY", "STINKO", "STINKS", "STITCH", "STOCKS", "STOCKY", "STODGY", "STOLID", "STONED",
+    "STONEY", "STOOLS", "STOOPS", "STORED", "STORES", "STOREY", "STORKS", "STORMS", "STOVES",
+    "STRAIT", "STRAPS", "STRAWS", "STREAK", "STRIPS", "STRIPY", "STRIVE", "STRUNG", "STUDLY",
+    "STUFFS", "STUFFY", "STUMPS", "STUMPY", "STUNTY", "STUPID", "STURDY", "STURTY", "STYLAR",
+    "STYLES", "SUABLE", "SUABLY", "SUBDUE", "SUBPAR", "SUBTLE", "SUBTLY", "SUBWAY", "SUGARY",
+    "SUITED", "SUITES", "SULLEN", "SULTRY", "SUNLIT", "SUNSET", "SUPERB", "SUPPLE", "SUREST",
+    "SURFAC", "SURGED", "SVELTE", "SWAINS", "SWAMPS", "SWAMPY", "SWANKY", "SWARMS", "SWEARS",
+    "SWEATY", "SWEEPS", "SWEETS", "SWELLS", "SWILLS", "SWINGS", "SWIPES", "SWIRLY", "SWISHY",
+    "SWORDS", "SYLPHY", "SYRUPY", "TABLES", "TACKLE", "TAILED", "TAILOR", "TAINTS", "TAIPAN",
+    "TAKING", "TALKED", "TALKER", "TALLER", "TALLOW", "TAMELY", "TAMEST", "TAMING", "TANGLE",
+    "TANGLY", "TANKED", "TANNED", "TANNIC", "TAPERS", "TAPIRS", "TARPON", "TARRED", "TARTLY",
+    "TASTED", "TASTES", "TATTOO", "TAUNTS", "TAUTLY", "TAWDRY", "TEASED", "TEENSY", "TELIAL",
+    "TELLER", "TEMPER", "TEMPTS", "TENDED", "TENREC", "TENSED", "TENSER", "TENTED", "TENTHS",
+    "TENTIE", "TERBIC", "TERETE", "TERGAL", "TERMED", "TERMLY", "TESTED", "TETCHY", "THANKS",
+    "THATCH", "THECAL", "THEIRS", "THEMES", "THETIC", "THIGHS", "THINGS", "THINKS", "THINLY",
+    "THIRDS", "THIRST", "THONGS", "THORIC", "THORNS", "THORNY", "THRASH", "THRAWN", "THREES",
+    "THRICE", "THRIFT", "THRILL", "THRIVE", "THRONE", "THROWS", "THRUSH", "THUMBS", "THUMPS",
+    "THUSLY", "THWART", "THYMIC", "TIBIAL", "TICKED", "TICKLE", "TIDDLY", "TIDIED", "TIDILY",
+    "TIGERS", "TIGHTS", "TIGLIC", "TILTED", "TIMELY", "TINDER", "TINEAL", "TINGED", "TINGLY",
+    "TINIER", "TINILY", "TINKLY", "TINNED", "TINPOT", "TIPPED", "TIRING", "TITLED", "TITLES",
+    "TOGAED", "TOGATE", "TOILED", "TOILER", "TOKENS", "TOLUIC", "TOMCAT", "TOMIAL", "TONIER",
+    "TONISH", "TOOTHY", "TOPICS", "TOPPED", "TOPPLE", "TORCHY", "TORNLY", "TOROSE", "TORPID",
+    "TORRID", "TOSSED", "TOSSES", "TOUCAN", "TOUCHY", "TOUGHY", "TOUTED", "TOWELS", "TOWERS",
+    "TOWERY", "TOWHEE", "TRACKS", "TRACTS", "TRADER", "TRADES", "TRAGIC", "TRAILS", "TRAINS",
+    "TRAITS", "TRAMPS", "TRAPPE", "TRAPPY", "TRASHY", "TREADS", "TREATS", "TREBLY", "TRESSY",
+    "TRIALS", "TRIBAL", "TRIBES", "TRICKS", "TRICKY", "TRIFID", "TRIGLY", "TRIMLY", "TRINAL",
+    "TRIPLY", "TRISTE", "TRITER", "TROGON", "TROLLY", "TROTTY", "TRUANT", "TRUCKS", "TRUEST",
+    "TRUING", "TRUMPS", "TRUNKS", "TRUSTS", "TRUSTY", "TRUTHS", "TRYING", "TUBATE", "TUBERS",
+    "TUBOID", "TUFTED", "TUMBLE", "TUNING", "TURACO", "TURBAN", "TURBID", "TURBOT", "TURGID",
+    "TURKEY", "TURNED", "TURRET", "TURTLE", "TUSHED", "TUSSAL", "TWEEDY", "TWIGGY", "TWINED",
+    "TWIRLS", "TWIRLY", "TWISTS", "UGLILY", "UGSOME", "UMBRAL", "UMTEEN", "UNABLE", "UNAGED",
+    "UNAVID", "UNAWED", "UNAXED", "UNBASE", "UNBEAR", "UNBLED", "UNBOLD", "UNBORN", "UNBRED",
+    "UNCALM", "UNCAST", "UNCHID", "UNCLAD", "UNCLES", "UNDEAF", "UNDEEP", "UNDEFT", "UNDEWY",
+    "UNDONE", "UNDULY", "UNDYED", "UNEASY", "UNEPIC", "UNEVEN", "UNEVIL", "UNFAIR", "UNFELT",
+    "UNFINE", "UNFIRM", "UNFLAT", "UNFOLD", "UNFOND", "UNFOUL", "UNFOXY", "UNFULL", "UNGAMY",
+    "UNGILT", "UNGLAD", "UNGLIB", "UNGUAL", "UNHALE", "UNHAZY", "UNHELD", "UNHEWN", "UNHOED",
+    "UNHOLY", "UNHUED", "UNHUNG", "UNHURT", "UNIDLE", "UNIDLY", "UNIFIC", "UNIONS", "UNITED",
+    "UNITES", "UNJUST", "UNKEPT", "UNKIND", "UNLAID", "UNLAME", "UNLEAN", "UNLEFT", "UNLENT",
+    "UNLIMP", "UNLOCK", "UNLOST", "UNLUSH", "UNMANS", "UNMEET", "UNMIRY", "UNMOWN", "UNNEAT",
+    "UNNICE", "UNNIGH", "UNOILY", "UNORAL", "UNPAID", "UNPALE", "UNPENT", "UNPRIM", "UNPURE",
+    "UNRASH", "UNREAD", "UNREAL", "UNRENT", "UNREST", "UNRIFE", "UNRIPE", "UNRUDE", "UNRUED",
+    "UNRULY", "UNRUNG", "UNSAFE", "UNSAGE", "UNSAID", "UNSAWN", "UNSEEN", "UNSENT", "UNSERE",
+    "UNSHED", "UNSHOD", "UNSHUT", "UNSICK", "UNSLIM", "UNSLOW", "UNSMUG", "UNSNUG", "UNSOFT",
+    "UNSORE", "UNSOUR", "UNSOWN", "UNSPUN", "UNSUED", "UNSUNG", "UNSUNK", "UNSURE", "UNTAME",
+    "UNTAUT", "UNTIDY", "UNTIED", "UNTIES", "UNTOLD", "UNTORN", "UNTRIG", "UNTROD", "UNTRUE",
+    "UNUSED", "UNVAIN", "UNVEIL", "UNVOID", "UNWARY", "UNWEPT", "UNWILD", "UNWILY", "UNWISE",
+    "UNWORN", "UPBEAT", "UPHOLD", "UPLIFT", "UPMOST", "UPPISH", "UPPITY", "UPSETS", "UPTOWN",
+    "UPWARD", "URANIC", "URATIC", "URBANE", "URCHIN", "UREIDO", "UREMIC", "URETIC", "URGENT",
+    "URGING", "URSINE", "USABLE", "USABLY", "USHERS", "USURER", "UTMOST", "UTTERS", "VACANT",
+    "VACUUM", "VADOSE", "VAGROM", "VAGUER", "VAINLY", "VALETS", "VALGUS", "VALUED", "VALVAL",
+    "VALVAR", "VANISH", "VAPORY", "VAPOUR", "VASTLY", "VAULTS", "VAUNTY", "VEILED", "VEINAL",
+    "VEINED", "VELATE", "VENIAL", "VENOSE", "VENOUS", "VERBAL", "VERDIN", "VERIER", "VERIFY",
+    "VERILY", "VERNAL", "VERSED", "VERSES", "VERSTS", "VERVET", "VESSEL", "VESTAL", "VESTED",
+    "VEXING", "VIABLE", "VIABLY", "VIANDS", "VICUNA", "VIEWED", "VILELY", "VILEST", "VILLAS",
+    "VINOUS", "VIOLIN", "VIRILE", "VIRLED", "VIRTUE", "VISAGE", "VISCID", "VISITS", "VITALS",
+    "VITRIC", "VOICED", "VOICES", "VOIDED", "VOLANT", "VOLUTE", "VORANT", "VOTERS", "VOTING",
+    "VOTIVE", "VOWELY", "VOYAGE", "VULGAR", "VULNED", "VULTUR", "WABBLY", "WADDLY", "WADERS",
+    "WAFERS", "WAFERY", "WAFFLY", "WAGERS", "WAGGLY", "WAISTS", "WAITED", "WAITER", "WAKING",
+    "WALKED", "WALLED", "WALNUT", "WALRUS", "WAMBLY", "WANIER", "WANNER", "WANTED", "WANTON",
+    "WAPITI", "WARDED", "WARIER", "WARILY", "WARMED", "WARMER", "WARMLY", "WARNED", "WASHED",
+    "WASHES", "WASTED", "WASTES", "WATERY", "WAVIER", "WAVILY", "WAVING", "WAXIER", "WAXILY",
+    "WAXING", "WEAKER", "WEAKLY", "WEARER", "WEASEL", "WEBBED", "WEDDED", "WEDGED", "WEDGES",
+    "WEEVIL", "WEIGHS", "WETTED", "WHACKY", "WHALER", "WHALES", "WHEELS", "WHEEZY", "WHEYEY",
+    "WHILES", "WHINED", "WHINES", "WHIRLS", "WHIRLY", "WHISKY", "WHITED", "WHOLLY", "WHYDAH",
+    "WICKED", "WIDEST", "WIDISH", "WIDOWS", "WIDTHS", "WIELDY", "WIFELY", "WIGEON", "WIGGLY",
+    "WILDER", "WILDLY", "WILFUL", "WILIER", "WILILY", "WILLED", "WILLET", "WINCED", "WINDED",
+    "WINDER", "WINGED", "WINIER", "WINISH", "WINKED", "WINTRY", "WIRILY", "WISELY", "WISEST",
+    "WISHED", "WISHER", "WISHES", "WITCHY", "WITHER", "WITTED", "WIZARD", "WOADED", "WOBBLY",
+    "WOEFUL", "WOLVES", "WOMBAT", "WOMBED", "WONTED", "WOODED", "WOODSY", "WOOLLY", "WORDED",
+    "WORKED", "WORLDS", "WORSER", "WORTHY", "WOUNDS", "WRASSE", "WRENCH", "WRETCH", "WRIEST",
+    "WRINGS", "WRISTS", "WRITES", "WRONGS", "XYLOID", "YACHTY", "YARELY", "YAREST", "YEARLY",
+    "YEASTY", "YELLED", "YEOMAN", "YESTER", "YIELDS", "YOUTHS", "YTTRIC", "ZANIER", "ZANIES",
+    "ZANILY", "ZEBRAS", "ZEPHYR", "ZEROTH", "ZINCIC", "ZINCKY", "ZINGEL", "ZIPPER", "ZONATE",
+    "ZONKED", "ZOONAL",
+];
+// Words of the English vocabulary of the Snowball stemmer (voc.txt of
+// snowballstem.org, as shipped in the rust-stemmers crate), with the BIP39
+// English list and the word lists of the names and petname crates
+pub static GUESSES_7: &[&str] = &[
+    "ABANDON", "ABASHED", "ABDOMEN", "ABETTOR", "ABIDING", "ABJURED", "ABOLISH", "ABOUNDS",
+    "ABRAHAM", "ABREAST", "ABRIDGE", "ABSCESS", "ABSOLVE", "ABSORBS", "ABSTAIN", "ABUSING",
+    "ABUSIVE", "ABYSSES", "ACACIAS", "ACCEDED", "ACCEDES", "ACCENTS", "ACCEPTS", "ACCRUED",
+    "ACCUSED", "ACCUSER", "ACCUSES", "ACTINIA", "ACTIONS", "ACTRESS", "ACTUATE", "ACUTELY",
+    "ADAMANT", "ADAPTED", "ADAPTER", "ADDUCED", "ADHERED", "ADHERES", "ADJURED", "ADJUSTS",
+    "ADMIRAL", "ADMIRED", "ADMIRER", "ADMIRES", "ADOPTED", "ADORING", "ADORNED", "ADVERSE",
+    "ADVISED", "ADVISES", "ADWISER", "AEROBIC", "AFANASY", "AFEARED", "AFFABLE", "AFFABLY",
+    "AFFAIRS", "AFFECTS", "AFFIRMS", "AFFLICT", "AFFORDS", "AFFRONT", "AFLICTO", "AFRICAN",
+    "AFRIQUE", "AGILELY", "AGILITY", "AGITATE", "AGONIES", "AGOUTIS", "AGROUND", "AGUEROS",
+    "AILMENT", "AIMLESS", "AIRNEST", "ALAMEDA", "ALARMED", "ALBANIA", "ALDGATE", "ALERTLY",
+    "ALEWIFE", "ALFALFA", "ALFONSO", "ALGEBRA", "ALIGHTS", "ALLAYED", "ALLEGED", "ALLENBY",
+    "ALLOWED", "ALLOYED", "ALLUDED", "ALLUDES", "ALLURED", "ALMANAC", "ALTERED", "ALTHOUG",
+    "AMASSED", "AMATEUR", "AMBIENT", "AMBLING", "AMENDED", "AMERICA", "AMIABLE", "AMIABLY",
+    "AMONGST", "AMOROUS", "AMOUNTS", "AMUSING", "ANAEMIC", "ANALOGY", "ANALYSE", "ANALYZE",
+    "ANARCHY", "ANATOMY", "ANCHORS", "ANCHOVY", "ANDREWS", "ANEMONE", "ANGELIC", "ANGERED",
+    "ANGRILY", "ANGUISH", "ANGULAR", "ANHINGA", "ANIMALS", "ANIMATE", "ANNALES", "ANNETTE",
+    "ANNEWUM", "ANNEXED", "ANNOYED", "ANNUALS", "ANNUITY", "ANOMALY", "ANSTICE", "ANSWERS",
+    "ANTENNA", "ANTHONY", "ANTIQUE", "ANTLERS", "ANTLION", "ANTONIO", "ANXIOUS", "ANYVAYS",
+    "ANYWAYS", "APLYSIA", "APOLOGY", "APOSTLE", "APPAREL", "APPEALS", "APPEARS", "APPEASE",
+    "APPLIES", "APPOINT", "APPRISE", "APPROVE", "APROPOS", "APTERYZ", "APTNESS", "AQUATIC",
+    "AQUEOUS", "AQUINAS", "ARABIAN", "ARBITER", "ARBOURS", "ARBUTUS", "ARCADES", "ARCADIA",
+    "ARCHERY", "ARCHEST", "ARCHWAY", "ARDNESS", "ARDUOUS", "ARGUING", "ARIDITY", "ARISING",
+    "ARMHOLE", "AROUSED", "ARRAYED", "ARREARS", "ARRIERO", "ARRIVAL", "ARRIVED", "ARRIVES",
+    "ARTHURS", "ARTISAN", "ARTISTS", "ARTLESS", "ARTWORK", "ASCENDS", "ASCETIC", "ASCRIBE",
+    "ASHAMED", "ASIATIC", "ASKANCE", "ASPECTS", "ASPIRED", "ASPIRES", "ASSAYER", "ASSENTS",
+    "ASSERTS", "ASSIGNS", "ASSISTS", "ASSIZES", "ASSUMED", "ASSUMES", "ASSURED", "ASSURES",
+    "ASTELIA", "ASTRIDE", "ASUNDER", "ASYLUMS", "ATACAMA", "ATHLETE", "ATHWART", "ATRATUS",
+    "ATTACHE", "ATTACKS", "ATTAGIS", "ATTAINS", "ATTENDS", "ATTIRED", "ATWATER", "AUDIBLE",
+    "AUDIBLY", "AUDITOR", "AUDUBON", "AUGMENT", "AUGURED", "AUGUSTA", "AUGUSTE", "AURALLY",
+    "AUREOLE", "AUSTELL", "AUSTERE", "AUTHORS", "AVAILED", "AVARICE", "AVATARS", "AVDOTYA",
+    "AVENGER", "AVENGES", "AVENUES", "AVERRED", "AVERTED", "AVIDITY", "AVIGNON", "AVOCADO",
+    "AVOIDED", "AVOIENT", "AVOWING", "AWAITED", "AWAKENS", "AWAKING", "AWESOME", "AWFULLY",
+    "AWHILES", "AWKWARD", "AWOIDED", "AXOLOTL", "AZINGLY", "BABBLED", "BABYLON", "BACCHUS",
+    "BACHMAN", "BACKERS", "BACKING", "BADNESS", "BAFFLED", "BAGGAGE", "BAGNETS", "BAILIFF",
+    "BAILLIE", "BALCONY", "BALEFUL", "BALKING", "BALLAST", "BALLOON", "BAMBOOS", "BAMFORD",
+    "BANANAS", "BANDAGE", "BANDBOX", "BANDIED", "BANEFUL", "BANGING", "BANISHE", "BANKERS",
+    "BANKING", "BANNERS", "BANQUET", "BANSHEE", "BANTERS", "BAPTISM", "BARBARE", "BARBARY",
+    "BARBUDA", "BARGAIN", "BARKING", "BARMAID", "BARNARD", "BARNTON", "BARONET", "BARRACK",
+    "BARRELS", "BARRETT", "BARROWS", "BASALTI", "BASHFUL", "BASKETS", "BASKING", "BASSOON",
+    "BASTARD", "BATFISH", "BATHERS", "BATHING", "BATTLED", "BATTLES", "BAUBLES", "BAWDILY",
+    "BAWLING", "BAYONET", "BEACHES", "BEACONS", "BEADLES", "BEAMING", "BEARDED", "BEARERS",
+    "BEARING", "BEARISH", "BEASTLY", "BEATERS", "BEATING", "BEATSON", "BEATTEN", "BECAUSE",
+    "BECKONS", "BECOMES", "BEDDING", "BEDECKS", "BEDSIDE", "BEDTIME", "BEECHEY", "BEEHIVE",
+    "BEESWAX", "BEETLES", "BEGGARS", "BEGGARY", "BEGGING", "BEGUILE", "BEHAVED", "BEHAVES",
+    "BEHESTS", "BEHOLDS", "BEHOVED", "BEHOVES", "BEHRING", "BEKNOWN", "BELATED", "BELDAME",
+    "BELIEFS", "BELIEVE", "BELLIES", "BELLING", "BELLMEN", "BELLOWS", "BELONGS", "BELOVED",
+    "BENCHER", "BENCHES", "BENDING", "BENEATH", "BENNETT", "BEQUEST", "BERARDI", "BERKELE",
+    "BERRIED", "BERRIES", "BERRYIN", "BERSERK", "BERTRAM", "BESEECH", "BESEEMS", "BESIDES",
+    "BESPEAK", "BESPOKE", "BESTEST", "BESTOWS", "BETAKEN", "BETHANY", "BETIMES", "BETRAYS",
+    "BETTERS", "BETTHER", "BETTING", "BETTONG", "BETWIXT", "BEUDANT", "BEZANTS", "BIDDING",
+    "BIGGEST", "BIGNESS", "BIGOTED", "BIGOTRY", "BILEING", "BILIOUS", "BILLING", "BILLOWS",
+    "BILLOWY", "BINDING", "BINGLEY", "BISCUIT", "BISHOPS", "BISNESS", "BITTERN", "BIVOUAC",
+    "BIZARRE", "BLACKEN", "BLACKER", "BLADDER", "BLAMING", "BLANCHE", "BLANDLY", "BLANKLY",
+    "BLASTED", "BLAZING", "BLEAKLY", "BLEARED", "BLEMISH", "BLENDED", "BLESBOK", "BLESSED",
+    "BLESSES", "BLIGHTS", "BLINDED", "BLINDER", "BLINDLY", "BLISTER", "BLOATED", "BLOCKED",
+    "BLOODED", "BLOSSOM", "BLOTCHY", "BLOTTED", "BLOWERS", "BLOWING", "BLUBBER", "BLUEJAY",
+    "BLUNDER", "BLUNTED", "BLUNTLY", "BLURTED", "BLUSHED", "BLUSHES", "BLUSTER", "BOARDED",
+    "BOARDER", "BOASTED", "BOATING", "BOBBING", "BOBBISH", "BOBSTER", "BODKINS", "BOILERS",
+    "BOILING", "BOLDEST", "BOLIVIA", "BOLSTER", "BOLTING", "BONDAGE", "BONITOS", "BONJOUR",
+    "BONNETS", "BOOKING", "BOOKISH", "BOORISH", "BOOTUNS", "BOOZUMS", "BORDERS", "BOREALI",
+    "BOREDOM", "BOROUGH", "BORROWS", "BOSOMER", "BOTANIC", "BOTTLED", "BOTTLES", "BOTTOMS",
+    "BOUDOIR", "BOULDER", "BOULONG", "BOUNCED", "BOUNDED", "BOUNDEN", "BOUQUET", "BOURBON",
+    "BOWERED", "BOWLING", "BOXFISH", "BOYHOOD", "BRACING", "BRACKET", "BRAIDED", "BRAINED",
+    "BRANDED", "BRANDON", "BRASHLY", "BRASSES", "BRAVADO", "BRAVARD", "BRAVELY", "BRAVERY",
+    "BRAVEST", "BRAVING", "BRAWLER", "BRAYING", "BRAZILS", "BREADTH", "BREAKER", "BREAKIN",
+    "BREASTS", "BREATHE", "BREATHS", "BRECCIA", "BREEDER", "BREEZES", "BREVITY", "BREWERS",
+    "BREWERY", "BREWING", "BRIBERY", "BRIBING", "BRICKED", "BRIDGED", "BRIDGES", "BRIEFLY",
+    "BRIGADE", "BRIGAND", "BRIMFUL", "BRIMMED", "BRINDLE", "BRISKER", "BRISKLY", "BRISTLE",
+    "BRISTLY", "BRISTOL", "BRITAIN", "BRITISH", "BRITTLE", "BROADER", "BROADLY", "BROCADE",
+    "BROCKET", "BROGDEN", "BROILED", "BROKERS", "BROKKEN", "BROMLEY", "BRONZED", "BROODED",
+    "BROOKER", "BROOSES", "BROUGHT", "BROWDIE", "BROWNER", "BROWSED", "BRUISED", "BRUISES",
+    "BRUSHED", "BRUSHES", "BRUSQUE", "BRUTISH", "BUBBLES", "BUBBLIN", "BUCKETS", "BUCKLER",
+    "BUCKLES", "BUCKRAM", "BUDDING", "BUDGETS", "BUFFALO", "BUFFETS", "BUFFOON", "BUILDER",
+    "BUILDIN", "BULBOUS", "BULIMUS", "BULLDOG", "BULLETS", "BULLIED", "BULLIES", "BULLION",
+    "BULLOCK", "BUMPERS", "BUMPING", "BUNCHES", "BUNDLED", "BUNDLES", "BUNGAYS", "BUNTING",
+    "BUOYANT", "BURDENS", "BURNING", "BURNOUS", "BURROWS", "BURTHEN", "BURYING", "BUSHELS",
+    "BUSTARD", "BUSTLED", "BUSYING", "BUTCHER", "BUTTHER", "BUTTING", "BUTTONS", "BUYINGS",
+    "BUZZARD", "BUZZING", "BYGONES", "CABBAGE", "CABBERY", "CACIQUE", "CACKLED", "CADOGAN",
+    "CAFFRES", "CAJOLED", "CALIBRE", "CALLEMS", "CALLING", "CALLOUS", "CALMEST", "CALMING",
+    "CALOMEL", "CALUMNY", "CAMBRIC", "CAMPANA", "CAMPANY", "CAMPHOR", "CANDLES", "CANDOUR",
+    "CANNILY", "CANNING", "CANNONS", "CANTING", "CANTRIP", "CANVASS", "CAPABLY", "CAPELIN",
+    "CAPELLA", "CAPITOL", "CAPPING", "CAPRICE", "CAPSULE", "CAPTION", "CAPTIVE", "CARACAL",
+    "CARAVAN", "CARBINE", "CARBURY", "CARCASE", "CARCASS", "CARDOON", "CAREERS", "CARGOES",
+    "CARIBOU", "CARIZAL", "CARKING", "CARLTON", "CARMINE", "CARNAGE", "CARPETS", "CARPING",
+    "CARRIED", "CARRIES", "CARRION", "CARROTS", "CARVING", "CASCADE", "CASKETS", "CASPIAN",
+    "CASSADA", "CASTING", "CASTLES", "CASUCHA", "CATBIRD", "CATCHED", "CATCHES", "CATFISH",
+    "CAUSING", "CAUSTIC", "CAUTION", "CAVALRY", "CAVERNS", "CAYANUS", "CAYENNE", "CEASING",
+    "CECILIA", "CEILLHG", "CELEBES", "CELLARS", "CENSURE", "CENTRED", "CENTRES", "CERTHIA",
+    "CERTIFY", "CETERAS", "CHAFING", "CHAGRIN", "CHAINED", "CHALKED", "CHAMBRE", "CHAMOIS",
+    "CHANCED", "CHANCES", "CHANGED", "CHANGES", "CHANTED", "CHAOTIC", "CHAPELS", "CHARADE",
+    "CHARGED", "CHARGES", "CHARIEY", "CHARILY", "CHARING", "CHARIOT", "CHARLES", "CHARLEY",
+    "CHARLIE", "CHARMED", "CHARMER", "CHARNEL", "CHARQUI", "CHARRED", "CHASING", "CHATEAU",
+    "CHATHAM", "CHATTED", "CHATTER", "CHEAPER", "CHEAPLY", "CHEATED", "CHECKED", "CHEEKED",
+    "CHEERED", "CHEETAH", "CHELSEA", "CHEMINS", "CHEMISE", "CHEMIST", "CHEQUES", "CHERISH",
+    "CHESNEY", "CHESTED", "CHEUCAU", "CHEVAUX", "CHEWING", "CHIDUCO", "CHIEFLY", "CHIGGER",
+    "CHILEAN", "CHILENO", "CHILIAN", "CHILIPA", "CHILLED", "CHIMNEY", "CHINESE", "CHINOOK",
+    "CHIONIS", "CHIPPER", "CHIRPED", "CHIRRUP", "CHITONS", "CHIVIED", "CHIZZLE", "CHOKING",
+    "CHOLERA", "CHONCHI", "CHOOSES", "CHOPPED", "CHOWSER", "CHRISTI", "CHUCKED", "CHUCKLE",
+    "CHURNED", "CICADAE", "CICADAS", "CICHLID", "CICIDAE", "CIMABUE", "CINDERS", "CINDERY",
+    "CIRCLES", "CISTERN", "CITADEL", "CIVILLY", "CLAIMED", "CLAMOUR", "CLAPPED", "CLARIFY",
+    "CLASHED", "CLASHES", "CLASPED", "CLASSED", "CLASSES", "CLATTER", "CLAUSEN", "CLAUSES",
+    "CLAWING", "CLAYING", "CLAYVER", "CLEANED", "CLEANER", "CLEANLY", "CLEANSE", "CLEARED",
+    "CLEARER", "CLEARLY", "CLERKLY", "CLICKED", "CLIENTS", "CLIFTON", "CLIMBED", "CLINKED",
+    "CLIPPED", "CLIQUOT", "CLOGGED", "CLOSELY", "CLOSEST", "CLOSETS", "CLOSING", "CLOTHED",
+    "CLOTHER", "CLOTTED", "CLOUDED", "CLUSKIE", "COACHES", "COARSER", "COATING", "COAVINS",
+    "COAXING", "COBBLER", "COBWEBS", "COCKING", "COCKNEY", "COCONUT", "CODDLED", "CODICIL",
+    "CODLING", "COERCED", "COFFERS", "COFFINS", "COGNATE", "COINAGE", "COINERS", "COINING",
+    "COLDEST", "COLEMAN", "COLLARS", "COLLIER", "COLLNET", "COLNETT", "COLOBUS", "COLOGNE",
+    "COLONEL", "COLONIA", "COLORED", "COLOURS", "COLUMNS", "COMBATS", "COMBING", "COMICAL",
+    "COMINGS", "COMMEND", "COMMITS", "COMMONL", "COMMONS", "COMMUNE", "COMPACT", "COMPANY",
+    "COMPASS", "COMPELS", "COMPOSE", "COMPTER", "COMPTES", "COMRADE", "CONCAVE", "CONCEAL",
+    "CONCEDE", "CONCEIT", "CONCHAS", "CONCISE", "CONCOCT", "CONCORD", "CONCURS", "CONDEMN",
+    "CONDIGN", "CONDOLE", "CONDORS", "CONDUCE", "CONEJOS", "CONFERS", "CONFESS", "CONFIDE",
+    "CONFINE", "CONFORM", "CONFUSE", "CONFUTE", "CONICAL", "CONJURE", "CONNING", "CONQUER",
+    "CONSARN", "CONSENT", "CONSIGN", "CONSOLE", "CONSOLS", "CONSORT", "CONSULT", "CONSUME",
+    "CONTEND", "CONURUS", "CONVENT", "CONVEYS", "CONVICT", "COOKERY", "COOKING", "COOLEST",
+    "COOLING", "COPECKS", "COPHIAS", "COPIAPO", "COPIOUS", "COPPERS", "COPPERY", "COPPICE",
+    "COPYING", "CORDAGE", "CORDIAL", "CORKING", "CORNERS", "CORNICE", "CORNISH", "CORONAL",
+    "CORONER", "CORONET", "CORPSES", "CORRALS", "CORRUPT", "CORUNDA", "COSTUME", "COTINGA",
+    "COTTAGE", "COTTONS", "COUCHED", "COUCHES", "COUGHED", "COULDST", "COUNSEL", "COUNTED",
+    "COUPLED", "COUPLES", "COURCYS", "COURIER", "COURSED", "COURSER", "COURSES", "COURTED",
+    "COURTLY", "COUSINS", "COVERED", "COVERTS", "COVETED", "COWARDS", "COWBIRD", "COWERED",
+    "COWFISH", "COWSHED", "COWSLIP", "COXCOMB", "COYNESS", "COZENED", "CRABBED", "CRACKED",
+    "CRACKER", "CRACKLE", "CRADLES", "CRAMBLE", "CRAMMED", "CRAMPED", "CRANING", "CRANKED",
+    "CRAPPIE", "CRASHED", "CRATERS", "CRAVATS", "CRAVING", "CRAWDAD", "CRAWLED", "CRAYONS",
+    "CRAZILY", "CREAKED", "CREASES", "CREATED", "CREATOR", "CREEPER", "CREETUR", "CRESTED",
+    "CREVICE", "CRICKET", "CRIMSON", "CRIPPLE", "CRISPLY", "CRISTAL", "CRITICS", "CROAKED",
+    "CROAKER", "CROESUS", "CROOKED", "CROPLEY", "CROSSED", "CROSSES", "CROSSIN", "CROSSLY",
+    "CROWBAR", "CROWDED", "CROWING", "CROWNED", "CRUCIAL", "CRUCIFY", "CRUDELY", "CRUDEST",
+    "CRUELLY", "CRUELTY", "CRUENTA", "CRUISES", "CRUMBER", "CRUMBLE", "CRUMPET", "CRUPPER",
+    "CRUSADE", "CRUSHED", "CRUSHES", "CUCKOOS", "CUDGELS", "CUENTAS", "CULPRIT", "CUNNING",
+    "CURLING", "CURRAGH", "CURRANT", "CURRIES", "CURSING", "CURSORY", "CURTAIN", "CURTSEY",
+    "CURTSYS", "CURVING", "CUSHION", "CUSTODY", "CUSTOMS", "CUTLASS", "CUTLERY", "CUTLETS",
+    "CUTTERS", "CUTTING", "CYNICAL", "CYNUCUS", "CYPERUS", "CYPRESS", "DABBING", "DABBLED",
+    "DAGGERS", "DALLIED", "DAMAGES", "DAMPEST", "DAMPIER", "DAMPING", "DAMSELS", "DANCERS",
+    "DANCING", "DANGERS", "DANIELL", "DAPIBUS", "DARESAY", "DARKENS", "DARKEST", "DARLING",
+    "DARNING", "DARTING", "DASHING", "DASTARD", "DASYPUS", "DAUBENY", "DAUBNEY", "DAUNTED",
+    "DAWLISH", "DAWNING", "DAYTIME", "DAZZLED", "DAZZLER", "DEADENS", "DEADPAN", "DEALERS",
+    "DEAREST", "DEATHLY", "DEBACLE", "DEBASED", "DEBATED", "DEBATER", "DEBATES", "DEBAUCH",
+    "DEBTORS", "DECAYED", "DECEASE", "DECEIVE", "DECENCY", "DECIDED", "DECIDES", "DECIMAL",
+    "DECKING", "DECLAIM", "DECLARE", "DECORUM", "DECREED", "DECREES", "DEDLOCK", "DEDUCED",
+    "DEEMING", "DEEPENS", "DEEPEST", "DEERING", "DEFECTS", "DEFENCE", "DEFIANT", "DEFILED",
+    "DEFILES", "DEFINED", "DEFUNCT", "DEFYING", "DEGRADE", "DEGREES", "DEIFIED", "DEIGNED",
+    "DELAVAL", "DELAYED", "DELIGHT", "DELUDED", "DELUDES", "DELUGES", "DELVING", "DEMANDS",
+    "DEMDEST", "DEMERSA", "DEMONIC", "DENMARK", "DENOTED", "DENOTES", "DENSELY", "DENSEST",
+    "DENTIST", "DENUDED", "DENYING", "DEODARA", "DEPARTS", "DEPENDS", "DEPICTS", "DEPLORE",
+    "DEPOSED", "DEPOSER", "DEPRESS", "DEPRIVE", "DEPUTED", "DERIDED", "DERIVED", "DERIVES",
+    "DERWENT", "DESCEND", "DESCENT", "DESCRIP", "DESERTS", "DESERVE", "DESIGNS", "DESIRED",
+    "DESIRES", "DESISTS", "DESOLEE", "DESPAIR", "DESPISE", "DESPOIL", "DESPOND", "DESSERT",
+    "DESTINY", "DETAILS", "DETECTS", "DETRACT", "DEVICES", "DEVILRY", "DEVIOUS", "DEVISED",
+    "DEVISER", "DEVOTED", "DEVOTES", "DEWDROP", "DIAGRAM", "DIALECT", "DIANAEA", "DIARIES",
+    "DICKENS", "DICTATE", "DIDDLER", "DIERNAN", "DIFFERS", "DIGGING", "DIGNITY", "DILATED",
+    "DILATES", "DILEMMA", "DILUTED", "DIMPLED", "DINGILY", "DINNERS", "DIOCESE", "DIOPAEA",
+    "DIPPING", "DIPTERA", "DIRECTS", "DIREFUL", "DIRTIED", "DIRTIER", "DISABLE", "DISAVOW",
+    "DISBAND", "DISCARD", "DISCERN", "DISCORD", "DISCUSS", "DISDAIN", "DISEASE", "DISGUST",
+    "DISJOIN", "DISLIKE", "DISMISS", "DISOBEY", "DISPOSE", "DISPUTE", "DISSENT", "DISTANC",
+    "DISTORT", "DISTURB", "DISUSED", "DITCHES", "DITTIES", "DIURNAL", "DIVERGE", "DIVIDED",
+    "DIVIDES", "DIVINED", "DIVINER", "DIVINES", "DIVISIO", "DIVULGE", "DIZZIER", "DIZZILY",
+    "DOCTORS", "DODGING", "DOGFISH", "DOGGIES", "DOGGING", "DOLEFUL", "DOLLARS", "DOLPHIN",
+    "DOMAINS", "DOMIDOR", "DOMINGO", "DONATIA", "DONKEYS", "DOORWAY", "DORMANT", "DOUBLED",
+    "DOUBLES", "DOUBTED", "DOUCHES", "DOUGLAS", "DOVETON", "DOWAGER", "DOWDILY", "DOWDLES",
+    "DOWNING", "DOZENTH", "DRAGGED", "DRAGOON", "DRAINED", "DRAPERY", "DRASTIC", "DRAUGHT",
+    "DRAWERS", "DRAWLED", "DREADED", "DREAMED", "DREAMER", "DRESDEN", "DRESSED", "DRESSER",
+    "DRESSES", "DRIFTED", "DRILLED", "DRINKER", "DRIVERS", "DRIVING", "DRIZZLE", "DRIZZLY",
+    "DROOPED", "DROPPED", "DROUGHT", "DROVERS", "DROWNED", "DRUDGES", "DRUMMER", "DRUNKEN",
+    "DRYNESS", "DUBIOUS", "DUCHESS", "DUCKING", "DUCLIDA", "DUDGEON", "DUKEDOM", "DULLEST",
+    "DULLISH", "DULNESS", "DULWICH", "DUNGEON", "DUNLOPS", "DUNNOCK", "DURABLE", "DURABLY",
+    "DURATIO", "DUSHKIN", "DUSKIER", "DUSTIER", "DUSTING", "DUSTMAN", "DUTIFUL", "DWELLER",
+    "DYNASTY", "EAGERLY", "EARINGS", "EARLIER", "EARNEST", "EARNING", "EARRING", "EARTHEN",
+    "EARTHLY", "EARWIGS", "EASIEST", "EATABLE", "ECHIDNA", "ECHOING", "ECLIPSE", "ECOLOGY",
+    "ECSTASY", "EDDYING", "EDENTAL", "EDIFICE", "EDIFIED", "EDITORS", "EDUCATE", "EDWARDS",
+    "EELPOUT", "EFFECTS", "EFFORTS", "EIGHTHS", "EJECTED", "ELAPSED", "ELAPSES", "ELASTIC",
+    "ELATERS", "ELATION", "ELBOWED", "ELECTED", "ELECTOR", "ELEGANS", "ELEGANT", "ELEVATE",
+    "ELEVENS", "ELICITS", "ELSDALE", "ELUDING", "ELYSIAN", "ELYSIUM", "EMBARGO", "EMBASSY",
+    "EMBLEMS", "EMBOWED", "EMBRACE", "EMBROIL", "EMERALD", "EMERGED", "EMERGES", "EMINENT",
+    "EMITTED", "EMPEROR", "EMPLOYS", "EMPOWER", "EMPRESS", "EMPTIED", "EMPTIES", "ENABLED",
+    "ENABLES", "ENACTED", "ENCAMPS", "ENCASED", "ENCLOSE", "ENDEMIC", "ENDERBY", "ENDLESS",
+    "ENDORSE", "ENDOWED", "ENDURED", "ENEMIES", "ENFORCE", "ENGADDI", "ENGAGED", "ENGAGES",
+    "ENGAGMG", "ENGINES", "ENGLAND", "ENGLISH", "ENJOINS", "ENJOYED", "ENLARGE", "ENLIVEN",
+    "ENNOBLE", "ENRAGED", "ENROBED", "ENSNARE", "ENSUING", "ENTERED", "ENTICED", "ENTITLE",
+    "ENTOMOL", "ENTREAT", "ENTRIES", "ENTRUST", "ENTWINE", "ENVIOUS", "ENVIRON", "EPICURE",
+    "EPISODE", "EPISTLE", "EPITHET", "EPITOME", "EQUABLE", "EQUABLY", "EQUALLE", "EQUALLY",
+    "EQUATOR", "EQUINOX", "EQUITEM", "ERECTED", "ERECTLY", "EROSION", "ERRANDS", "ERRATIC",
+    "ERUDITE", "ERUPTED", "ESCAPED", "ESCAPES", "ESCHARA", "ESCOLAR", "ESPAGNE", "ESPOUSE",
+    "ESQUIRE", "ESSAYED", "ESSENCE", "ESTATES", "ESTEEMS", "ESTHERS", "ESTUARY", "ETERNAL",
+    "ETHICAL", "EUGLENA", "EUSTACE", "EVADING", "EVASION", "EVASIVE", "EVELYNS", "EVENLNG",
+    "EVERETT", "EVIDENT", "EVINCED", "EVOLVED", "EXACTED", "EXALTED", "EXCEEDS", "EXCITES",
+    "EXCLAIM", "EXCUSED", "EXCUSES", "EXECUTE", "EXERTED", "EXHALED", "EXHAUST", "EXHORTS",
+    "EXISTED", "EXOTICS", "EXPANDE", "EXPANDS", "EXPANSE", "EXPECTS", "EXPIATE", "EXPIRED",
+    "EXPLODE", "EXPLOIT", "EXPORTS", "EXPOSED", "EXPOSES", "EXPOUND", "EXTENDS", "EXTINCT",
+    "EXTRACT", "EXULTED", "EYEBALL", "EYEBROW", "EYELASH", "EYELIDS", "EYESORE", "EZACTLY",
+    "FACINGS", "FACTION", "FACTORS", "FACTUAL", "FACULTY", "FADEDLY", "FAILING", "FAINTED",
+    "FAINTER", "FAINTLY", "FAIREST", "FAIRIES", "FAIRISH", "FALLACY", "FALLING", "FALSELY",
+    "FALSIFY", "FALSITY", "FALTERS", "FAMINES", "FANCIED", "FANCIER", "FANCIES", "FANEANT",
+    "FANTAIL", "FANTASY", "FARINHA", "FARMERS", "FARMING", "FARRAGO", "FARTHER", "FASTENS",
+    "FASTEST", "FASTING", "FATALLY", "FATEFUL", "FATHERS", "FATHOMS", "FATIGUE", "FATNESS",
+    "FATTISH", "FATUITY", "FAVOURS", "FAWNING", "FAZENDA", "FEARETH", "FEARFUL", "FEARING",
+    "FEASTED", "FEATHER", "FEDOSYA", "FEDYAEV", "FEEBLER", "FEECKLE", "FEEDERS", "FEEDING",
+    "FEIGNED", "FELLOWS", "FELSPAR", "FEMALES", "FENCING", "FERMENT", "FERNERY", "FERONIA",
+    "FERRIED", "FERRULE", "FERTILE", "FERVENT", "FERVOUR", "FESTIVE", "FESTOON", "FETCHED",
+    "FETCHES", "FETLOCK", "FETTERS", "FEVERED", "FEWNESS", "FEYTHER", "FIANCEE", "FIBBING",
+    "FIBROUS", "FIDDLES", "FIDGETT", "FIDGETY", "FIERCER", "FIFTHLY", "FIGHTER", "FIGMENT",
+    "FIGURED", "FIGURES", "FILCHED", "FILLING", "FINALLY", "FINCHES", "FINFOOT", "FINGERS",
+    "FINICKY", "FINNISH", "FIREFLY", "FIREMAN", "FIRMEST", "FIRSTLY", "FISSURE", "FITTEST",
+    "FITTING", "FITZROY", "FIXEDLY", "FIXTURE", "FLAGGED", "FLAMING", "FLANKED", "FLANNEL",
+    "FLAPPED", "FLARING", "FLASHED", "FLASHES", "FLATTER", "FLAVOUR", "FLAYING", "FLECKED",
+    "FLEDGED", "FLEMISH", "FLESHLY", "FLEXURE", "FLIGHTS", "FLIGHTY", "FLINDER", "FLIRTED",
+    "FLITTED", "FLOATED", "FLOCKED", "FLOGGED", "FLOODED", "FLOORED", "FLORIAN", "FLORIST",
+    "FLORULA", "FLOUNCE", "FLOURED", "FLOWERS", "FLOWERY", "FLOWING", "FLUNKEY", "FLUSHED",
+    "FLUSHES", "FLUSTRA", "FLUTTER", "FOAMING", "FOIBLES", "FOLDING", "FOLIAGE", "FOLLIES",
+    "FOLLOWS", "FOMITCH", "FONDEST", "FONDLES", "FOOLERY", "FOOLING", "FOOLISH", "FOOTBOY",
+    "FOOTING", "FOOTMAN", "FOOTMEN", "FOOTPAD", "FOOTWAY", "FOPPISH", "FORARDS", "FORBADE",
+    "FORBEAR", "FORBIDS", "FORBORE", "FORCEPS", "FORCING", "FOREMAN", "FORESAW", "FORESEE",
+    "FORESTS", "FORFEIT", "FORGAVE", "FORGERY", "FORGETS", "FORGING", "FORGIVE", "FORLORN",
+    "FORMING", "FORRARD", "FORSAKE", "FORSOOK", "FORSTER", "FOSSILS", "FOULEST", "FOUNDED",
+    "FOURIER", "FOURTHS", "FRAGILE", "FRAMING", "FRANCIA", "FRANCIS", "FRANKLY", "FRANTIC",
+    "FRAUGHT", "FREEING", "FREEZES", "FREIGHT", "FRESHEN", "FRESHER", "FRESHLY", "FRETFUL",
+    "FRETTED", "FRIABLE", "FRIDAYS", "FRIENDS", "FRIGATE", "FRIGHTS", "FRILLED", "FRINGED",
+    "FRINGES", "FRITTER", "FROLICS", "FRONTED", "FROSTED", "FROTHED", "FROWNED", "FUCHSIA",
+    "FUDDLED", "FUEGIAN", "FUENTES", "FULCRUM", "FULLEST", "FULNESS", "FUMBLED", "FUNEBRE",
+    "FUNNILY", "FURIOUS", "FURNACE", "FURNISH", "FURROWS", "FURTHER", "FURTIVE", "FUSSILY",
+    "FUSTIAN", "FUTURES", "GABBLED", "GADWALL", "GAIMARD", "GAINFUL", "GAINING", "GAINSAY",
+    "GAITERS", "GALLANT", "GALLEYS", "GALLING", "GALLONS", "GALLOPS", "GALLOWS", "GAMBIER",
+    "GAMBLED", "GAMBLER", "GAMBLES", "GANGING", "GANGWAY", "GANNETS", "GARBAGE", "GARBLED",
+    "GARDENS", "GARDNER", "GARFISH", "GARLAND", "GARMENT", "GARNETT", "GARNISH", "GARPIKE",
+    "GARRETS", "GARTERS", "GASPING", "GATHERS", "GAUCHOS", "GAUDILY", "GAUNTLY", "GAZELLE",
+    "GAZETTE", "GAZINGI", "GELDING", "GEMSBOK", "GENERIC", "GENESIS", "GENLMEN", "GENTEEL",
+    "GENTILE", "GENTLER", "GEOLOGY", "GEORGES", "GEORGIA", "GEOUSLY", "GERENUK", "GERMANS",
+    "GERMANY", "GERVAIS", "GETTING", "GEWGAWS", "GHASTLY", "GHOSTLY", "GIDDILY", "GIGGLED",
+    "GIGGLES", "GILDING", "GILLIES", "GIMLETS", "GINGERY", "GIRAFFE", "GIRDLED", "GIRLISH",
+    "GIZZARD", "GLACIER", "GLADDEN", "GLANCED", "GLANCES", "GLARING", "GLASSES", "GLEAMED",
+    "GLEANED", "GLIDING", "GLIMMER", "GLISSEZ", "GLISTEN", "GLITTER", "GLOATED", "GLORIED",
+    "GLORIES", "GLOWING", "GLUTTON", "GNARLED", "GNASHED", "GNAWERS", "GNAWING", "GOBBLER",
+    "GOBLETS", "GODDESS", "GODFREY", "GODLESS", "GODLIKE", "GODSEND", "GOESLER", "GONDOLA",
+    "GOODBYE", "GORGING", "GORILLA", "GOSHAWK", "GOSSIPS", "GOURAMI", "GOVERNS", "GRACING",
+    "GRACKLE", "GRADUAL", "GRAFTED", "GRAINED", "GRAMMAR", "GRANDEE", "GRANDER", "GRANDLY",
+    "GRANITE", "GRANTED", "GRANULO", "GRAPPLE", "GRASPED", "GRASPUS", "GRASSES", "GRATFUL",
+    "GRATIFY", "GRATING", "GRAVELY", "GRAVEST", "GRAVIES", "GRAVITY", "GRAZIER", "GRAZING",
+    "GREASED", "GREATER", "GREATLY", "GRECIAN", "GREENER", "GREENLY", "GREETED", "GREGORY",
+    "GRESHAM", "GREYISH", "GRIDLEY", "GRIEVED", "GRIEVES", "GRIFFIN", "GRIFFON", "GRIMACE",
+    "GRIMBLE", "GRINDER", "GRINNED", "GRIPING", "GRIPPED", "GRIZZLY", "GROANED", "GROCERY",
+    "GROGRAM", "GROOMED", "GROOVES", "GROPING", "GROSSER", "GROSSLY", "GROUCHY", "GROUNDS",
+    "GROUPED", "GROUPER", "GROWETH", "GROWLED", "GRUBBED", "GRUBBLE", "GRUDDEN", "GRUDGED",
+    "GRUDGES", "GRUFFLY", "GRUMBLE", "GRUNTED", "GRUNTER", "GRYLLUS", "GUANACO", "GUARDED",
+    "GUARDIA", "GUASCOS", "GUDGEON", "GUESSED", "GUESSES", "GUIDING", "GUINEAS", "GUITRON",
+    "GULLEYS", "GULLIES", "GULPING", "GUMWOOD", "GUNLESS", "GUNNERA", "GUNNERS", "GUNTHER",
+    "GUNWALE", "GURGLED", "GURNARD", "GUSHING", "GUTTERS", "GWYNETH", "GYPSIES", "HABITED",
+    "HACKNEY", "HADDOCK", "HAGFISH", "HAGGARD", "HAILING", "HAIRCUT", "HALCYON", "HALFWAY",
+    "HALIBUT", "HALTICA", "HALTING", "HAMLETS", "HAMMERS", "HAMMOND", "HAMPERS", "HAMPTON",
+    "HAMSTER", "HANDILY", "HANDING", "HANDLED", "HANDLES", "HANGDOG", "HANGERS", "HANGING",
+    "HANGMAN", "HANKERS", "HANOVER", "HANSOME", "HAPLESS", "HAPPENS", "HAPPIER", "HAPPILY",
+    "HARBOUR", "HARDEST", "HARDILY", "HARDING", "HARMFUL", "HARNESS", "HARPIES", "HARPING",
+    "HARRIER", "HARRIET", "HARROWS", "HARSHER", "HARSHLY", "HARVEST", "HASTENS", "HASTILY",
+    "HATCHED", "HATCHES", "HATCHET", "HATEFUL", "HATLESS", "HATREDS", "HAUGHTY", "HAULING",
+    "HAUNTED", "HAYCOCK", "HAZARDS", "HEADING", "HEALING", "HEALTHS", "HEAPING", "HEARERS",
+    "HEAREST", "HEARKEN", "HEARTED", "HEARTHS", "HEATHEN", "HEATHER", "HEAVENS", "HEAVIER",
+    "HEAVING", "HEDGING", "HEEDING", "HEIGHTH", "HEIGHTS", "HEINOUS", "HEIRESS", "HELLISH",
+    "HELMETS", "HELPERS", "HELPING", "HEMMING", "HENSLOW", "HERBAGE", "HERBERT", "HERETIC",
+    "HERODED", "HEROINE", "HEROISM", "HERRIES", "HERRING", "HIDEOUS", "HIGGINS", "HIGHEST",
+    "HILAIRE", "HILLOCK", "HINDOOS", "HINTING", "HIPPAHS", "HIPPISH", "HIRROLD", "HISSELF",
+    "HISSING", "HITCHED", "HITCHES", "HITTING", "HOARDED", "HOARSER", "HOATZIN", "HOBBIES",
+    "HOBBLED", "HOBBLES", "HOGOLEU", "HOISTED", "HOLBORN", "HOLDERS", "HOLDING", "HOLLAND",
+    "HOLLIES", "HOLLOWS", "HONESTY", "HONEYED", "HONORIA", "HONOURS", "HOOKING", "HOONGER",
+    "HOONGRY", "HOOPING", "HOOTING", "HOPEFUL", "HOPPING", "HORATIO", "HORDERS", "HORIZON",
+    "HORNSEY", "HORRORS", "HOSTESS", "HOSTILE", "HOTTEST", "HOUNDED", "HOVERED", "HOWBEIT",
+    "HOWLING", "HUACHOS", "HUBBARD", "HUDDLED", "HUFFILY", "HUGGING", "HUGGINS", "HUITREU",
+    "HULKING", "HUMAINE", "HUMANLY", "HUMANUM", "HUMBLED", "HUMBLER", "HUMDRUM", "HUMMING",
+    "HUMOURS", "HUNTERS", "HURRAHS", "HURRIED", "HURRIES", "HURTADO", "HURTING", "HUSHING",
+    "HUSKILY", "HUSTLED", "HYBRIDA", "HYDRANT", "ICEBERG", "ICELAND", "ICTERUS", "IDEALLY",
+    "IDIOTCY", "IDIOTIC", "IGNEOUS", "IGNITED", "IGNOBLE", "IGNOBLY", "IGNORED", "ILLAPEL",
+    "ILLEGAL", "IMBIBED", "IMITATE", "IMMENSE", "IMMORAL", "IMMURED", "IMPARTS", "IMPEACH",
+    "IMPEDED", "IMPIETY", "IMPIOUS", "IMPLIED", "IMPLIES", "IMPLORE", "IMPOSED", "IMPULSE",
+    "IMPUTED", "INANELY", "INANITY", "INAPTLY", "INCASED", "INCENSE", "INCITED", "INCLINE",
+    "INCUBUS", "INDIANS", "INDITED", "INDOORS", "INDUCED", "INDUCES", "INDUCTS", "INDULGE",
+    "INEPTLY", "INERMIS", "INERTIA", "INERTLY", "INFANCY", "INFANTS", "INFIDEL", "INFLICT",
+    "INFORMS", "INFUSED", "INGENIO", "INHABIT", "INHALED", "INHERIT", "INHUMAN", "INJURED",
+    "INJURES", "INKLING", "INMATES", "INNINGS", "INQUEST", "INQUIRE", "INROADS", "INSECTS",
+    "INSENSE", "INSERTS", "INSIDES", "INSIPID", "INSISTS", "INSPECT", "INSULAR", "INSULTS",
+    "INSURED", "INTENDS", "INTENTS", "INTREAT", "INTRUDE", "INTRUST", "INVADED", "INVALID",
+    "INVENTS", "INVESTS", "INVITED", "INVITES", "INWARDS", "IQUIQUE", "IRATELY", "IRELAND",
+    "IRKSOME", "IRONING", "ISLANDS", "ISOLATE", "ISSUING", "ISTHMUS", "ITALIAN", "ITCHING",
+    "JABBERS", "JACAMAR", "JACKALS", "JACKDAW", "JACKETS", "JACKSON", "JAGUARS", "JAMAICA",
+    "JANEIRO", "JANGLED", "JANUARY", "JARRING", "JASMINE", "JAVELIN", "JAWFISH", "JAYBIRD",
+    "JEALOUS", "JEERING", "JELLYBY", "JENKINS", "JERICHO", "JERKILY", "JERKING", "JESTING",
+    "JEWELER", "JEWFISH", "JEZEBEL", "JINGLED", "JINGLES", "JITTERY", "JOBLESS", "JOBLING",
+    "JOCULAR", "JOGGING", "JOHNNIE", "JOHNSON", "JOINING", "JOINTED", "JOINTLY", "JOLLITY",
+    "JOLTING", "JONESES", "JORULLO", "JOSTLED", "JUDGING", "JUGGLED", "JUGGLER", "JUGULAR",
+    "JUILLET", "JUMBLED", "JUMPING", "JUNGLES", "JUNIORS", "JUPITER", "JURYMAN", "JURYMEN",
+    "JUSTEST", "JUSTIFY", "KALYDOR", "KAMPFES", "KATYDID", "KEATING", "KEELING", "KEENEST",
+    "KEEPERS", "KEEPING", "KENDALL", "KENNELS", "KENTISH", "KENWIGS", "KESTREL", "KETCHUP",
+    "KETTLES", "KEYHOLE", "KICKING", "KILLING", "KINDEST", "KINDLED", "KINDLES", "KINDRED",
+    "KINGDOM", "KINGLET", "KINSMAN", "KISSING", "KITCHIN", "KITTENS", "KNAVISH", "KNEADED",
+    "KNEELED", "KNIGHTS", "KNITTED", "KNOCKED", "KNOCKER", "KNOTTED", "KNOWEST", "KNOWETH",
+    "KNOWING", "KNUCKLE", "KOBELEV", "KRYUKOV", "LABORED", "LABORER", "LABOURS", "LACERTA",
+    "LACKING", "LACONIC", "LADDERS", "LADERAS", "LADYBUG", "LAGGARD", "LAGGING", "LAGOONS",
+    "LAISSEZ", "LAMARCK", "LAMBERT", "LAMBETH", "LAMENTS", "LAMINAE", "LAMPREY", "LANGUID",
+    "LANGUOR", "LANTERN", "LAPSING", "LAPWING", "LARGEST", "LARKING", "LARMING", "LASHING",
+    "LASSOIT", "LATCHED", "LATERAL", "LATTHER", "LATTICE", "LAUGHED", "LAUNDRY", "LAURELS",
+    "LAWSUIT", "LAWYERS", "LAZARUS", "LEADERS", "LEAGUED", "LEAGUES", "LEANING", "LEAPING",
+    "LEARNED", "LEAVING", "LEBANON", "LEDGERS", "LEDROOK", "LEERING", "LEEUWIN", "LEEWARD",
+    "LEGALLY", "LEGATEE", "LEGENDS", "LEGIBLE", "LEGIBLY", "LEISURE", "LEMMING", "LENDERS",
+    "LENDING", "LENGTHS", "LENGTHY", "LENIENT", "LENNING", "LENTILS", "LEOPARD", "LESSONS",
+    "LETTERS", "LETTING", "LETTRES", "LETTUCE", "LEVELLE", "LIBERTY", "LICENCE", "LICHENS",
+    "LICKING", "LIFTING", "LIGHTED", "LIGHTEN", "LIGHTER", "LIGNITE", "LIKENED", "LIKINGS",
+    "LIMACHE", "LIMNAEA", "LIMPING", "LIMPKIN", "LINCOLN", "LINEAGE", "LINGERS", "LININGS",
+    "LINKING", "LINNEAN", "LINNETS", "LINTELS", "LIONESS", "LIPPING", "LIQUORS", "LISTENS",
+    "LITERAL", "LITHELY", "LIVIDLY", "LIZARDS", "LOADING", "LOATHED", "LOBBIES", "LOBSTER",
+    "LOCALLY", "LOCKING", "LOCKYER", "LOCOCKS", "LOCUSTS", "LODGERS", "LODGING", "LOFTILY",
+    "LOGICAL", "LOLLING", "LOMBARD", "LONGEST", "LONGING", "LOODGIN", "LOOKERS", "LOOKING",
+    "LOOMING", "LOOSELY", "LOOSING", "LOPEZES", "LORENZO", "LOTTERY", "LOUDEST", "LOUNGED",
+    "LOUNGER", "LOUNGES", "LOUTISH", "LOUVAIN", "LOVABLE", "LOWERED", "LOWLAND", "LOWNESS",
+    "LOYALLY", "LOYALTY", "LOZENGE", "LUCANEE", "LUCIANO", "LUCIDLY", "LUCIFER", "LUCKILY",
+    "LUDGATE", "LUDSHIP", "LUGGAGE", "LULLABY", "LULLING", "LUMPKIN", "LUNATIC", "LUNCHES",
+    "LURCHED", "LURIDLY", "LURKING", "LUSTIER", "LUSTILY", "LYRICAL", "MACABRE", "MACADAM",
+    "MACAQUE", "MACBETH", "MACHINA", "MACTRAE", "MADCAPS", "MADCHEN", "MADDEST", "MADEIRA",
+    "MADNESS", "MADONNA", "MADRINA", "MAGENTA", "MAGGOTS", "MAGICAL", "MAGNATE", "MAGNIFY",
+    "MAGPIES", "MAHOMET", "MAIDENS", "MAIDISH", "MAILBOX", "MAJESTY", "MALACCA", "MALARIA",
+    "MALDIVA", "MALLARD", "MAMMALS", "MAMMOTH", "MANAGED", "MANAGES", "MANAKIN", "MANATEE",
+    "MANDATE", "MANDIBL", "MANGLES", "MANHOOD", "MANIACS", "MANKIND", "MANNERS", "MANNISH",
+    "MANTLED", "MANTLES", "MANUALS", "MANURED", "MARBLES", "MARCHED", "MARCHES", "MARGATE",
+    "MARGINS", "MARIANO", "MARINES", "MARITAL", "MARKETS", "MARKHOR", "MARKING", "MARQUIS",
+    "MARRIES", "MARRING", "MARROWS", "MARSDEN", "MARSHAL", "MARSHES", "MARTENS", "MARTIAL",
+    "MARTINS", "MARVELS", "MASONRY", "MASTERS", "MASTERY", "MASTIFF", "MATADOR", "MATAVAI",
+    "MATCHED", "MATCHES", "MATILDA", "MATLOCK", "MATRONS", "MATTERS", "MATTHER", "MATTHEW",
+    "MATTING", "MATURED", "MATURER", "MAUNDER", "MAURICE", "MAUVAIS", "MAWKISH", "MAXIMAL",
+    "MAYFAIR", "MAZEPPA", "MAZURKA", "MEADOWS", "MEANEST", "MEASLES", "MEDDLED", "MEDIATE",
+    "MEDUSAE", "MEEKEST", "MEERKAT", "MELLOWS", "MELODIC", "MELTETH", "MELTING", "MEMBERS",
+    "MEMEMTO", "MEMOIRS", "MENDING", "MENDOZA", "MENFION", "MERCERS", "MERCIES", "MERCURY",
+    "MERGING", "MERITED", "MERMAID", "MERRIER", "MERRILY", "MESSIAH", "MESSILY", "METEORS",
+    "METHODS", "MEWLINN", "MEXICAN", "MICHAEL", "MICHELL", "MIGRATE", "MIKOLKA", "MILDEST",
+    "MILDMAY", "MILITIA", "MILKING", "MILKMAN", "MILKPOT", "MILKSOP", "MILLING", "MIMICRY",
+    "MIMOSAE", "MIMOSAS", "MINARET", "MINCING", "MINDFUL", "MINDING", "MINERVA", "MINGLED",
+    "MINGLES", "MINIONS", "MINIVET", "MINSTER", "MINUTES", "MINUTUS", "MIRACLE", "MIRANDA",
+    "MIRRORS", "MISDEED", "MISERLY", "MISGAVE", "MISLAID", "MISLEAD", "MISSILE", "MISSIVE",
+    "MISTHER", "MISTILY", "MISTOOK", "MISUSED", "MISUSES", "MOANING", "MOCKERY", "MOCKING",
+    "MODESTY", "MOISTEN", "MOISTLY", "MOLIERE", "MOLLIFY", "MOLLUSK", "MOMENTS", "MONARCH",
+    "MONDAYS", "MONEYED", "MONGREL", "MONKEYS", "MONSOON", "MONSTER", "MONTHLY", "MOODILY",
+    "MOONEYE", "MOONING", "MOONTHS", "MOORHEN", "MOORISH", "MORAINE", "MORALLY", "MORBURY",
+    "MORESBY", "MORETON", "MOROCCO", "MORSELS", "MORTALS", "MORTIFY", "MOTHERS", "MOTIONS",
+    "MOTIVES", "MOTTLED", "MOTTLES", "MOUFLON", "MOULDED", "MOULDER", "MOUNTED", "MOURNED",
+    "MOURNER", "MOUTHED", "MOVEMEN", "MUDDLED", "MUDDLES", "MUDFISH", "MUDLIKE", "MUFFINS",
+    "MUFFLED", "MUFFLES", "MUGERES", "MULATTO", "MULCTED", "MULLINS", "MUMBLED", "MUMMERY",
+    "MUNCHES", "MUNDANE", "MURDERS", "MURIATE", "MURINUS", "MURKILY", "MURMURS", "MUSCLES",
+    "MUSKETS", "MUSKRAT", "MUSLINS", "MUSTANG", "MUSTARD", "MUTTERS", "MYLODON", "MYRIADS",
+    "MYSTIFY", "MYTILUS", "NAIVELY", "NAKEDLY", "NANKEEN", "NARROWS", "NARWHAL", "NASALLY",
+    "NASTILY", "NATALYA", "NATERAL", "NATIONS", "NATIVES", "NATTILY", "NATURED", "NATURES",
+    "NAUGHTY", "NAVARIN", "NAVEDAD", "NEAMING", "NEAREST", "NEATEST", "NECKETT", "NECKTIE",
+    "NEEBURS", "NEEDFUL", "NEEDING", "NEGLECT", "NEGRESS", "NEGROES", "NEPHEWS", "NERVING",
+    "NERVOUS", "NERVURE", "NESTLED", "NETTLED", "NETTLES", "NEUTRAL", "NEWBORN", "NEWGATE",
+    "NEWNESS", "NEWSMEN", "NICOLAS", "NIGGERS", "NIGHTLY", "NIKODIM", "NIKOLAY", "NINEPIN",
+    "NINETTA", "NINNIES", "NIPPING", "NITRATE", "NIVALIS", "NOABODY", "NOBLEST", "NOBODYS",
+    "NODDIES", "NODDING", "NODULES", "NOISIER", "NOISILY", "NOISOME", "NOKOLAY", "NOLASKO",
+    "NOMINAL", "NOMINEE", "NONSTOP", "NOODLES", "NOONDAY", "NORONHA", "NOSEGAY", "NOSTRIL",
+    "NOTABLE", "NOTABLY", "NOTCHED", "NOTCHES", "NOTHINK", "NOTHURA", "NOTICED", "NOTICES",
+    "NOTIONS", "NOURISH", "NOVELTY", "NOVICES", "NOXIOUS", "NUCLEUS", "NUDGING", "NUMBERS",
+    "NUNNERY", "NUPTIAL", "NURSERY", "NURSING", "NURTURE", "OARFISH", "OARSMAN", "OATMEAL",
+    "OBELISK", "OBESITY", "OBEYETH", "OBEYING", "OBJECTS", "OBLEEGE", "OBLIGED", "OBLIGES",
+    "OBLIQUE", "OBSCENE", "OBSCURE", "OBSERVA", "OBTAINS", "OCEANIC", "OCTAVIA", "OCTOBER",
+    "OCTOPUS", "ODDNESS", "OFFBEAT", "OFFENCE", "OFFENDS", "OFFERED", "OFFICES", "OFTENER",
+    "OGREISH", "OILBIRD", "OILSKIN", "OLDWIFE", "OLOGIES", "OLYMPIC", "OLYMPUS", "OMINOUS",
+    "OMITTED", "OMNIBUS", "ONENESS", "ONEROUS", "ONESELF", "ONISCIA", "ONWARDS", "OOTSIDE",
+    "OPENING", "OPINING", "OPINLON", "OPOSSUM", "OPPOSED", "OPPRESS", "OPTICAL", "OPTIMAL",
+    "OPTIMUM", "OPULENT", "OPUNTIA", "ORAISON", "ORANGES", "ORATION", "ORATORS", "ORATORY",
+    "ORBIGNY", "ORBINGY", "ORCHARD", "ORCHIDS", "ORDERED", "ORDERLY", "ORIFICE", "ORINOCO",
+    "ORLANDO", "ORPHANS", "ORPHEUS", "ORYCTES", "OSSEOUS", "OSTLERS", "OSTRICH", "OTHELLO",
+    "OTHERED", "OTTOMAN", "OUSTING", "OUTCAST", "OUTDONE", "OUTLAWS", "OUTLETS", "OUTLINE",
+    "OUTLIVE", "OUTLOOK", "OUTRAGE", "OUTSTEP", "OUTWARD", "OVARIUM", "OVERAWE", "OVERDID",
+    "OVERDUE", "OVERRUN", "OVERSET", "OVERTLY", "OXIDATE", "OXYURUS", "OYSTERS", "PACIFIC",
+    "PACKETS", "PACKING", "PADDLED", "PADDLES", "PADDOCK", "PADLOCK", "PAGEANT", "PAINFUL",
+    "PAINTED", "PAIRING", "PALACES", "PALADIN", "PALATES", "PALINGS", "PAMPEAN", "PANACEA",
+    "PANCAKE", "PANICKY", "PANOPLY", "PANSIES", "PANTHER", "PANTING", "PAPERED", "PAPIETE",
+    "PAPILIO", "PARABLE", "PARADED", "PARADES", "PARADOX", "PARAGON", "PARAPET", "PARASHA",
+    "PARASOL", "PARCELS", "PARCHED", "PARDONS", "PARENTS", "PARKERS", "PARLOUR", "PARRIED",
+    "PARROTS", "PARSLEY", "PARTAKE", "PARTIAL", "PARTIES", "PARTING", "PARTOOK", "PARVULA",
+    "PASSEES", "PASSERS", "PASSING", "PASSIVE", "PASTHRY", "PASTIME", "PASTORS", "PASTURE",
+    "PATCHED", "PATCHES", "PATHWAY", "PATRICK", "PATRIOT", "PATRONS", "PATTENS", "PATTING",
+    "PAUCITY", "PAULINE", "PAUNCHY", "PAUPERS", "PAUSING", "PAVIOUR", "PAYABLE", "PAYPOTE",
+    "PEACHES", "PEACOCK", "PEAFOWL", "PEASANT", "PEBBLES", "PECCARI", "PECCARY", "PECKING",
+    "PECORIS", "PEDLARS", "PEELING", "PEEPING", "PEERAGE", "PEERING", "PEEVISH", "PEEWITS",
+    "PEGASUS", "PELAGIC", "PELICAN", "PELISSE", "PELTING", "PENANCE", "PENCILS", "PENDANT",
+    "PENDING", "PENGUIN", "PENSIVE", "PEOPLED", "PEOPLES", "PERCENT", "PERCHED", "PERCHES",
+    "PERFIDY", "PERFUME", "PERIODS", "PERJURY", "PERKILY", "PERKINS", "PERNETY", "PERPLEX",
+    "PERSIAN", "PERSIST", "PERSONS", "PERUSAL", "PERUSED", "PERVADE", "PETISES", "PETORCA",
+    "PETRELS", "PETTILY", "PETTING", "PETTISH", "PHAETON", "PHALLUS", "PHANTOM", "PHARAOH",
+    "PHINEAS", "PHOENIX", "PHRASED", "PHRASES", "PHYSIOL", "PICKAXE", "PICKERS", "PICKING",
+    "PICKLED", "PICKLES", "PICNICS", "PIDERIT", "PIEBALD", "PIERCED", "PIERCES", "PIERRES",
+    "PIETRAS", "PIGEONS", "PIGFISH", "PIGMIES", "PIGTAIL", "PILGRIM", "PILLARS", "PILLOWS",
+    "PILOTED", "PIMLICO", "PIMPLES", "PINCERS", "PINCHED", "PINCHES", "PINIONS", "PINKISH",
+    "PINNACE", "PINNING", "PINTAIL", "PIOUSLY", "PIQUANT", "PIRANHA", "PISAGUA", "PISTOLS",
+    "PITCHED", "PITCHER", "PITEOUS", "PITFALL", "PITHILY", "PITIFUL", "PITYING", "PIVOTAL",
+    "PIZZARO", "PLACARD", "PLACING", "PLAGUED", "PLAGUES", "PLAINER", "PLAINLY", "PLAISIR",
+    "PLAITED", "PLANING", "PLANNED", "PLANTED", "PLANTER", "PLASTER", "PLATEAU", "PLATINA",
+    "PLAYERS", "PLAYFUL", "PLAYING", "PLEADED", "PLEADER", "PLEASES", "PLEDGED", "PLEDGES",
+    "PLIABLE", "PLICATA", "PLOTTED", "PLOTTER", "PLOUGHS", "PLUCKED", "PLUMAGE", "PLUMING",
+    "PLUNDER", "PLUNGED", "PLUNGES", "POACHED", "POACHER", "POCHARD", "POCKETS", "POINTED",
+    "POINTER", "POISING", "POKOREV", "POLANCO", "POLECAT", "POLENKA", "POLITIC", "POLKING",
+    "POLLARD", "POLLEWT", "POLLING", "POLLOCK", "POLLUTE", "POLYGON", "POLYPUS", "POMADED",
+    "POMARRE", "POMATUM", "POMFRET", "POMPANO", "POMPOUS", "PONCHOS", "PONDERS", "PONIARD",
+    "POONDED", "POOREST", "POPCORN", "POPLARS", "POPPING", "PORCHES", "PORFIRY", "PORTEND",
+    "PORTERS", "PORTICO", "POSSESS", "POSTAGE", "POSTERN", "POSTERS", "POSTING", "POSTMAN",
+    "POSTMEN", "POSTURE", "POTOROO", "POTRERO", "POTTERY", "POUCHES", "POULTRY", "POUNCED",
+    "POUNCES", "POUNDED", "POUNDER", "POURING", "POUTING", "PRAISED", "PRAISES", "PRASANT",
+    "PRAYERS", "PRAYING", "PRECEDE", "PRECEPT", "PREFACE", "PREFERS", "PRELUDE", "PREMISE",
+    "PRESIDE", "PRESSED", "PRESSES", "PRESUME", "PRETEND", "PRETEXT", "PREVAIL", "PREVOST",
+    "PREYING", "PRICKED", "PRICKLY", "PRIESTS", "PRIMATE", "PRIMERA", "PRINCES", "PRINTED",
+    "PRISONS", "PROBING", "PROCURE", "PRODIGY", "PROFANE", "PROFESS", "PROFFER", "PROFITS",
+    "PROFUSE", "PROLONG", "PROMOTE", "PROMPTS", "PRONOUN", "PROPHET", "PROPOSE", "PROPPED",
+    "PROSAIC", "PROSING", "PROSPER", "PROTEUS", "PROUDER", "PROUDLY", "PROVERB", "PROVING",
+    "PROVISO", "PROVOKE", "PROWESS", "PROWLED", "PRUDENT", "PRUDERY", "PRUDISH", "PRUNING",
+    "PRUSSIC", "PSYCHIC", "PUDDICK", "PUDDING", "PUDDLES", "PUFFING", "PULLING", "PUMPING",
+    "PUMPKIN", "PUNCHED", "PUNCHES", "PUNDITS", "PUPPIES", "PURCHAS", "PURPORT", "PURPURA",
+    "PURRING", "PURSING", "PURSUED", "PURSUER", "PURSUES", "PURSUIT", "PURSULT", "PUSHING",
+    "PUSHKIN", "PUTTING", "PUZZLED", "PUZZLES", "PYRAMID", "PYRITES", "PYROSMA", "QUACCHA",
+    "QUADRAS", "QUAILED", "QUAKING", "QUANTUM", "QUARREL", "QUEDIUS", "QUEENLY", "QUEERLY",
+    "QUELLED", "QUERIES", "QUETZAL", "QUIBBLE", "QUICKEN", "QUICKER", "QUIETED", "QUIETER",
+    "QUIETLY", "QUILLAY", "QUILMES", "QUINTUS", "QUITTED", "QUIVERS", "QUIXOTE", "QUONDAM",
+    "QUOODLE", "QUOTING", "RABBITS", "RABIDLY", "RACCOON", "RACHAEL", "RACKING", "RADIANT",
+    "RADIATA", "RADIATE", "RAFTERS", "RAILING", "RAINBOW", "RAINING", "RAISING", "RAISINS",
+    "RALLIED", "RAMBLED", "RAMBLES", "RAMIREZ", "RAMPANT", "RAMPART", "RAMSDEN", "RANCHOS",
+    "RANCOUR", "RANDALL", "RANGEES", "RANGING", "RANKLED", "RANSACK", "RAPHAEL", "RAPIDLY",
+    "RAPPING", "RAPTURE", "RASCALS", "RATIONS", "RATTAIL", "RATTLED", "RATTLER", "RATTLES",
+    "RAVAGED", "RAVAGES", "RAVINES", "RAVINGS", "REACHED", "REACHES", "READERS", "READIED",
+    "READING", "REALISE", "REALIZE", "REAPING", "REARING", "REASONS", "REAUMUR", "REBOUND",
+    "REBUILD", "REBUILT", "REBUKED", "RECALLS", "RECEDED", "RECEIPT", "RECIPRO", "RECITAL",
+    "RECITED", "RECLAIM", "RECLUSE", "RECOILS", "RECORDS", "RECOUNT", "RECROSS", "RECRUIT",
+    "RECTIFY", "RECTORY", "RECYCLE", "REDBIRD", "REDDEST", "REDDISH", "REDFISH", "REDHEAD",
+    "REDOUND", "REDPOLL", "REDRESS", "REDUCED", "REDUCES", "REEKING", "REELING", "REFINED",
+    "REFINES", "REFOLDS", "REFORMS", "REFRAIN", "REFRESH", "REFUSAL", "REFUSED", "REFUSES",
+    "REGAINS", "REGALED", "REGALLY", "REGARDS", "REGIMEN", "REGIONS", "REGRETS", "REIGNED",
+    "REINING", "REJECTS", "REJOICE", "REJOINS", "RELAPSE", "RELATES", "RELAXED", "RELAXES",
+    "RELENTS", "RELIANT", "RELIEFS", "RELIEVE", "RELYING", "REMAINE", "REMANDS", "REMARKS",
+    "REMINDS", "REMNANT", "REMORSE", "REMOVED", "REMOVES", "REMPLIR", "RENDERS", "RENDING",
+    "RENEWAL", "RENEWED", "RENGGER", "REPAIRS", "REPEATS", "REPENTS", "REPINED", "REPINES",
+    "REPLETE", "REPLIED", "REPLIES", "REPORTS", "REPOSED", "REPOSES", "REPRESS", "REPRINT",
+    "REPROOF", "REPROVE", "REPTILE", "REPULSE", "REPUTED", "REQUIEM", "REQUITE", "RESCUED",
+    "RESENTS", "RESIDED", "RESIDES", "RESIDUE", "RESIGNS", "RESISTS", "RESOLED", "RESORTS",
+    "RESOUND", "RESPITE", "RESTFUL", "RESTING", "RESTIVE", "RESULTS", "RESUMED", "RESUMES",
+    "RETAINS", "RETCHES", "RETINUE", "RETIRED", "RETIRES", "RETORED", "RETORTS", "RETRACE",
+    "RETRACT", "RETURNS", "REUNION", "REVELRY", "REVENGE", "REVERED", "REVERIE", "REVERTS",
+    "REVIEWS", "REVILED", "REVISIT", "REVIVAL", "REVIVED", "REVOKED", "REVOLTS", "REVOLVE",
+    "REWARDS", "RHUBARB", "RIBBONS", "RIBEIRA", "RICHARD", "RICHEST", "RICKETY", "RIDDING",
+    "RIDDLES", "RIFLING", "RIGGERS", "RIGGING", "RIGHTED", "RIGHTLY", "RIGIDLY", "RIGUEUR",
+    "RINGING", "RINSING", "RIOTOUS", "RIPENED", "RIPPLED", "RIPPLES", "RISIBLE", "RISKING",
+    "RIVALRY", "RIVETED", "RIVIERE", "RIVULET", "ROAMING", "ROARING", "ROASTED", "ROBBERS",
+    "ROBBERY", "ROBBING", "ROBERTO", "ROCKETS", "ROCKING", "RODENTS", "ROGUERY", "ROLLING",
+    "ROMANCE", "ROMPING", "ROOKERY", "ROOSHER", "ROOSTER", "ROOTETH", "ROSEATE", "ROSEBUD",
+    "ROSINAS", "ROTTING", "ROUBLES", "ROUGHER", "ROUNDED", "ROUNDER", "ROUNDLY", "ROUSING",
+    "ROWDILY", "ROWLAND", "ROWLING", "ROYALLY", "ROYALTY", "ROYSTON", "ROZARIO", "RUBBERS",
+    "RUBBING", "RUBBISH", "RUDDIER", "RUFFIAN", "RUFFLED", "RUINING", "RUINOUS", "RUMBLED",
+    "RUMMAGE", "RUMMEST", "RUMOURS", "RUNAWAY", "RUPTURE", "RUSHING", "RUSSELL", "RUSSIAN",
+    "RUSTILY", "RUSTING", "RUSTLED", "SABBATH", "SACKING", "SADDLED", "SADDLER", "SADNESS",
+    "SAFFRON", "SAILING", "SAILORS", "SAINTED", "SAINTLY", "SALINAS", "SALINUS", "SALLIED",
+    "SALLIES", "SALOONS", "SALTING", "SALTIRE", "SALUTED", "SALUTES", "SAMOVAR", "SAMPLER",
+    "SAMPLES", "SANCTUM", "SANDALS", "SANGSBY", "SAPIENT", "SAPLING", "SARACEN", "SARCASM",
+    "SARDINE", "SATOSHI", "SATRAPS", "SAUCERS", "SAUCILY", "SAUNTER", "SAUSAGE", "SAVAGES",
+    "SAVINGS", "SAVIOUR", "SAVOURS", "SAVOURY", "SAWDUST", "SAWFISH", "SAYINGS", "SCALDED",
+    "SCALING", "SCALLOP", "SCAMPER", "SCANDAL", "SCANNED", "SCARCEL", "SCARCER", "SCARLET",
+    "SCARRED", "SCATTER", "SCENERY", "SCENTED", "SCEPTIC", "SCHEMER", "SCHEMES", "SCHOLAR",
+    "SCHOOLS", "SCHWACH", "SCISSOR", "SCLATER", "SCOFFED", "SCOLDED", "SCOOPED", "SCORIAE",
+    "SCORNED", "SCOURED", "SCOURGE", "SCOUTED", "SCOWLED", "SCRAPED", "SCRAPER", "SCRAPES",
+    "SCRATCH", "SCRAWLS", "SCRAWNY", "SCREAMS", "SCREENS", "SCREWED", "SCROLLS", "SCRUBBS",
+    "SCRUBBY", "SCRUPLE", "SCUDDED", "SCUFFLE", "SCULPIN", "SCUTTLE", "SEAFOWL", "SEAGULL",
+    "SEALERS", "SEALING", "SEAPORT", "SEASIDE", "SEASONS", "SEATING", "SEAWARD", "SEAWEED",
+    "SECLUDE", "SECONDS", "SECRECY", "SECRETE", "SECRETS", "SECULAR", "SECURED", "SECURES",
+    "SEDUCED", "SEDUCER", "SEEKERS", "SEEKING", "SEEMING", "SEETZEN", "SEIZING", "SEIZURE",
+    "SELFISH", "SELLERS", "SELLING", "SEMINAL", "SEMINAR", "SENATOR", "SENDING", "SENIORS",
+    "SENSUAL", "SERFDOM", "SERIEMA", "SERPENT", "SERVANT", "SERVILE", "SERVING", "SETTLED",
+    "SETTLER", "SETTLES", "SEVENTY", "SEVERED", "SEVERER", "SHADING", "SHADOWS", "SHADOWY",
+    "SHAKILY", "SHAKING", "SHALLOW", "SHAPELY", "SHAPING", "SHARING", "SHARMER", "SHARPEN",
+    "SHARPER", "SHARPLY", "SHAVING", "SHEAVES", "SHELLEY", "SHELVES", "SHIELDS", "SHIFTED",
+    "SHINGLE", "SHINING", "SHIPPED", "SHIPTON", "SHIRKED", "SHIVERS", "SHOALER", "SHOCKED",
+    "SHOOHOO", "SHOPMAN", "SHOPMEN", "SHORTEN", "SHORTER", "SHORTLY", "SHOULDN", "SHOUTED",
+    "SHOVING", "SHOWERS", "SHOWERY", "SHOWILY", "SHOWING", "SHRIEKS", "SHRIKES", "SHRILLY",
+    "SHRIMPS", "SHRINKS", "SHRIVEL", "SHUDDER", "SHUFFLE", "SHUFLLE", "SHUNNED", "SHUTTER",
+    "SHYLOCK", "SHYNESS", "SIAMESE", "SIBERIA", "SIBLING", "SICKENS", "SIDEWAY", "SIDINGS",
+    "SIDLING", "SIGHING", "SIGHTED", "SIGNALS", "SIGNIFY", "SIGNING", "SILLIER", "SILLIES",
+    "SILURUS", "SILVERY", "SIMILES", "SIMPLER", "SIMPLEX", "SIMPLON", "SIMPSON", "SINCERE",
+    "SINGERS", "SINGING", "SINGULA", "SINKING", "SINLESS", "SINNERS", "SINNING", "SINUOUS",
+    "SIPPING", "SIROCCO", "SISTERS", "SISTINE", "SITTERS", "SITUATE", "SIXIEME", "SIXTEEN",
+    "SIXTHLY", "SKATERS", "SKATING", "SKELETO", "SKIDDAW", "SKILFUL", "SKILLED", "SKIMMED",
+    "SKIMMER", "SKINNED", "SKIPPED", "SKIPPER", "SKIRTED", "SKITTLE", "SKUTTLE", "SKYLARK",
+    "SLACKEN", "SLACKLY", "SLAMMED", "SLANDER", "SLAPPED", "SLAVERY", "SLAVISH", "SLEEKLY",
+    "SLEEPER", "SLEEVED", "SLEEVES", "SLEIGHT", "SLENDER", "SLICING", "SLICKLY", "SLIDERS",
+    "SLIDING", "SLIGHTS", "SLIPPED", "SLIPPER", "SLOCOMB", "SLOPING", "SLUICES", "SLUMBER",
+    "SLYNESS", "SMACKED", "SMALLER", "SMARTLY", "SMASHED", "SMEARED", "SMELTED", "SMIFSER",
+    "SMILING", "SMITING", "SMITTEN", "SMOKING", "SMOOTHS", "SMUGGLE", "SNAGSBY", "SNAPPED",
+    "SNAPPER", "SNARLED", "SNAWLEY", "SNEAKED", "SNEERED", "SNEEZED", "SNEWKES", "SNIDELY",
+    "SNIFFED", "SNIGGER", "SNITTLE", "SNORING", "SNORTED", "SNOWING", "SNUBBED", "SNUFFED",
+    "SNUFFIM", "SOAKING", "SOARING", "SOBBING", "SOBERED", "SOBERLY", "SOCKETS", "SOFTENS",
+    "SOFTEST", "SOIREES", "SOJOURN", "SOLACED", "SOLACES", "SOLIDLY", "SOLOMON", "SOLUBLE",
+    "SOLVENT", "SOMEDAY", "SOMEONE", "SOMEWAY", "SONDERN", "SONNETS", "SOODDEN", "SOOFFER",
+    "SOONEST", "SOOTHED", "SORCERY", "SORROWS", "SORTING", "SOUNDED", "SOUNDER", "SOUNDLY",
+    "SOURCES", "SOURING", "SOUSING", "SOWERBY", "SPANIEL", "SPANISH", "SPANNED", "SPARELY",
+    "SPARING", "SPARKLE", "SPARROW", "SPARTAN", "SPATIAL", "SPEAKIN", "SPECIES", "SPECIFY",
+    "SPECTRE", "SPENCER", "SPHERES", "SPICULA", "SPIDERS", "SPILLED", "SPINACH", "SPINDLE",
+    "SPIRITS", "SPIRTED", "SPOILED", "SPONGEY", "SPORTED", "SPOTTED", "SPRAWLS", "SPREADS",
+    "SPRINGS", "SPROUTS", "SPURNED", "SPURRED", "SQUALID", "SQUALLS", "SQUALLY", "SQUALOR",
+    "SQUARED", "SQUARER", "SQUARES", "SQUEALS", "SQUEERS", "SQUEERY", "SQUEEZE", "SQUIRES",
+    "STABBED", "STABLED", "STABLES", "STADIUM", "STAGGER", "STAIDLY", "STAINED", "STAKING",
+    "STALKED", "STAMENS", "STAMMER", "STAMPED", "STARING", "STARKLY", "STARRED", "STARTED",
+    "STARTLE", "STARVED", "STARVIN", "STATELY", "STATING", "STATUES", "STATURE", "STATUTE",
+    "STAUNCH", "STAVING", "STAYING", "STEALTH", "STEAMED", "STEAMER", "STEELED", "STEEPED",
+    "STEEPER", "STEEPLE", "STEEPLY", "STEERED", "STELLAR", "STEPPED", "STEPPES", "STEPSON",
+    "STERILE", "STERNER", "STERNLY", "STEWARD", "STEWART", "STEWING", "STICKER", "STIFFER",
+    "STIFFLY", "STIFLED", "STIPEND", "STIRRED", "STIRRUP", "STOCKED", "STOMACH", "STONILY",
+    "STOOPED", "STOPPED", "STOPPER", "STOREYS", "STORIED", "STORIES", "STORING", "STORMED",
+    "STOUTER", "STOUTLY", "STRAINS", "STRAITS", "STRATUM", "STRAYED", "STREAKS", "STREAMS",
+    "STREETS", "STREWED", "STRIDES", "STRIKES", "STRINGS", "STRIPED", "STRIPES", "STRIVEN",
+    "STROKED", "STROKES", "STROLLS", "STUBBLE", "STUBBLY", "STUDDED", "STUDIES", "STUFFED",
+    "STUMBLE", "STUNNED", "STUNTED", "STUPIDS", "STYLISH", "SUADIVA", "SUAVELY", "SUAVITY",
+    "SUBACID", "SUBDUED", "SUBDUER", "SUBDUES", "SUBLIME", "SUBMITS", "SUBSIDE", "SUBSIST",
+    "SUBSOIL", "SUBTILE", "SUBURBS", "SUCCOUR", "SUCKERS", "SUCKING", "SUCTION", "SUDDENL",
+    "SUFFERS", "SUFFICE", "SUFFOLK", "SUICIDE", "SUITING", "SUITORS", "SULIVAN", "SULKILY",
+    "SULPHUR", "SUMATRA", "SUMMERS", "SUMMING", "SUMMITS", "SUMMONS", "SUNBEAM", "SUNBIRD",
+    "SUNDAYS", "SUNFISH", "SUNNING", "SUNRISE", "SUNSETS", "SUPPERS", "SURGEON", "SURGING",
+    "SURMISE", "SURNAME", "SURPASS", "SURVEYS", "SURWEYS", "SVIRBEY", "SWAGGER", "SWALLER",
+    "SWALLOW", "SWANSEA", "SWARMED", "SWARTHY", "SWAYING", "SWEATER", "SWEEPER", "SWEETEN",
+    "SWEETER", "SWEETIE", "SWEETLY", "SWELLED", "SWERVED", "SWIFTER", "SWIFTLY", "SWINDLE",
+    "SWOLLEN", "SWOONED", "SWOOPED", "SWOSSER", "SYENITE", "SYMBOLS", "SYMONDS", "SYMPTOM",
+    "SYRPHUS", "SYSTEMS", "TABANUS", "TABLEAU", "TABOOED", "TACITLY", "TACKING", "TACTFUL",
+    "TACTICS", "TADPOLE", "TAILORS", "TAINTED", "TALENTS", "TALGUEN", "TALKERS", "TALKING",
+    "TALLEST", "TALLIED", "TAMARIN", "TAMBOUR", "TAMPERS", "TANAGER", "TANDEEL", "TANGLED",
+    "TANKARD", "TAPPING", "TAPSTER", "TARDILY", "TARGETS", "TARNISH", "TARSIER", "TARTARY",
+    "TASKING", "TASSELS", "TASTING", "TATTERS", "TATTLER", "TAUNTED", "TAUNTON", "TAVERNS",
+    "TEACHES", "TEACUPS", "TEARFUL", "TEARING", "TEASING", "TEATIME", "TEDIOUS", "TEEMING",
+    "TELLING", "TEMPERS", "TEMPEST", "TEMPLES", "TEMPTED", "TEMPTER", "TENABLE", "TENANTS",
+    "TENDING", "TENFOLD", "TENSELY", "TENUOUS", "TERCERO", "TEREBRA", "TEREWTH", "TERRACE",
+    "TERRIER", "TERRIFY", "TERRORS", "TERSELY", "TESTIFY", "TESTILY", "TESTUDO", "TEUTONS",
+    "TEXTURE", "THANKED", "THANKEE", "THANYOU", "THAVIES", "THAWING", "THEATRE", "THEORIE",
+    "THEREIN", "THEREOF", "THEREON", "THERESA", "THERETO", "THICKEN", "THICKER", "THICKET",
+    "THICKLY", "THIEVED", "THIEVES", "THIMBLE", "THINKER", "THINKIN", "THINNED", "THINNER",
+    "THIRDLY", "THIRSTY", "THISTLE", "THITHER", "THOUGHY", "THREADS", "THREATS", "THRILLS",
+    "THRIVEN", "THRIVES", "THROATS", "THRONGS", "THROWED", "THRUSTS", "THUMBED", "THUMPED",
+    "THUNDER", "THYSELF", "TIAAURU", "TICKETS", "TICKING", "TICKLED", "TICKLES", "TIDDLER",
+    "TIDINGS", "TIGHTEN", "TIGHTER", "TIGHTLY", "TIGRESS", "TILAPIA", "TILLAGE", "TILTING",
+    "TIMBERS", "TIMBREL", "TIMIDLY", "TIMOTHY", "TINAMOU", "TINAMUS", "TINGLED", "TINIEST",
+    "TINKERS", "TINKLED", "TINTING", "TIPPETS", "TIPSILY", "TIREDLY", "TITLARK", "TITULAR",
+    "TOASTED", "TODDLER", "TOILING", "TOLLING", "TOMKINS", "TONGUED", "TONGUES", "TOOTHED",
+    "TOOTING", "TOPICAL", "TOPPING", "TOPSAIL", "TORCHES", "TORMENT", "TORNADO", "TORRENT",
+    "TORTURE", "TORYISM", "TOSSING", "TOTANUS", "TOUCANS", "TOUCHED", "TOUCHER", "TOUCHES",
+    "TOUGHER", "TOUGHEY", "TOUGHLY", "TOWERED", "TOXODON", "TRACERY", "TRACING", "TRACKED",
+    "TRADERS", "TRADING", "TRAILED", "TRAINED", "TRAINER", "TRAITOR", "TRAMPED", "TRAMPLE",
+    "TRANSIT", "TRAPPED", "TRAVELS", "TREACES", "TREACLE", "TREADIN", "TREASON", "TREASUR",
+    "TREATED", "TREBLED", "TRELLIS", "TREMBLE", "TREMORS", "TRENHAM", "TRESSES", "TRIBUTE",
+    "TRICKED", "TRIFLED", "TRIFLES", "TRIGGER", "TRILLED", "TRIMMED", "TRINITY", "TRINKET",
+    "TRIPODS", "TRIPPED", "TRISTAN", "TRITELY", "TRIUMPH", "TRIVETS", "TRIVIAL", "TROCHUS",
+    "TRODDEN", "TROOPED", "TROOPER", "TROPICS", "TROTTED", "TROUGHS", "TROUSER", "TROWELS",
+    "TRUDGED", "TRUDGES", "TRUMPET", "TRUSSES", "TRUSTED", "TSCHUDI", "TSUBMIT", "TUATARA",
+    "TUCKING", "TUCUMAN", "TUESDAY", "TUGGING", "TUITION", "TUMBLED", "TUMBLER", "TUMBLES",
+    "TUNEFUL", "TUNNELS", "TURBANS", "TURKISH", "TURMOIL", "TURNING", "TURNIPS", "TURNOUT",
+    "TURRETS", "TURTLES", "TUTBURY", "TWELFTH", "TWINING", "TWINKLE", "TWIRLED", "TWISTED",
+    "TWITTED", "TWITTER", "TWOFOLD", "TWOPENN", "TYERMAN", "TYRANNY", "TYRANTS", "UGLIEST",
+    "UMSONST", "UNAIDED", "UNARMED", "UNASKED", "UNAWARE", "UNBOUND", "UNCANNY", "UNCARED",
+    "UNCHAIN", "UNCHILD", "UNCIVIL", "UNCLASP", "UNCLEAN", "UNCLOSE", "UNCOILE", "UNCOILS",
+    "UNCOUTH", "UNCOVER", "UNCTION", "UNDERGO", "UNDOING", "UNDRAWS", "UNDRESS", "UNDYING",
+    "UNEQUAL", "UNFOLDS", "UNFRESH", "UNGODLY", "UNGROWN", "UNHAPPY", "UNHEARD", "UNICORN",
+    "UNIFIED", "UNITIES", "UNITING", "UNKEMPT", "UNKIVER", "UNLEARN", "UNLOCKS", "UNLOVED",
+    "UNLUCKY", "UNMANLY", "UNMIXED", "UNMOVED", "UNNAMED", "UNOWNED", "UNPAVED", "UNQUIET",
+    "UNRAVEL", "UNTAMED", "UNTAXED", "UNTRIED", "UNTYING", "UNWOUND", "UNWRUNG", "UPBRAID",
+    "UPRIGHT", "UPSTART", "UPTIGHT", "UPWARDS", "URGENCY", "URINARY", "URUGUAY", "USBORNE",
+    "USELESS", "USHERED", "USUALLY", "USURERS", "UTILIZE", "UTOPIAN", "UTTERED", "UTTERLY",
+    "VACANCY", "VACATED", "VACUOUS", "VAGRANT", "VAGUELY", "VAINEST", "VALIANT", "VALIDLY",
+    "VALLEYS", "VAMPIRE", "VANESSA", "VAPOURS", "VAPOURY", "VARENTS", "VARNISH", "VARYING",
+    "VASSILY", "VAULTED", "VEERING", "VEINOUS", "VELVETS", "VENALLY", "VENDACE", "VENDOME",
+    "VENDORS", "VENTANA", "VENTING", "VENTNOR", "VERANDA", "VERBENA", "VERBOSE", "VERDANT",
+    "VERDICT", "VERDURE", "VERGING", "VERULAM", "VESSELS", "VESTIGE", "VIBRANT", "VIBRATE",
+    "VICIOUS", "VICTIMS", "VIEWING", "VILINCO", "VILLAIN", "VINCENT", "VINEGAR", "VINOQUE",
+    "VINTAGE", "VINTEMS", "VIOLATE", "VIOLETS", "VIOLINS", "VIRTUES", "VISAGED", "VISHERA",
+    "VISIBLY", "VISIONS", "VISITED", "VITALLY", "VITHOUT", "VITTLES", "VIVIDLY", "VIVORAS",
+    "VOCALLY", "VOLCANO", "VOLLEYS", "VOLUBLE", "VOLUBLY", "VOLUMES", "VOLUTAS", "VOUCHES",
+    "VOYAGER", "VOYAGES", "VULTURE", "WADDING", "WAFERED", "WAGGING", "WAGGISH", "WAGGONS",
+    "WAGTAIL", "WAILING", "WAIMATE", "WAIOMIO", "WAISTED", "WAITERS", "WAITHER", "WAITING",
+    "WAKEFUL", "WAKENED", "WALKETH", "WALKING", "WALLABY", "WALLEYE", "WALPOLE", "WALTING",
+    "WALTZED", "WALTZES", "WANDERS", "WANNESS", "WANTING", "WARBLER", "WARDING", "WARFARE",
+    "WARKING", "WARLIKE", "WARMEST", "WARMING", "WARNING", "WARRANT", "WARRENS", "WARRING",
+    "WARRIOR", "WARTHOG", "WARWICK", "WASHERY", "WASHING", "WASTING", "WATCHED", "WATCHER",
+    "WATCHES", "WATERED", "WATKINS", "WAVERED", "WAXBILL", "WAXWING", "WAYLAID", "WAYSIDE",
+    "WAYWARD", "WEAKEST", "WEANING", "WEAPONS", "WEARERS", "WEARIED", "WEARILY", "WEARING",
+    "WEAVERS", "WEAVING", "WEBSTER", "WEDLOCK", "WEEDING", "WEEPERS", "WEEPING", "WEIGHED",
+    "WEIGHTS", "WEIGHTY", "WEIRDLY", "WENCHES", "WENDING", "WEPPING", "WETTEST", "WETTING",
+    "WHALERS", "WHALERY", "WHALING", "WHARTON", "WHARVES", "WHEELED", "WHEREAT", "WHEREBY",
+    "WHEREIN", "WHEREOF", "WHEREON", "WHEREVE", "WHIMPER", "WHINING", "WHIPPED", "WHIPPER",
+    "WHIPPET", "WHIRLED", "WHISKED", "WHISKER", "WHISPER", "WHISTLE", "WHITEST", "WHITHER",
+    "WHITING", "WHITISH", "WHOEVER", "WICIOUS", "WICKHAM", "WIDENED", "WIDOWED", "WIDOWER",
+    "WIELDED", "WIGMORE", "WIGWAMS", "WILDCAT", "WILDEST", "WILHEIM", "WILLAGE", "WILLIAM",
+    "WILLOWS", "WINCING", "WINDERS", "WINDING", "WINDOWS", "WINDSOR", "WINKING", "WINKLES",
+    "WINSOME", "WINTERS", "WISHFUL", "WISHING", "WISITIN", "WISTFUL", "WITCHES", "WITHERS",
+    "WITTIER", "WITTILY", "WITTLES", "WIZENED", "WOFULLY", "WOMANLY", "WONDERS", "WOODCOT",
+    "WOODCUT", "WOODMAN", "WOOLLEN", "WOOLLYA", "WORDING", "WORKBOX", "WORKERS", "WORKING",
+    "WORKMAN", "WORKMEN", "WORLDLY", "WORMING", "WORRIES", "WORSHIP", "WORSTED", "WOUNDED",
+    "WRANGLE", "WRAPPED", "WRAPPER", "WREAKED", "WREATHS", "WRECKED", "WRESTED", "WRESTLE",
+    "WRINKLE", "WRITERS", "WRITHED", "WRONGED", "WRONGLY", "WROUGHT", "WRYNECK", "WYNDHAM",
+    "XXXVIII", "YAWNING", "YEARNED", "YELLING", "YELPING", "YIELDED", "YOUNGER", "YSEULTE",
+    "YUSUPOV", "ZAMETOV", "ZARAISK", "ZEALAND", "ZEALOUS", "ZELINDA", "ZENAIDA", "ZIGZAGS",
+    "ZOOLOGY", "ZOOPHYT", "ZORILLA", "ZORILLO",
+];
+// Same sources as GUESSES_7
+pub static GUESSES_8: &[&str] = &[
+    "AARDVARK", "AARDWOLF", "ABANDONS", "ABDICATE", "ABERRANT", "ABERSHAW", "ABEYANCE", "ABHORRED",
+    "ABINGDON", "ABIPONES", "ABJECTLY", "ABNORMAL", "ABORTION", "ABORTIVE", "ABOUNDED", "ABRASIVE",
+    "ABRIDGED", "ABROLHOS", "ABRUPTLY", "ABSENTLY", "ABSOLVED", "ABSORBED", "ABSTRUSE", "ABSURDLY",
+    "ABUNDANT", "ABUTTING", "ACALYPHA", "ACAPULCO", "ACCENTOR", "ACCORDED", "ACCOSTED", "ACCOUNTS",
+    "ACCRUING", "ACCURACY", "ACCURSED", "ACCUSERS", "ACCUSING", "ACCUSTOM", "ACERBITY", "ACHIEVED",
+    "ACHIEVER", "ACHIEVES", "ACHILLES", "ACHINGLY", "ACOUSTIC", "ACQUAINT", "ACQUIRES", "ACQULRED",
+    "ACRYDIUM", "ACTIVELY", "ACTUATED", "ADAPTING", "ADAPTIVE", "ADDICTED", "ADDUCING", "ADHERENT",
+    "ADHERING", "ADHESIVE", "ADIMONIA", "ADJOINED", "ADJURING", "ADJUTANT", "ADMIRERS", "ADMIRING",
+    "ADMITTED", "ADOPTING", "ADOPTION", "ADORABLE", "ADORABLY", "ADORNING", "ADROITLY", "ADULTERY",
+    "ADVANCES", "ADVERTED", "ADVISING", "ADVOCACY", "AERIFORM", "AERONAUT", "AFFINITY", "AFFIRMED",
+    "AFFLUENT", "AFFORDED", "AFFRIGHT", "AGITATED", "AGONISED", "AGRARIAN", "AGREEING", "AGRICULT",
+    "AILMENTS", "AIREDALE", "AIRPLANE", "ALACRITY", "ALARMING", "ALBACORE", "ALBICEPS", "ALDERMAN",
+    "ALEHOUSE", "ALEXANDR", "ALIGHTED", "ALLEGING", "ALLEGORY", "ALLOTTED", "ALLOWING", "ALLUDING",
+    "ALLURING", "ALLUSION", "ALLUVIAL", "ALLUVIUM", "ALMANACS", "ALMIGHTY", "ALPHABET", "ALPHONSE",
+    "ALTERING", "ALTITUDE", "ALYOSHKA", "AMANCAES", "AMASSING", "AMAZEDLY", "AMBITION", "AMENABLE",
+    "AMERICAN", "AMERICAS", "AMERIQUE", "AMICABLE", "AMICABLY", "AMORALLY", "AMORITES", "AMOUNTED",
+    "ANACONDA", "ANADEERS", "ANALOGUE", "ANALYSED", "ANALYZED", "ANATOLIA", "ANCESTOR", "ANCESTRY",
+    "ANCHORED", "ANCIENTS", "ANECDOTE", "ANGELICA", "ANGLICAN", "ANGRIEST", "ANIMATED", "ANIMATES",
+    "ANNOYING", "ANNUALLY", "ANNUELLE", "ANOINTED", "ANOOTHER", "ANSWERED", "ANTEATER", "ANTELOPE",
+    "ANTENNAE", "ANTERIOR", "ANTILLES", "ANTIQUES", "ANYTHINK", "APERIENT", "APERTURE", "APHODIUS",
+    "APOPLEXY", "APOSTLES", "APPALLED", "APPANAGE", "APPEALED", "APPEARED", "APPEASED", "APPENDED",
+    "APPENDIX", "APPERTON", "APPINESS", "APPLAUSE", "APPLYING", "APPOINTS", "APPOSITE", "APPRISED",
+    "APPROVED", "APPROVES", "APRICOTS", "APTITUDE", "AQUILINE", "ARACHNID", "ARAPAIMA", "ARCHNESS",
+    "ARCHWAYS", "ARDENTLY", "ARENALES", "AREQUIPA", "ARETHUSA", "ARGUABLY", "ARMCHAIR", "ARMORIAL",
+    "ARMOURER", "AROMATIC", "ARQUEROS", "ARRANGED", "ARRANGES", "ARRAYING", "ARRECIFE", "ARRESTED",
+    "ARRIVALS", "ARRIVING", "ARROGANT", "ARROWING", "ARTEFACT", "ARTFULLY", "ARTICLED", "ARTICLES",
+    "ARTIFICE", "ASCENDED", "ASCIDIAE", "ASCRIBED", "ASCRIBES", "ASHEAMED", "ASPERITY", "ASPHALAX",
+    "ASPIRANT", "ASPIRATE", "ASPIRING", "ASSASSIN", "ASSAULTS", "ASSEMBLE", "ASSENTED", "ASSERTED",
+    "ASSESSOR", "ASSIGNED", "ASSISTED", "ASSOILED", "ASSORTED", "ASSURING", "ASTONISH", "ASTUTELY",
+    "ATHEISTS", "ATHENIAN", "ATLANTIC", "ATOLLONS", "ATTACHES", "ATTACKED", "ATTAINED", "ATTEMPTS",
+    "ATTENDED", "ATTESTED", "ATTRACTS", "AUCKLAND", "AUDACITY", "AUDITORS", "AUGMENTS", "AUGURIES",
+    "AUGUSTUS", "AUSPICES", "AUSTRIAN", "AUTUMNAL", "AUVERGNE", "AVAILING", "AVENGING", "AVERRING",
+    "AVERSION", "AVERTING", "AVESTRUZ", "AVOIDING", "AVOWEDLY", "AWAITING", "AWAKENED", "BABBLING",
+    "BACKBONE", "BACKYARD", "BADGERED", "BADINAGE", "BALANCED", "BALANDRA", "BALCARCE", "BALLENAR",
+    "BALLOONS", "BANDAGES", "BANDITTI", "BANDYING", "BANISHED", "BANISHES", "BANKRUPT", "BANQUETS",
+    "BAPTIZED", "BARBAULD", "BAREFOOT", "BARGAINS", "BARNACLE", "BARONESS", "BARONETS", "BARONIAL",
+    "BAROUCHE", "BARRACKS", "BARRIERS", "BASALTIC", "BASEMENT", "BASENESS", "BASILISK", "BATHURST",
+    "BATTERED", "BEARINGS", "BEATINGS", "BEAUFORT", "BEAUTIES", "BECALMED", "BECKONED", "BEDAUBED",
+    "BEDECKED", "BEDROOMS", "BEDSTEAD", "BEFALLEN", "BEFOGGED", "BEFRIEND", "BEGGARED", "BEGGARLY",
+    "BEGINNER", "BEGOTTEN", "BEGRIMED", "BEGRIMES", "BEGRUDGE", "BEGUILED", "BEHAVING", "BEHAVIOR",
+    "BEHEADED", "BEHOLDEN", "BEHOLDER", "BELAUDED", "BELGRAVE", "BELIEVED", "BELIEVER", "BELIEVES",
+    "BELLBIRD", "BELLOWER", "BELONGED", "BEMOANED", "BENCHERS", "BENCHUCA", "BENEDICT", "BENEFITS",
+    "BENGUELA", "BENIGNLY", "BENUMBED", "BEQUEATH", "BEREAVED", "BERKELEY", "BERMUDAS", "BERQUELO",
+    "BESIEGED", "BESOUGHT", "BESPEAKS", "BESTOWAL", "BESTOWED", "BETRAYAL", "BETRAYED", "BEVERAGE",
+    "BEVERLEY", "BEWAILED", "BEWILDER", "BIDDABLE", "BILLETED", "BILLFISH", "BILLIARD", "BINDINGS",
+    "BINDLOES", "BIRDCAGE", "BISCUITS", "BITINGLY", "BITTERER", "BITTERLY", "BIVALVES", "BIWEEKLY",
+    "BIZCACHA", "BLACKCAP", "BLACKENS", "BLACKEST", "BLACKING", "BLACKISH", "BLACKLEG", "BLADDERS",
+    "BLAMABLE", "BLANCHED", "BLANDEST", "BLANKETS", "BLAZONED", "BLEACHED", "BLEEDING", "BLENDING",
+    "BLESSING", "BLIGHTED", "BLINDING", "BLINKERS", "BLINKING", "BLISSFUL", "BLITHELY", "BLOCKADE",
+    "BLOCKING", "BLOCKSON", "BLOODILY", "BLOOMETH", "BLOOMING", "BLOSSOMS", "BLOTCHES", "BLOTTING",
+    "BLOWFISH", "BLOWPIPE", "BLUDGEON", "BLUEBILL", "BLUEBIRD", "BLUEFISH", "BLUEGILL", "BLUNDERS",
+    "BLURTING", "BLUSHING", "BOARDERS", "BOARDING", "BOARFISH", "BOASTFUL", "BOASTING", "BOATBILL",
+    "BOBOLINK", "BOBWHITE", "BOHEMOND", "BOLABOLA", "BOLDNESS", "BOLIVIAN", "BONEFISH", "BONFIRES",
+    "BONPLAND", "BONTEBOK", "BOOKCASE", "BORDERED", "BORINGLY", "BOROUGHS", "BORRERIA", "BORROWED",
+    "BORROWER", "BOTANIST", "BOTHERED", "BOTOFOGO", "BOTTOMED", "BOULDERS", "BOULOGNE", "BOUNCING",
+    "BOUNDING", "BOUQUETS", "BOYISHLY", "BOYTBORN", "BOYTHORN", "BRACELET", "BRACKISH", "BRADSHAW",
+    "BRAGGART", "BRAGGING", "BRAMADOR", "BRAMBLES", "BRANCHED", "BRANCHES", "BRANDING", "BRANDONS",
+    "BRAVASSA", "BRAWLING", "BRAZENED", "BRAZENLY", "BREACHED", "BREACHES", "BREAKERS", "BREASTED",
+    "BREATHED", "BREATHES", "BREECHES", "BREEZILY", "BRETHREN", "BREWSTER", "BRIDLING", "BRIGANDS",
+    "BRIGHTEN", "BRIGHTER", "BRIGHTLY", "BRIGHTON", "BRIMLESS", "BRIMMING", "BRINGING", "BRISTLED",
+    "BRISTLES", "BRITTANN", "BROACHED", "BROCCOLI", "BROCHURE", "BROILING", "BROKENLY", "BROMELIA",
+    "BROMPTON", "BROODING", "BROTHERS", "BROUGHAM", "BROWDIES", "BROWNING", "BROWNISH", "BROWSING",
+    "BRUISING", "BRUSHING", "BRUSSELS", "BRUTALLY", "BUBBLING", "BUCKLAND", "BUFFOONS", "BULGARIA",
+    "BULKELEY", "BULLFROG", "BULLHEAD", "BULLOCKS", "BULLYING", "BULWARKS", "BUNCOMBE", "BUNGLING",
+    "BUOYANCY", "BURCHELL", "BURCHESS", "BURDENED", "BURGLARS", "BURNINGS", "BURROWED", "BURSTING",
+    "BUSHBUCK", "BUSTLING", "BUSYBODY", "BUTCHERS", "BUTTERED", "BUTTONED", "CABALLED", "CABBAGES",
+    "CABINETS", "CABOOSES", "CACIQUES", "CACTUSES", "CADESSES", "CAJOLING", "CALABRIA", "CALAMITY",
+    "CALCAREO", "CALCULUS", "CALDRONS", "CALLINGS", "CALMNESS", "CALODERA", "CALOSOMA", "CANARIES",
+    "CANDIDLY", "CANISTER", "CANNIBAL", "CANTERED", "CANTRIPS", "CAPITALS", "CAPRICES", "CAPSICUM",
+    "CAPSTANS", "CAPTAINS", "CAPTIOUS", "CAPTIVES", "CAPTURED", "CAPUCHIN", "CAPYBARA", "CARACARA",
+    "CARACTER", "CARAVELS", "CARDINAL", "CAREFREE", "CARELESS", "CARESSED", "CARESSES", "CAREWORN",
+    "CARNALLY", "CARNEGIE", "CAROLINE", "CARPACHO", "CARPETED", "CARRIAGE", "CARRYING", "CARSTONE",
+    "CARTLOAD", "CARVINGS", "CASARITA", "CASCADES", "CASEMENT", "CASHMERE", "CASTANET", "CASTAWAY",
+    "CASUALLY", "CASUCHAS", "CASUISTS", "CATACOMB", "CATARACT", "CATCHERS", "CATHOLIC", "CAUCAHUE",
+    "CAULDRON", "CAUSALLY", "CAUTIONS", "CAVITIES", "CEILINGS", "CELLARIA", "CELLULAR", "CEMENTED",
+    "CEMETERY", "CENSURED", "CENSURES", "CENTAURS", "CENTERED", "CENTRING", "CEREBRAL", "CERTAINL",
+    "CERVICEM", "CESENATE", "CESSPOOL", "CHADBAND", "CHAFFERS", "CHAIRING", "CHALKING", "CHAMBERS",
+    "CHAMISSO", "CHANCERY", "CHANCING", "CHANDLER", "CHANERAL", "CHANGING", "CHANNELS", "CHANTANT",
+    "CHANTING", "CHAPERON", "CHAPLAIN", "CHAPTERS", "CHAQUAIO", "CHARCOAL", "CHARGERS", "CHARGING",
+    "CHARIOTS", "CHARMERS", "CHARMING", "CHARTISM", "CHARTIST", "CHASTELY", "CHASTISE", "CHASTITY",
+    "CHATTELS", "CHATTERS", "CHATTILY", "CHATTING", "CHEAPEST", "CHEATING", "CHECKING", "CHEEKILY",
+    "CHEERFUL", "CHEERILY", "CHEERING", "CHEMISTS", "CHENILLE", "CHEPONES", "CHERRIES", "CHERUBIM",
+    "CHESTNUT", "CHICKENS", "CHIEFEST", "CHILDISH", "CHILENOS", "CHILLING", "CHILOTAN", "CHILTERN",
+    "CHIMAERA", "CHIMANGO", "CHIMBLEY", "CHIMNEYS", "CHINKING", "CHIPMUNK", "CHIPPING", "CHIRPING",
+    "CHIVALRY", "CHIVYING", "CHOICEST", "CHOISEUL", "CHOOSING", "CHOPKINS", "CHOPPING", "CHORUSES",
+    "CHRISTEN", "CHRYSOPA", "CHUCKLED", "CHURCHES", "CHURNING", "CINEREUS", "CINNAMON", "CIRCLING",
+    "CIRCUITS", "CISTERNS", "CITATION", "CITIZENS", "CIVILITY", "CLADONIA", "CLAIMANT", "CLAIMING",
+    "CLANGING", "CLANGOUR", "CLANKING", "CLAPPING", "CLASHING", "CLASPING", "CLASSIFY", "CLAVIPES",
+    "CLEANEST", "CLEANING", "CLEANSES", "CLEAREST", "CLEARING", "CLEAVAGE", "CLEMATIS", "CLEMENCY",
+    "CLENCHED", "CLENCHES", "CLERICAL", "CLEVERER", "CLEVERLY", "CLICKING", "CLIFFORD", "CLIMATES",
+    "CLIMBERS", "CLIMBING", "CLINCHED", "CLINGING", "CLINKING", "CLIPPERS", "CLIPPING", "CLOISTER",
+    "CLOSETED", "CLOUDILY", "CLUMSILY", "CLUSTERS", "CLUTCHED", "CLUTCHES", "COACHING", "COACHMAN",
+    "COACHMEN", "COARSELY", "COARSEST", "COATINGS", "COCHRANE", "COCKADED", "COCKADES", "COCKATOO",
+    "CODDLING", "CODIFIED", "CODLINGS", "COERCIVE", "COGENTLY", "COHERENT", "COHESION", "COINCIDE",
+    "COLDNESS", "COLLECTS", "COLLIERS", "COLLIERY", "COLLNETT", "COLLOQUY", "COLONIES", "COLONIST",
+    "COLONIZE", "COLORADO", "COLOSSAL", "COLOURED", "COLUMBUS", "COLUMNAR", "COMBINED", "COMBINES",
+    "COMEDIES", "COMFORTS", "COMMANDS", "COMMENDS", "COMMENTS", "COMMIXTA", "COMMONER", "COMMONLY",
+    "COMMUNAL", "COMMUTED", "COMPACTS", "COMPARED", "COMPARES", "COMPLIED", "COMPLIES", "COMPOSER",
+    "COMPOSES", "COMRADES", "CONCEDED", "CONCEDES", "CONCEIVE", "CONCERNS", "CONCERTS", "CONCLAVE",
+    "CONDEMNS", "CONDENSE", "CONDOLED", "CONDUCTS", "CONDUITS", "CONFIDED", "CONFIDES", "CONFINED",
+    "CONFINES", "CONFIRMS", "CONFOUND", "CONFRONT", "CONFUSES", "CONJOINT", "CONJUGAL", "CONJURED",
+    "CONJURES", "CONJUROR", "CONNECTS", "CONQUEST", "CONSENTS", "CONSISTS", "CONSOLED", "CONSOLES",
+    "CONSPIRE", "CONSTRUE", "CONSULTS", "CONSUMED", "CONTAINS", "CONTEMNS", "CONTEMPT", "CONTENDS",
+    "CONTENTS", "CONTESTS", "CONTREES", "CONTRITE", "CONTRIVE", "CONTROLS", "CONVENED", "CONVENTS",
+    "CONVERGE", "CONVERSE", "CONVERTS", "CONVEYED", "CONVICTS", "COOKITES", "COOKSHOP", "COOLNESS",
+    "COQUETRY", "COQUETTE", "COQUILLE", "COQUIMBO", "CORDIALS", "CORDUROY", "CORFIELD", "CORMORAN",
+    "CORNELIA", "CORNERED", "CORNWALL", "CORONERS", "CORONETS", "CORPORAL", "CORRALES", "CORRECTS",
+    "CORRODED", "CORRUPTS", "COSGRAVE", "COSTLIER", "COSTUMES", "COTTAGER", "COTTAGES", "COTTONED",
+    "COUGHING", "COUNCILS", "COUNSELS", "COUNTERS", "COUNTESS", "COUNTIES", "COUNTING", "COUNTREE",
+    "COUPLING", "COURSERS", "COURSING", "COURTESY", "COURTIER", "COURTING", "COUSINLY", "COUTHOUY",
+    "COVENTRY", "COVERLET", "COVERLEY", "COVERTLY", "COVETOUS", "COWARDLY", "COWERING", "COWSLIPS",
+    "COXCOMBS", "COXSWAIN", "CRACKING", "CRACKLED", "CRAFTIER", "CRAFTILY", "CRAMMING", "CRAMPING",
+    "CRANNIES", "CRASHING", "CRAWLING", "CRAYFISH", "CREAKILY", "CREAKING", "CREASING", "CREATING",
+    "CREATURE", "CREDENCE", "CREDIBLE", "CREDIBLY", "CREDITED", "CREDITOR", "CREEPERS", "CREEPING",
+    "CREETURS", "CRESCENT", "CREVICES", "CRICHTON", "CRICKETS", "CRINGING", "CRIPPLED", "CRIPPLER",
+    "CRITIQUE", "CRITTURS", "CROAKING", "CROCKERY", "CROCKING", "CROTCHET", "CROUCHED", "CROUCHES",
+    "CROWDING", "CROWNING", "CRUMBLED", "CRUMMLES", "CRUMPETS", "CRUMPLED", "CRUSADER", "CRUSADES",
+    "CRUSHING", "CRUTCHES", "CRYSTALS", "CRYSTIAL", "CTENOMYS", "CUCHILLA", "CUCUMBER", "CULTURED",
+    "CUMFBLER", "CUPBOARD", "CUPIDITY", "CURASSOW", "CURLINGS", "CURRANTS", "CURRENTS", "CURRICLE",
+    "CURSITOR", "CURTAINS", "CURTNESS", "CURTSEYS", "CURTSIED", "CUSHIONS", "CUSSEDLY", "CYLINDER",
+    "CYMINDIS", "CYNICISM", "CYTTARIA", "DABBLERS", "DABBLING", "DABCHICK", "DAGESTAN", "DAINTIES",
+    "DAINTILY", "DAMAGING", "DAMNABLE", "DAMNABLY", "DAMPNESS", "DANCINGS", "DANDLING", "DANDYISM",
+    "DANGLING", "DARINGLY", "DARKENED", "DARKNESS", "DARLINGS", "DARWINII", "DAVEROUS", "DAWDLING",
+    "DAYBREAK", "DAZZLING", "DEADENED", "DEADLOCK", "DEADWOOD", "DEAFNESS", "DEALFISH", "DEALINGS",
+    "DEATHBED", "DEBARRED", "DEBASING", "DEBATING", "DEBILITY", "DEBONAIR", "DECAMPED", "DECANTER",
+    "DECAYING", "DECEASED", "DECEIVED", "DECEIVES", "DECEMBER", "DECENTLY", "DECIMALS", "DECIPHER",
+    "DECISIVE", "DECLARED", "DECLARES", "DECLINED", "DECLINES", "DECORATE", "DECOROUS", "DECREPIT",
+    "DEDLOCKS", "DEDUCTED", "DEEPENED", "DEFACING", "DEFEATED", "DEFENDED", "DEFIANCE", "DEFINING",
+    "DEFORMED", "DEFRAYED", "DEGRADED", "DEIGNING", "DEJECTED", "DELAVALS", "DELAYING", "DELICACY",
+    "DELIGHTS", "DELIRIUM", "DELIVERS", "DELUDING", "DELUSION", "DELUSIVE", "DEMANDED", "DEMARLII",
+    "DEMENTED", "DEMERITS", "DEMIGODS", "DEMNEBLE", "DEMNEBLY", "DEMURELY", "DEMURRED", "DENIZENS",
+    "DENNISON", "DENOTING", "DENOUNCE", "DENUDING", "DEPARTED", "DEPENDED", "DEPICTED", "DEPICTER",
+    "DEPLORED", "DEPOSITS", "DEPRAVED", "DEPRIVED", "DEPRIVES", "DEPTFORD", "DEPUTIES", "DERANGED",
+    "DERISION", "DERISIVE", "DERIVING", "DESCENDS", "DESCRIED", "DESCRIES", "DESERTED", "DESERVED",
+    "DESERVES", "DESIGNED", "DESIRING", "DESIROUS", "DESISTED", "DESMODUS", "DESOLATE", "DESPAIRS",
+    "DESPATCH", "DESPISED", "DESPISES", "DESPOTIC", "DESSERTS", "DESTINED", "DESTROYS", "DETACHED",
+    "DETACHES", "DETAINED", "DETAINER", "DETECTED", "DETERRED", "DETESTED", "DETRITUS", "DEVELOPS",
+    "DEVIATED", "DEVILISH", "DEVISING", "DEVOLVED", "DEVOLVES", "DEVONIAN", "DEVOTING", "DEVOTION",
+    "DEVOURED", "DEVOUTLY", "DEWDROPS", "DEWINESS", "DEYVLISH", "DIAGONAL", "DIALECTS", "DIAMONDS",
+    "DIBABSES", "DICTATED", "DICTATES", "DICTATOR", "DIDACTIC", "DIFFERED", "DIFFUSED", "DIGESTER",
+    "DIGESTIO", "DILATING", "DILATORY", "DILIGENT", "DIMINISH", "DIMPLING", "DINGLEBY", "DINOSAUR",
+    "DIRECFLY", "DIRECTED", "DIRTIEST", "DISAGREE", "DISARMED", "DISARRAY", "DISCLOSE", "DISCORDS",
+    "DISCREET", "DISCRETE", "DISEASED", "DISEASES", "DISGRACE", "DISGUISE", "DISGUSTS", "DISLIKED",
+    "DISLIKES", "DISLODGE", "DISMALLY", "DISMAYED", "DISMOUNT", "DISOWNED", "DISPATCH", "DISPENSE",
+    "DISPERSE", "DISPLAYS", "DISPOSED", "DISPOSES", "DISPROOF", "DISPUTED", "DISPUTES", "DISQUIET",
+    "DISSOLVE", "DISSUADE", "DISTASTE", "DISTRACT", "DISTRESS", "DISTRUST", "DISTURBS", "DITCHING",
+    "DIVERGED", "DIVERTED", "DIVESTED", "DIVIDING", "DIVINELY", "DIVINING", "DIVINITY", "DIVORCED",
+    "DIVULGES", "DOBERMAN", "DOCKYARD", "DOGGEDLY", "DOLOROUS", "DOMIDORS", "DOMINEER", "DOMINION",
+    "DONATION", "DOOMSDAY", "DOORWAYS", "DORMOUSE", "DOTHEBYS", "DOTINGLY", "DOTTEREL", "DOUBLING",
+    "DOUBTFUL", "DOUBTING", "DOWAGERS", "DOWNCAST", "DOWNFALL", "DOWNWARD", "DRAGGING", "DRAGGLED",
+    "DRAGONET", "DRAGOONS", "DRAINAGE", "DRAINING", "DRAUGHTS", "DRAWBACK", "DRAWINGS", "DRAWLING",
+    "DREADFUL", "DREADING", "DREAMILY", "DREAMING", "DREARILY", "DRENCHED", "DRESSING", "DRIBLETS",
+    "DRIFTING", "DRINKERS", "DRIPPING", "DROLLERY", "DROOPING", "DROUGHTS", "DROWNDED", "DROWNING",
+    "DROWSILY", "DRUBBING", "DRUDGERY", "DRUMMERS", "DRUMMOND", "DRUNKARD", "DUCKBILL", "DUCKLING",
+    "DULLARDS", "DULLNESS", "DUMBBELL", "DUMPLING", "DUNGEONS", "DUNGHILL", "DUNHEVED", "DUODENUM",
+    "DUSSAUTS", "DUTCHMAN", "DWELLING", "DWINDLED", "EARLIEST", "EARRINGS", "EASINESS", "EASTERLY",
+    "EASTWARD", "EATABLES", "ECHOINGS", "ECLECTIC", "ECLIPSED", "ECSTATIC", "EDENTATA", "EDGELESS",
+    "EDGEWARE", "EDGEWAYS", "EDIFICES", "EDIFYING", "EDUCATOR", "EFFECTED", "EFFICACY", "EFFLUVIA",
+    "EFFUSION", "EGYPTIAN", "EIGHTEEN", "EIGHTHLY", "EJECTING", "ELBOWING", "ELECTING", "ELECTORS",
+    "ELEGANCE", "ELEMENTS", "ELEVATED", "ELEVATES", "ELEVATOR", "ELEVENTH", "ELEWATED", "ELICITED",
+    "ELIGIBLE", "ELLIPTIC", "ELOQUENT", "EMANATED", "EMBARKED", "EMBEDDED", "EMBITTER", "EMBODIED",
+    "EMBOSSED", "EMBRACED", "EMBRACER", "EMBRACES", "EMIGRATE", "EMINENCE", "EMITTING", "EMOTIONS",
+    "EMPETRUM", "EMPHATIC", "EMPLOYED", "EMPORIUM", "EMPTYING", "EMULATED", "ENABLING", "ENACTING",
+    "ENCAMPED", "ENCIRCLE", "ENCLOSED", "ENCOMIUM", "ENCROACH", "ENCUMBER", "ENCYCLOP", "ENDANGER",
+    "ENDEARED", "ENDORSED", "ENDOWING", "ENDROITS", "ENDURING", "ENERGIES", "ENFOLDED", "ENFORCED",
+    "ENGENDER", "ENGRAVED", "ENGRAVEN", "ENGRAVER", "ENHANCED", "ENJOINED", "ENJOYING", "ENLARGED",
+    "ENLISTED", "ENNOBLED", "ENNOBLES", "ENORMITY", "ENRICHED", "ENROLLED", "ENSLAVER", "ENSNARED",
+    "ENTAILED", "ENTANGLE", "ENTERING", "ENTICING", "ENTIRETY", "ENTITLED", "ENTOMBED", "ENTRAILS",
+    "ENTREATS", "ENTREATY", "ENTWINED", "ENTWINES", "ENVELOPS", "ENVIABLE", "ENVIABLY", "EPAULETS",
+    "EPILEPSY", "EPILOGUE", "EPISODES", "EPITHETS", "EQUALLED", "EQUIPAGE", "EQUIPPED", "ERICHSON",
+    "ERUCTANS", "ERUPTION", "ERUPTIVE", "ERYNGIUM", "ESCAPING", "ESCARGOT", "ESCORTED", "ESPECIAL",
+    "ESPOUSED", "ESQUIMAU", "ESTACADO", "ESTANCIA", "ESTEEMED", "ESTRANGE", "ETERNITY", "ETHEREAL",
+    "EUDROMIA", "EULACHON", "EULOGIUM", "EUROPEAN", "EVASIONS", "EVENINGS", "EVENNESS", "EVENTFUL",
+    "EVERBODY", "EVERMORE", "EVINCING", "EVOLVING", "EXACTING", "EXALTING", "EXAMINED", "EXAMINES",
+    "EXAMPLES", "EXCEEDED", "EXCELLED", "EXCEPTED", "EXCESSES", "EXCLAIMS", "EXCLUDED", "EXCUSING",
+    "EXECRATE", "EXECUTED", "EXECUTOR", "EXEMPTED", "EXERTING", "EXERTION", "EXHAUSTS", "EXHIBITS",
+    "EXHORTED", "EXISTENT", "EXORDIUM", "EXPANSES", "EXPELLED", "EXPENDED", "EXPENSES", "EXPERTLY",
+    "EXPIRING", "EXPLAINS", "EXPLETUS", "EXPLODES", "EXPLOITS", "EXPLORED", "EXPLORER", "EXPONENT",
+    "EXPORTED", "EXPOSING", "EXSERTED", "EXTERIOR", "EXTOLLED", "EXTORTED", "EXTRACTS", "EXTREMES",
+    "EXTREMUM", "EXULTANT", "EXULTING", "EYEBROWS", "EYEGLASS", "EYESIGHT", "FABULOUS", "FACIALLY",
+    "FAILINGS", "FAILURES", "FAINEANT", "FAINTEST", "FAINTING", "FAIRNESS", "FAITHFUL", "FALCONER",
+    "FALCONET", "FALKLAND", "FALLIBLE", "FALLIBLY", "FALMOUTH", "FALSETTO", "FALTERED", "FAMILIES",
+    "FAMISHED", "FAMOUSLY", "FANCIFUL", "FANCYING", "FANLIGHT", "FANSHAWE", "FAREWELL", "FARTHEST",
+    "FARTHING", "FASHIONS", "FASTENED", "FASTNESS", "FATHERLY", "FATIGUED", "FATIGUES", "FATTENED",
+    "FAULTILY", "FAVORITE", "FAVOURED", "FAZENDAS", "FEARLESS", "FEASIBLE", "FEASIBLY", "FEASTING",
+    "FEATHERS", "FEATHERY", "FEATURES", "FEBRUARY", "FEELINGS", "FEIGNING", "FELICITY", "FEMININE",
+    "FERGUSON", "FERNANDO", "FEROCITY", "FERRETED", "FERVENCY", "FERVIDLY", "FESTOONS", "FETCHING",
+    "FETLOCKS", "FEVERISH", "FEYTHERS", "FICTIONS", "FIDELITY", "FIDGETED", "FIDGETTS", "FIENDISH",
+    "FIERCELY", "FIERCEST", "FIGHTING", "FIGURING", "FILEFISH", "FILIGREE", "FILTERED", "FILTHILY",
+    "FINANCES", "FINGERED", "FINISHES", "FINITELY", "FINLSHED", "FINSBURY", "FIREARMS", "FIREBACK",
+    "FIREBRAT", "FIRESIDE", "FIREWOOD", "FIREWORK", "FIRMNESS", "FISCALLY", "FISSURED", "FISSURES",
+    "FITFULLY", "FITTINGS", "FITZHUGH", "FIXTURES", "FLABBILY", "FLABELLA", "FLAGGING", "FLAGRANT",
+    "FLAMINGO", "FLAPPING", "FLASHILY", "FLASHING", "FLATFISH", "FLATHEAD", "FLATNESS", "FLATTERS",
+    "FLATTERY", "FLATTEST", "FLATTISH", "FLAWLESS", "FLEETING", "FLESHING", "FLETCHER", "FLEXIBLY",
+    "FLICKERS", "FLICKING", "FLIMSILY", "FLINDERS", "FLINGING", "FLIPPANT", "FLIPPING", "FLIRTING",
+    "FLITTING", "FLOCKING", "FLOGGING", "FLOODING", "FLOORING", "FLORENCE", "FLORIDLY", "FLOTILLA",
+    "FLOUNCED", "FLOUNCES", "FLOUNDER", "FLOURISH", "FLUENTLY", "FLUFFLES", "FLUGGERS", "FLURRIED",
+    "FLURRIES", "FLUSHING", "FLUTINGS", "FLUTTERS", "FOLLERER", "FOLLOWED", "FOLLOWER", "FONDLING",
+    "FONDNESS", "FOOLSCAP", "FOOTFALL", "FOOTHOLD", "FOOTPATH", "FOOTSORE", "FOOTSTEP", "FOOTWAYS",
+    "FORAGERS", "FORBEARS", "FORCEFUL", "FORCIBLE", "FORCIBLY", "FOREGONE", "FOREHEAD", "FORELOCK",
+    "FORENOON", "FORENSIC", "FORESEEN", "FORESEES", "FORETELL", "FORETOLD", "FORFEITS", "FORGIVEN",
+    "FORGIVES", "FORMALLY", "FORSAKEN", "FORSWORN", "FORTRESS", "FORTUNES", "FORWARDS", "FOSSILES",
+    "FOSTERED", "FOUNDING", "FOUNTAIN", "FOURTHLY", "FOXHOUND", "FRACTURE", "FRAGMENT", "FRAGRANT",
+    "FRANCAIS", "FRANKEST", "FRECKLED", "FREEZING", "FRENZIED", "FRESHEST", "FRETTING", "FREYRINA",
+    "FRICTION", "FRIDOLIN", "FRIGHTEN", "FRIGIDLY", "FRINGING", "FRIPPERY", "FRISKILY", "FRISKING",
+    "FROGFISH", "FRONTING", "FROSTILY", "FROWNING", "FRUGALLY", "FRUITFUL", "FUEGIANS", "FUGITIVE",
+    "FULLNESS", "FULVIPES", "FUMBLING", "FUNERALS", "FUNEREAL", "FUNNIEST", "FURLONGS", "FURNACES",
+    "FURNITER", "FURROWED", "FURTHEST", "FUTILELY", "GADZOOKS", "GAIETIES", "GAINSAID", "GALLANTS",
+    "GALLEGOS", "GALLOPED", "GAMBLERS", "GAMBLING", "GAMESTER", "GANGLION", "GARDENER", "GARDNERS",
+    "GARGANEY", "GARISHLY", "GARLANDS", "GARMENTS", "GARRISON", "GARTERED", "GARTHERS", "GATEWAYS",
+    "GATHERED", "GATHERER", "GATHERUM", "GAUDIEST", "GAUNTLET", "GAZELLES", "GEMMULES", "GENELMAN",
+    "GENELMEN", "GENERALS", "GENIALLY", "GENIUSES", "GENTLEMA", "GENTLEST", "GEOFFROI", "GEOFFROY",
+    "GEOGRAPH", "GEOMETRY", "GEORGINA", "GEOSPIZA", "GERANIUM", "GESTURES", "GETTINGS", "GIGANTEA",
+    "GIGGLING", "GINGERLY", "GIRAFFES", "GIRLHOOD", "GIUSEPPE", "GLACIERS", "GLADNESS", "GLADSOME",
+    "GLANCING", "GLASSFUL", "GLEAMING", "GLEESOME", "GLENCORA", "GLIMPSES", "GLISTENS", "GLOATING",
+    "GLOBALLY", "GLOBULAR", "GLOBULES", "GLOOMIER", "GLOOMILY", "GLORIOUS", "GLORYING", "GLOSSARY",
+    "GLOWERED", "GLOWWORM", "GLUTTONS", "GNASHING", "GOATFISH", "GOATHERD", "GOATSKIN", "GODCHILD",
+    "GOLDFISH", "GOLGOTHA", "GOLOSHES", "GONZALES", "GOODWILL", "GOODWOOD", "GOOTTHER", "GORGEOUS",
+    "GOSSAMER", "GOSSIPER", "GOURMAND", "GOVERNED", "GOVERNOR", "GRACEFUL", "GRACIOUS", "GRAMMARS",
+    "GRANDEES", "GRANDEST", "GRANDEUR", "GRANDSON", "GRANITIC", "GRANTHAM", "GRANTING", "GRANULAR",
+    "GRAPPLED", "GRASPING", "GRAVAMEN", "GRAVELLY", "GRAYLING", "GREASILY", "GREATEST", "GRECIANS",
+    "GREEDILY", "GREENISH", "GREETING", "GRIDIRON", "GRIEVING", "GRIEVOUS", "GRIFFITH", "GRIMACES",
+    "GRIMBLES", "GRINDERS", "GRINDING", "GRINNING", "GRIPPING", "GRITTING", "GRIZZLED", "GROANING",
+    "GROGZWIG", "GROOVING", "GROSBEAK", "GROSSEST", "GROTTOES", "GROUNDED", "GROUPING", "GROWLERY",
+    "GROWLING", "GRUBBING", "GRUBWORM", "GRUESOME", "GRUMBLED", "GRUMBLER", "GRUMPILY", "GUANACOS",
+    "GUARDAGE", "GUARDING", "GUESSING", "GUFFAWED", "GUILTILY", "GULLIBLE", "GULLIVER", "GUNNNERS",
+    "GUNSMITH", "GUNWALES", "GURGLING", "GUTTERED", "GUTTURAL", "GUYAQUIL", "GUZZLING", "HABITUAL",
+    "HACHETTE", "HACIENDA", "HAIRLESS", "HAIRTAIL", "HALFBEAK", "HALLOWED", "HAMILTON", "HAMMERED",
+    "HAMPDENS", "HAMPERED", "HANDCUFF", "HANDEDER", "HANDFULS", "HANDMAID", "HANDRAIL", "HANDSOME",
+    "HANGINGS", "HAPPENED", "HAPPIEST", "HARANGUE", "HARASSED", "HARBOURS", "HARDENED", "HARDNESS",
+    "HARDSHIP", "HARESKIN", "HARLAMOV", "HARMLESS", "HARMONIC", "HARPALUS", "HARRIETT", "HARRISON",
+    "HARSHEST", "HASTENED", "HATCHING", "HAUNCHES", "HAUNTING", "HAWFINCH", "HAZARDED", "HEADACHE",
+    "HEADGEAR", "HEADLAND", "HEADLESS", "HEADLONG", "HEADMOST", "HEARTILY", "HEATEDLY", "HEAVENLY",
+    "HEAVIEST", "HEAVINGS", "HEDGEHOG", "HEEDLESS", "HEIGHTEN", "HEIRSHIP", "HELPLESS", "HELPMATE",
+    "HENEMIES", "HERALDED", "HERALDIC", "HERCULES", "HEREFORD", "HERETICK", "HERETICS", "HEREUPON",
+    "HEREWITH", "HERRINGS", "HERSCHEL", "HESITANT", "HESITATE", "HIBISCUS", "HICCUPED", "HIGHGATE",
+    "HIGHNESS", "HIGHROAD", "HIGHWAYS", "HILARITY", "HILLOCKS", "HILLTOPS", "HIMALAYA", "HINDERED",
+    "HISTOIRE", "HITCHING", "HITHERTO", "HITTITES", "HOARDING", "HOARSELY", "HOBBLING", "HOLIDAYS",
+    "HOLINESS", "HOLISTIC", "HOLLOWAY", "HOLLOWED", "HOLLOWER", "HOLLOWLY", "HOLSTEIN", "HOLYHEAD",
+    "HOMELIKE", "HOMEWARD", "HONESTER", "HONESTLY", "HONEYBEE", "HONORARY", "HONOURED", "HONOURMG",
+    "HOOKITES", "HOOKWORM", "HOOTINGS", "HOPELESS", "HORNBILL", "HORNPIPE", "HORNTAIL", "HORRIBLE",
+    "HORRIBLY", "HORRIDLY", "HORSEFLY", "HORSEMAN", "HORSEMEN", "HORTENSE", "HOSTAGES", "HOSTELRY",
+    "HOSTLERS", "HOTHOUSE", "HOUNDING", "HOVERING", "HUANTAMO", "HUCKSTER", "HUMANELY", "HUMBLEST",
+    "HUMBLING", "HUMBOLDT", "HUMIDITY", "HUMILITY", "HUMMOCKS", "HUMOROUS", "HUMOURED", "HUMPBACK",
+    "HUNDREDS", "HUNGRILY", "HUNTSMAN", "HUNTSMEN", "HURRAHED", "HURRYING", "HUSBANDS", "HUSTINGS",
+    "HUSTLING", "HYACINTH", "HYDROGEN", "HYGIENIC", "HYPERION", "HYPNOTIC", "HYSETERS", "HYSTERIC",
+    "ICEBERGS", "IDEALISM", "IDEALIST", "IDEALITY", "IDLENESS", "IDOLATRY", "IDOLIZED", "IGNITING",
+    "IGNOMINY", "IGNORANT", "IGNORING", "ILLUSION", "IMAGINED", "IMAGINES", "IMBECILE", "IMBEDDED",
+    "IMITATED", "IMITATOR", "IMMATURE", "IMMENSUS", "IMMERSED", "IMMINENT", "IMMOLATE", "IMMORTAL",
+    "IMPAIRED", "IMPALING", "IMPARTED", "IMPELLED", "IMPERILS", "IMPISHLY", "IMPLICIT", "IMPLORED",
+    "IMPLYING", "IMPOLITE", "IMPORTED", "IMPORTER", "IMPOSING", "IMPOSTER", "IMPOTENT", "IMPROPER",
+    "IMPROVED", "IMPROVES", "IMPUDENT", "IMPUGNED", "IMPULSES", "IMPUNITY", "IMPURELY", "IMPURITY",
+    "IMPUTING", "INACTION", "INACTIVE", "INASMUCH", "INCENSED", "INCISIVE", "INCLINED", "INCLINES",
+    "INCLUDES", "INCOMING", "INCURRED", "INDEBTED", "INDECENT", "INDENTED", "INDIAMAN", "INDIGENT",
+    "INDOLENT", "INDUCING", "INDUCTED", "INDULGED", "INFAMIES", "INFAMOUS", "INFANTRY", "INFECTED",
+    "INFERIOR", "INFERNAL", "INFERRED", "INFESTED", "INFIDELS", "INFINITE", "INFINITY", "INFLAMED",
+    "INFLATED", "INFLATES", "INFLEXAM", "INFLICTS", "INFORMER", "INFUSING", "INGLESES", "INHABITS",
+    "INHALING", "INIMICAL", "INIQUITY", "INJECTED", "INJURIES", "INJURING", "INKSTAND", "INKWHICH",
+    "INNATELY", "INNUENDO", "INQUESTS", "INQUIRED", "INQUIRER", "INQUIRES", "INSANELY", "INSANITY",
+    "INSECURE", "INSERTED", "INSISTED", "INSISTES", "INSOLENT", "INSOMUCH", "INSPECTS", "INSPIRES",
+    "INSPIRIT", "INSTINCT", "INSTITUT", "INSTRUCT", "INSULTED", "INSURING", "INTENTLY", "INTIMACY",
+    "INTOMBED", "INTONING", "INTREPID", "INTRIGUE", "INTRUDED", "INTRUDER", "INTRUDES", "INTWINED",
+    "INVADERS", "INVADING", "INVALIDS", "INVEIGLE", "INVENTED", "INVERTED", "INVESTED", "INVITING",
+    "INVOKING", "INVOLVES", "INWARDLY", "IRISHMAN", "IRISHMEN", "IRONICAL", "IRRIGATE", "IRRITATE",
+    "ITHACAIA", "IVANITCH", "IVANOVNA", "JACKDAWS", "JAGGEDLY", "JANGLING", "JARNDERS", "JARNDYCE",
+    "JARODYCE", "JAUNDICE", "JAUNTILY", "JAVELINA", "JEALOUSY", "JELLYBYS", "JENNINGS", "JEOPARDY",
+    "JEWELLED", "JEWELLER", "JINGLING", "JOCOSELY", "JOCUNDLY", "JOKINGLY", "JOLLIEST", "JOLQUERA",
+    "JOSTLING", "JOURNALS", "JOURNEYS", "JOVIALLY", "JOYFULLY", "JOYOUSLY", "JUBILANT", "JUGGLERY",
+    "JUMBLING", "JUNCTURE", "JUSTITIA", "JUVENILE", "KANGAROO", "KATERINA", "KEENNESS", "KEEPSAKE",
+    "KERCHIEF", "KEYHOLES", "KILLDEER", "KINDLIER", "KINDLING", "KINDNESS", "KINGBIRD", "KINGDOMS",
+    "KINGFISH", "KINGSTON", "KINKAJOU", "KITCHENS", "KITTLITZ", "KNEADING", "KNEELING", "KNIGHTLY",
+    "KNITTING", "KNOCKERS", "KNOCKING", "KNOWLEDG", "KNUCKLES", "KOTZEBUE", "LABELLED", "LABOURED",
+    "LABOURER", "LABRADOR", "LABURNUM", "LACERATE", "LACEWING", "LADYBIRD", "LADYHOOD", "LADYLIKE",
+    "LADYSHIP", "LAMENESS", "LAMENTED", "LAMPYRIS", "LANDINGS", "LANDLADY", "LANDMARK", "LANDSEER",
+    "LANDSMAN", "LANGUISH", "LANTERNS", "LATENESS", "LATHERED", "LATINITY", "LATITUDE", "LATTERLY",
+    "LATTHERS", "LATTICED", "LAUDABLE", "LAUDABLY", "LAUDAMUS", "LAUGHING", "LAUNCHED", "LAURANCE",
+    "LAUREATE", "LAURENCE", "LAVENDER", "LAVISHED", "LAVISHLY", "LAWFULLY", "LAWGIVER", "LAWRENCE",
+    "LAZINESS", "LEAFLESS", "LEANNESS", "LEAPINGS", "LEARNERS", "LEATHERN", "LECTURED", "LECTURER",
+    "LECTURES", "LEGALITY", "LEGATEES", "LEGGINGS", "LEIGHTON", "LEMONADE", "LENGTHEN", "LENVILLE",
+    "LEONEROS", "LEOVILLE", "LESSENED", "LETHALLY", "LETHARGY", "LETTERED", "LETTUCES", "LEVANTED",
+    "LEVELLED", "LEVITIES", "LIBATION", "LIBERALS", "LIBERATE", "LICENCES", "LICENSED", "LICENSES",
+    "LIFELESS", "LIGAMENT", "LIGHTENS", "LIGHTEST", "LIKEABLE", "LIKELIER", "LIKENESS", "LILLIPUT",
+    "LIMPIDLY", "LINEALLY", "LINEARLY", "LINGERED", "LINGUIST", "LINNAEAN", "LIONFISH", "LISTENED",
+    "LISTENER", "LISTLESS", "LITERATE", "LITTERED", "LITTLEST", "LITTORAL", "LIVELIER", "LIVELONG",
+    "LIVERIED", "LIVERIES", "LIZAVETA", "LLANDAFF", "LOATHING", "LOBSTERS", "LOCALITY", "LOCHNESS",
+    "LOCKOUTS", "LODGINGS", "LOFTIEST", "LOITERED", "LOITERER", "LONELIER", "LONESOME", "LONGHORN",
+    "LONGINGS", "LONGSPUR", "LONGWOOD", "LOOSENED", "LOPPINGS", "LOPSIDED", "LORDSHIP", "LORIKEET",
+    "LOTHBURY", "LOUDNESS", "LOUNGERS", "LOUNGING", "LOVEABLE", "LOVELIER", "LOVINGLY", "LOWERING",
+    "LOWLIEST", "LOZENGES", "LUCIFERS", "LUCKIEST", "LUCKLESS", "LUKEWARM", "LUMINARY", "LUMINOUS",
+    "LUNCHEON", "LUNGFISH", "LUSCIOUS", "LUSTROUS", "LUXURIES", "LYCURGUS", "LYREBIRD", "MACCOORT",
+    "MACKEREL", "MACLAREN", "MACULATA", "MADDENED", "MADELINE", "MADHOUSE", "MADRINAS", "MADWOMAN",
+    "MAGDALEN", "MAGELLAN", "MAGICIAN", "MAGNATES", "MAHOGANY", "MAIDENLY", "MAINLAND", "MAINSTAY",
+    "MAJESTIC", "MALAMUTE", "MALIGNED", "MAMELUKE", "MAMMALIA", "MANAGERS", "MANAGING", "MANDIBLE",
+    "MANDIOCA", "MANDRILL", "MANFULLY", "MANGLING", "MANIACAL", "MANIFEST", "MANIFOLD", "MANNERED",
+    "MANNERLY", "MANSIONS", "MANTLING", "MANUALLY", "MANURING", "MARCHING", "MARINERS", "MARITIME",
+    "MARKEDLY", "MARMOSET", "MARQUESS", "MARRYING", "MARSTONE", "MARTLETS", "MASSACRE", "MASTADON",
+    "MASTERED", "MASTERLY", "MASTHEAD", "MASTODON", "MATCHING", "MATERNAL", "MATRONLY", "MATTERED",
+    "MATTHEWS", "MATTRESS", "MATURELY", "MATURING", "MATUTINA", "MAXILLAE", "MEAGERLY", "MEALWORM",
+    "MEANINGS", "MEANNESS", "MEASTHER", "MEASURER", "MEASURES", "MECHANIC", "MEDDLERS", "MEDDLING",
+    "MEDIATOR", "MEDITATE", "MEEKNESS", "MEETINGS", "MELANOPS", "MELASOMA", "MELLOWED", "MEMBRANE",
+    "MEMORIES", "MENACING", "MENHADEN", "MENIALLY", "MENTALLY", "MENTIONS", "MERCEDES", "MERCIFUL",
+    "MERIDIAN", "MERRIEST", "MESSAGES", "METALLIC", "METEORIC", "MICROBES", "MIDDLING", "MIDWIVES",
+    "MIGHTIER", "MIGHTILY", "MIGRATED", "MIGRATES", "MILDEWED", "MILDNESS", "MILKMAID", "MILLINER",
+    "MILLIONS", "MINDEDLY", "MINDLESS", "MINGLING", "MINISTRY", "MINSTREL", "MINUTELY", "MINUTEST",
+    "MIRRORED", "MIRTHFUL", "MISCHIEF", "MISDEEDS", "MISERIES", "MISSIONS", "MISSPENT", "MISTAKEN",
+    "MISTAKES", "MISTRESS", "MISTRUST", "MITCHELL", "MOANINGS", "MOCCASIN", "MODELLED", "MODESTLY",
+    "MODIFIED", "MODISHLY", "MOISTURE", "MOLASSES", "MOLDAVIA", "MOLESTED", "MOLLUSCA", "MOLLUSCS",
+    "MONARCHS", "MONCEAUX", "MONGOOSE", "MONGRELS", "MONKFISH", "MONOGRAM", "MONOTONY", "MONSIEUR",
+    "MONSTERS", "MONSTHER", "MONTAGNE", "MONUMENT", "MOONFISH", "MOORINGS", "MOORLAND", "MOOTHERS",
+    "MORALISE", "MORALITY", "MORBIDLY", "MOREOVER", "MORLEENA", "MORNINGS", "MOROSELY", "MORPHEUS",
+    "MORTALLY", "MORTIMER", "MOSQUITO", "MOTHERLY", "MOTIONED", "MOUCHOIR", "MOULDING", "MOUNTING",
+    "MOURNERS", "MOURNFUL", "MOURNING", "MOUTHFUL", "MOUTHING", "MOVABLES", "MOVEABLE", "MOVINGLY",
+    "MUDDIEST", "MUDDOCKS", "MUDDYING", "MUFFLING", "MULBERRY", "MULETEER", "MULISHLY", "MULTIPLY",
+    "MUMBLING", "MUNCHING", "MURDERED", "MURDERER", "MURIATES", "MURMURED", "MURRELET", "MUSCULAR",
+    "MUSGRAVE", "MUSHROOM", "MUSICIAN", "MUSINGLY", "MUSKETRY", "MUSTERED", "MUTINOUS", "MUTTERED",
+    "MUTUALLY", "MYIOBIUS", "NAMELESS", "NAMESAKE", "NAPOLEON", "NARRATED", "NARRATES", "NARROWED",
+    "NARROWER", "NARROWLY", "NASTASYA", "NAUSEOUS", "NAUTICAL", "NAVIGATE", "NEARNESS", "NEATNESS",
+    "NEBULOUS", "NECESARY", "NECKLACE", "NEEDLESS", "NEIGHING", "NEOPHYTE", "NEREIDAE", "NERVURES",
+    "NESTLING", "NIAGARAS", "NICENESS", "NICHOLAS", "NICKELBY", "NICKLEBY", "NICKNAME", "NIGHTCAP",
+    "NIGHTJAR", "NIHILIST", "NINEVITE", "NOBBIEST", "NOBILITY", "NOBLEMAN", "NOBLEMEN", "NOBLESSE",
+    "NOBODIES", "NOCTURNA", "NODDINGS", "NOMINATE", "NONSENSE", "NORMALLY", "NORTHERN", "NOSEGAYS",
+    "NOSTRILS", "NOTAPHUS", "NOTHINGS", "NOTICING", "NOTIFIED", "NOTOPODS", "NOVELIST", "NOVEMBER",
+    "NOWADAYS", "NOWHERES", "NUISANCE", "NUMBERED", "NUMSKULL", "NUPTIALS", "NURTURED", "NUTHATCH",
+    "NUTSHELL", "OBDURACY", "OBDURATE", "OBEDIENT", "OBEISANT", "OBELISKS", "OBJECTED", "OBLIGING",
+    "OBLIVION", "OBSCURED", "OBSCURES", "OBSERVED", "OBSERVES", "OBSOLETE", "OBSTACLE", "OBTAINED",
+    "OBTRUDED", "OBTUSELY", "OCCUPANT", "OCCUPIED", "OCCUPIER", "OCCUPIES", "OCCURRED", "ODDITIES",
+    "ODIOUSLY", "OFFENCES", "OFFENDED", "OFFENDER", "OFFICERS", "OFFICIER", "OFTENEST", "OILINESS",
+    "OINTMENT", "OJECTION", "OLFERSIA", "OLIVASEA", "OMISSION", "OMITTING", "OPAQUELY", "OPENINGS",
+    "OPENNESS", "OPERATED", "OPINIONS", "OPOSSUMS", "OPPOSING", "OPULENCE", "OPUNTIAS", "ORATIONS",
+    "ORBIGNYI", "ORCHARDS", "ORDAINED", "ORDERING", "ORGANISE", "ORGANISM", "ORIENTAL", "ORIENTED",
+    "ORIFICES", "ORLANDOS", "ORNAMENT", "ORNATELY", "ORPHANED", "ORTHODOX", "OSSEMENS", "OSSIFIED",
+    "OTAHEITE", "OTTOMANS", "OUTBURST", "OUTCASTS", "OUTCRIES", "OUTEREST", "OUTGOING", "OUTGROWN",
+    "OUTHOUSE", "OUTLIERS", "OUTLINES", "OUTLIVED", "OUTLYING", "OUTPOSTS", "OUTRAGED", "OUTRAGES",
+    "OUTRIGHT", "OUTSHINE", "OUTSHONE", "OUTSIDER", "OUTSIDES", "OUTWARDS", "OUTWEIGH", "OVENBIRD",
+    "OVERAWED", "OVERCAME", "OVERCAST", "OVERCOAT", "OVERDOES", "OVERDONE", "OVERFLOW", "OVERHAUL",
+    "OVERHEAD", "OVERHEAR", "OVERHUNG", "OVERLAID", "OVERLEAP", "OVERMUCH", "OVERSTEP", "OVERTAKE",
+    "OVERTASK", "OVERTIME", "OVERTOOK", "OVERTURN", "OWLISHLY", "OXPECKER", "PACIFIED", "PACKAGES",
+    "PADDLING", "PAGEANTS", "PAILFULS", "PAINTERS", "PALENESS", "PALLIATE", "PALLISER", "PALPABLE",
+    "PALPABLY", "PALUDINA", "PAMPAEAN", "PAMPERED", "PAMPHLET", "PANCAKES", "PANDANUS", "PANELLED",
+    "PANGOLIN", "PANORAMA", "PAPERING", "PAPILLAE", "PAPILLON", "PARABOLA", "PARADISE", "PARAGUAY",
+    "PARAKEET", "PARALYSE", "PARAPETS", "PARASITE", "PARASOLS", "PARDONED", "PARISHES", "PARISIAN",
+    "PARLANCE", "PARLOURS", "PAROXYSM", "PARTAKEN", "PARTAKER", "PARTAKES", "PARTERRE", "PARTINGS",
+    "PARTNERS", "PARVULUS", "PASHENKA", "PASSABLE", "PASSAGES", "PASSERBY", "PASSIONS", "PASSPORT",
+    "PASTORAL", "PASTURES", "PATCHILY", "PATELLAE", "PATENTLY", "PATERNAL", "PATHETIC", "PATHWAYS",
+    "PATIENTS", "PATTERED", "PATTERNS", "PAVEMENT", "PAVILION", "PAVLITCH", "PAVLOVNA", "PAYMENTS",
+    "PEACOCKS", "PEASANTS", "PECTORAL", "PECULIAR", "PEDANTRY", "PEDESTAL", "PEDIGREE", "PEDIMENT",
+    "PEERAGES", "PEERLESS", "PEMBROKE", "PENDULUM", "PENGUINS", "PENITENT", "PENKNIFE", "PENSIONS",
+    "PENTLAND", "PERCEIVE", "PERCEPTS", "PERCIVAL", "PERFORCE", "PERFORMS", "PERFUMED", "PERFUMES",
+    "PERIAGUA", "PERIANTH", "PERILLED", "PERILOUS", "PERISHED", "PERJURED", "PERSISTS", "PERUSALS",
+    "PERUSING", "PERUVIAN", "PERVADED", "PERVADES", "PERVERSE", "PERVIOUS", "PERWERSE", "PESTERED",
+    "PETIOLES", "PETOWKER", "PETROVNA", "PETULANT", "PHANAEUS", "PHANTOMS", "PHEASANT", "PHILIPPE",
+    "PHYSALIA", "PHYSIQUE", "PICAYUNE", "PICKEREL", "PICKINGS", "PICKLING", "PICKWICK", "PICTURED",
+    "PICTURES", "PIEBALDS", "PIERCING", "PIGSTIES", "PIGTAILS", "PILCHARD", "PILLOWED", "PINAFORE",
+    "PINCHING", "PINNACLE", "PINNIPED", "PINSCHER", "PIPEFISH", "PIQUANCY", "PITCAIRN", "PITCHERS",
+    "PITCHING", "PITFALLS", "PITIABLE", "PITIABLY", "PITTANCE", "PLACARDS", "PLACIDLY", "PLAGUING",
+    "PLAINEST", "PLANARIA", "PLANTING", "PLASHING", "PLATTERS", "PLATYPUS", "PLAUDITS", "PLAYBILL",
+    "PLAYMATE", "PLAYSURE", "PLEADERS", "PLEADING", "PLEASING", "PLEBEIAN", "PLEDGING", "PLIANTLY",
+    "PLODDING", "PLOTTERS", "PLOTTING", "PLOUGHED", "PLUCKING", "PLUMBAGO", "PLUMMETS", "PLUMPEST",
+    "PLUNGING", "PLUTONIC", "PLYMOUTH", "POACHING", "POCKETED", "POETICAL", "POIGNANT", "POINTING",
+    "POISONED", "POLICIES", "POLISHED", "POLISHES", "POLITELY", "POLITEST", "POLLIWOG", "POLLUTED",
+    "POLTROON", "POLYBORI", "PONDERED", "PONSONBY", "POORNESS", "POPULOUS", "PORPHYRY", "PORPOISE",
+    "PORRIDGE", "PORTILLO", "PORTIONS", "PORTLAND", "PORTUGAL", "POSSESSE", "POSSIBLY", "POSTPONE",
+    "POSTURES", "POTATOES", "POTENTLY", "POTHOUSE", "POUNCING", "POUNTNEY", "POWDERED", "POWDERIN",
+    "PRACTISE", "PRAIRIES", "PRAISING", "PRANCING", "PREACHED", "PREACHER", "PRECEDED", "PRECEPTS",
+    "PREENING", "PREFERER", "PREFIXED", "PREJUDGE", "PREMIERS", "PREMISED", "PREMISES", "PRENTICE",
+    "PREPARED", "PREPARES", "PREPENSE", "PRESENTS", "PRESIDED", "PRESTIGE", "PRESUMED", "PRESUMES",
+    "PRETENCE", "PRETENDS", "PRETEXTS", "PRETTIER", "PRETTILY", "PREVAILS", "PREVENTS", "PRICKING",
+    "PRICKLES", "PRIESTLY", "PRILUKOV", "PRIMEVAL", "PRIMROSE", "PRINCELY", "PRINKING", "PRINTERS",
+    "PRIORESS", "PRISONER", "PRISSILY", "PROBLEMS", "PROCEEDS", "PROCLAIM", "PROCURED", "PRODIGAL",
+    "PRODUCED", "PRODUCES", "PRODUCTS", "PROFITED", "PROJECTS", "PROLIFIC", "PROMISED", "PROMISES",
+    "PROMISIN", "PROMOTED", "PROMOTER", "PROMOTES", "PROMPTED", "PROMPTER", "PROMPTLY", "PROODEST",
+    "PROPERLY", "PROPHECY", "PROPOSED", "PROPOSES", "PROPOUND", "PROPPING", "PROTEGEE", "PROTEGES",
+    "PROTESTS", "PROTOZOA", "PROTRACT", "PROTRUDE", "PROUDEST", "PROUDHON", "PROVABLY", "PROVIDES",
+    "PROVOKED", "PROVOKES", "PROWLING", "PROXYING", "PRUDENCE", "PRUNINGS", "PRURIENT", "PRUSSIAN",
+    "PUCKERED", "PUDDINGS", "PUFFINUS", "PULPERIA", "PUNCHING", "PUNCTUAL", "PUNCTURE", "PUNISHED",
+    "PUPPYISM", "PURBLIND", "PURIFIED", "PURPLISH", "PURPOSED", "PURPOSES", "PURPUREA", "PURSUANT",
+    "PURSUERS", "PURSUING", "PURSUITS", "PURVIDED", "PUSTULES", "PUZZLING", "PYRAMIDS", "PYRENEES",
+    "PYRIFERA", "QUAGMIRE", "QUAILING", "QUAINTLY", "QUAKINGS", "QUANTITE", "QUARRELS", "QUARTERN",
+    "QUARTERS", "QUARTHER", "QUEASILY", "QUEEREST", "QUENCHED", "QUICKENS", "QUICKEST", "QUIETEST",
+    "QUIETUDE", "QUILLOTA", "QUINCHAO", "QUINTERO", "QUITTING", "QUIVERED", "QUIXOTIC", "QUIZZING",
+    "RACIALLY", "RACKINGS", "RADIALLY", "RADIANCE", "RADIATED", "RADICALS", "RADISHES", "RAGGEDLY",
+    "RAILINGS", "RAILLERY", "RAILROAD", "RAILWAYS", "RAKISHLY", "RALLYING", "RAMBLING", "RAMSGATE",
+    "RANCAGUA", "RANDOMLY", "RANKLING", "RANKNESS", "RAPACITY", "RAPIDITY", "RAPTURES", "RAREFIED",
+    "RASCALLY", "RASHNESS", "RATTLERS", "RATTLING", "RAVAGING", "RAVENOUS", "REACHING", "READIEST",
+    "READINGS", "READJUST", "REALISED", "REALIZED", "REALIZES", "REAPPEAR", "REASONED", "REASSUME",
+    "REASSURE", "REBELLED", "REBUFFED", "RECALLED", "RECEDING", "RECEIPTS", "RECEIVER", "RECEIVES",
+    "RECESSES", "RECITALS", "RECKLESS", "RECKONED", "RECLINED", "RECLINES", "RECOILED", "RECOPIED",
+    "RECORDED", "RECOUPED", "RECOURSE", "RECOVERS", "RECURRED", "RECUVVER", "REDDENED", "REDEEMED",
+    "REDEEMER", "REDOLENT", "REDOUBLE", "REDSHANK", "REDSTART", "REDUCING", "REDUVIUS", "REEDBUCK",
+    "REFERRED", "REFLECTS", "REFORMED", "REFORMER", "REFRAINS", "REFUGEES", "REFUNDED", "REFUSEST",
+    "REFUSING", "REGAINED", "REGALING", "REGARDED", "REGIMENT", "REGINALD", "REGULARS", "REGULATE",
+    "REHEARSE", "REIGNING", "REINDEER", "REJECTED", "REJOICED", "REJOICES", "REJOINED", "RELAPSED",
+    "RELAPSES", "RELATING", "RELAXING", "RELEASED", "RELEASES", "RELENTED", "RELIABLY", "RELIANCE",
+    "RELIEVED", "RELIEVES", "RELIQUES", "RELISHED", "REMAINED", "REMANDED", "REMARKED", "REMEDIES",
+    "REMINDED", "REMINDER", "REMNANTS", "REMOTELY", "REMOTEST", "REMOVALS", "REMOVING", "RENDERED",
+    "RENEGADE", "RENEWALS", "RENEWING", "RENOUNCE", "REPACKED", "REPAIRED", "REPARTEE", "REPASSED",
+    "REPAYING", "REPEALED", "REPEATER", "REPELLED", "REPENTED", "REPINING", "REPLACED", "REPLACES",
+    "REPLYING", "REPORTED", "REPOSING", "REPRIEVE", "REPROACH", "REPROOFS", "REPROVED", "REPTILES",
+    "REPULSED", "REQUESTS", "REQUIRES", "RESCUERS", "RESCUING", "RESEMBLE", "RESENTED", "RESERVES",
+    "RESFLESS", "RESIDING", "RESIGNED", "RESINOUS", "RESISTED", "RESOLUTE", "RESOLVED", "RESOLVES",
+    "RESONANT", "RESORTED", "RESPECTS", "RESPONDS", "RESSLICH", "RESTLESS", "RESTORED", "RESTORES",
+    "RESTRAIN", "RESULTED", "RESUMING", "RETAINED", "RETAINER", "RETARDED", "RETICENT", "RETICLES",
+    "RETICULE", "RETIRING", "RETORTED", "RETRACED", "RETRACES", "RETREATS", "RETRIEVE", "RETURNED",
+    "REVEALED", "REVELLED", "REVENGED", "REVENGES", "REVEREND", "REVERENT", "REVERSAL", "REVERSED",
+    "REVERSES", "REVERTED", "REVIEWAL", "REVILING", "REVIVING", "REVOLTED", "REVOLVED", "REVOLVER",
+    "REVOLVES", "REWARDED", "REWASHED", "RHAPSODY", "RICHMOND", "RICHNESS", "RIDDANCE", "RIDENDUS",
+    "RIDICULE", "RIGHTFUL", "RIGIDITY", "RIGOROUS", "RINGLETS", "RINGTAIL", "RIPENING", "RIPPLING",
+    "RITUALLY", "RIVALLED", "RIVETING", "RIVETTED", "RIVULETS", "ROADSIDE", "ROASTING", "ROBINSON",
+    "ROBUSTLY", "ROBUSTUS", "ROCKFISH", "ROCKLING", "RODENTIA", "ROLLINGS", "ROONAWAY", "ROOSTING",
+    "ROSETTES", "ROSEWOOD", "ROTATION", "ROTATORY", "ROUGHEST", "ROUGHING", "ROULETTE", "ROUNDEST",
+    "ROUNDING", "ROUSSEAU", "RUBECULA", "RUBICUND", "RUDENESS", "RUEFULLY", "RUFFIANS", "RUGGEDLY",
+    "RUMBLING", "RUMINANT", "RUMINATE", "RUMMAGED", "RUMMIEST", "RUMOURED", "RUMPLING", "RUNAWAYS",
+    "RUPTURED", "RUSSIANS", "RUSTLING", "RUTHLESS", "RYLSTONE", "SACREDLY", "SADDENED", "SADDLING",
+    "SAGACITY", "SAILFISH", "SALARIED", "SALESMAN", "SALITRAL", "SALUTARY", "SALUTING", "SALVADOR",
+    "SALVATOR", "SAMENESS", "SAMOVARS", "SANCTION", "SANCTITY", "SANDALED", "SANDFISH", "SANDWICH",
+    "SANGUINE", "SANTIAGO", "SAPPHIRE", "SARACENS", "SARANDIS", "SASIATED", "SATURDAY", "SAUCEPAN",
+    "SAUNTERS", "SAUSAGES", "SAUVAGES", "SAVAGELY", "SAVOURED", "SCAFFOLD", "SCALDING", "SCALESIA",
+    "SCALLOPS", "SCALPING", "SCANDALS", "SCANNING", "SCANTIER", "SCANTILY", "SCARCELY", "SCARCITY",
+    "SCEPTICS", "SCHEMING", "SCHILLER", "SCHIRDEL", "SCHOLARS", "SCHOOLED", "SCHOONER", "SCIENCES",
+    "SCIMITAR", "SCISSORS", "SCOFFERS", "SCOLDING", "SCOOPING", "SCORCHED", "SCORCHES", "SCORESBY",
+    "SCORNFUL", "SCORNING", "SCORPION", "SCOTCHED", "SCOTLAND", "SCOURGES", "SCOURING", "SCOWLING",
+    "SCRAMBLE", "SCRAPING", "SCRAWLED", "SCREAMED", "SCREAMER", "SCREENED", "SCREWING", "SCRIBBLE",
+    "SCRIPTER", "SCRUBBED", "SCRUPLES", "SCULLERY", "SCULPTOR", "SEACOAST", "SEAHORSE", "SEARCHED",
+    "SEARCHES", "SEASHORE", "SEASNAIL", "SEASONED", "SEAWARDS", "SECLUDED", "SECONDED", "SECONDLY",
+    "SECRETED", "SECRETES", "SECRETLY", "SECTIONS", "SECURELY", "SECURING", "SEDATELY", "SEDATIVE",
+    "SEDIMENT", "SEDULOUS", "SEGMENTS", "SELLINGS", "SENATORS", "SENSIBLE", "SENSIBLY", "SENSUOUS",
+    "SENTINEL", "SENTRIES", "SEPULTUS", "SERAPHIM", "SERENELY", "SERENITY", "SERIALLY", "SERIATIM",
+    "SERJEANT", "SERPENTS", "SERPULAE", "SERRATED", "SERVANTS", "SERVICES", "SERVITOR", "SESSIONS",
+    "SETTLERS", "SETTLING", "SEVERELY", "SEVEREST", "SEVERITY", "SEWERAGE", "SEWERELY", "SHABBILY",
+    "SHADOWED", "SHAKINGS", "SHALLOWS", "SHAMBLED", "SHAMBLES", "SHAMEFUL", "SHAMMING", "SHANTIES",
+    "SHARKING", "SHARPENS", "SHARPERS", "SHARPEST", "SHEARING", "SHEDDING", "SHEEPDOG", "SHEEPISH",
+    "SHELDUCK", "SHELTERS", "SHELVING", "SHEPHERD", "SHETLAND", "SHIELDED", "SHIFTILY", "SHIFTING",
+    "SHILLING", "SHIPPING", "SHIRKING", "SHIVERED", "SHOCKING", "SHODDILY", "SHOEBILL", "SHOELESS",
+    "SHOOTERS", "SHOREHAM", "SHORTENS", "SHORTEST", "SHOULDST", "SHOUTING", "SHOVELER", "SHOWERED",
+    "SHREWDLY", "SHRIEKED", "SHROUDED", "SHRUGGED", "SHRUNKEN", "SHUDDERS", "SHUFFLED", "SHUFFLES",
+    "SHUNNING", "SHUTTERS", "SHUTTING", "SIBERIAN", "SICKENED", "SICKNESS", "SIDELONG", "SIDEWALK",
+    "SIDEWAYS", "SIDEWISE", "SIGNALLY", "SILENCED", "SILENTLY", "SILKWORM", "SILLIEST", "SILLIMAN",
+    "SILURIAN", "SILVERED", "SILWORTH", "SIMMONDS", "SIMPERED", "SIMPLEST", "SINECURE", "SINFULLY",
+    "SINGEING", "SINGLING", "SINGULAR", "SINISTER", "SISTERLY", "SITTINGS", "SIXPENCE", "SIXPENNY",
+    "SKELETON", "SKETCHED", "SKETCHES", "SKILLFUL", "SKIMMING", "SKIMPOLE", "SKINNING", "SKIPPING",
+    "SKIRMISH", "SKITTISH", "SKITTLES", "SKULKING", "SKYLIGHT", "SLADDERY", "SLAMMONS", "SLANTING",
+    "SLAPPING", "SLASHING", "SLEEPERS", "SLEEPILY", "SLEEPING", "SLIGHTED", "SLIGHTER", "SLINKING",
+    "SLIPPERS", "SLIPPERY", "SLIPPING", "SLIPSHOD", "SLOPPILY", "SLOVENLY", "SLOWNESS", "SLUGGISH",
+    "SLUMBERS", "SLURRING", "SLYBOOTS", "SMACKING", "SMALLEST", "SMARTING", "SMASHING", "SMEARING",
+    "SMELLING", "SMELTING", "SMIFFELD", "SMIRCHED", "SMIRKING", "SMITHERS", "SMITHSON", "SMOKINGS",
+    "SMOOTHED", "SMOOTHER", "SMOOTHLY", "SMOTHERS", "SMUGGLER", "SNAPPING", "SNAPPISH", "SNARLING",
+    "SNATCHED", "SNATCHES", "SNEAKILY", "SNEAKING", "SNEERING", "SNEEZING", "SNIFFING", "SNIPPING",
+    "SNOBBISH", "SNORTING", "SNUBBING", "SNUFFERS", "SNUFFING", "SOBBINGS", "SOBERING", "SOBRIETY",
+    "SOCIABLE", "SOCIABLY", "SOCIALLY", "SOFTENED", "SOFTNESS", "SOIZABLE", "SOLACING", "SOLANDER",
+    "SOLDIERS", "SOLEMNLY", "SOLICITS", "SOLIDITY", "SOLITARY", "SOLITUDE", "SOLSTICE", "SOMBRERO",
+    "SOMETIME", "SONOROUS", "SOOBJACT", "SOOTHING", "SORDIDLY", "SORENESS", "SOUNDING", "SPACIOUS",
+    "SPALETRO", "SPANGLED", "SPANIARD", "SPANIELS", "SPANKING", "SPARKING", "SPARKLED", "SPARKLES",
+    "SPARRING", "SPARROWS", "SPARSELY", "SPEAKETH", "SPEARING", "SPECIMEN", "SPECIOUS", "SPECKLED",
+    "SPECTRAL", "SPEECHES", "SPEEDILY", "SPENDING", "SPHINXES", "SPILEING", "SPILLING", "SPINNERS",
+    "SPINNIES", "SPINNING", "SPINSTER", "SPIRALLY", "SPIRITED", "SPITEFUL", "SPITHEAD", "SPITTING",
+    "SPITTOON", "SPLASHED", "SPLASHES", "SPLASHIN", "SPLENDID", "SPLINTER", "SPLOICED", "SPLUTTER",
+    "SPOILING", "SPONSORS", "SPOONFUL", "SPORTIVE", "SPORULES", "SPOTLESS", "SPOUTING", "SPRAWLER",
+    "SPRINGER", "SPRINKLE", "SPROUTER", "SPRUCELY", "SPRUGEON", "SPURIOUS", "SQUABBLE", "SQUANDER",
+    "SQUARELY", "SQUARING", "SQUASHED", "SQUATTED", "SQUEAKED", "SQUEAKER", "SQUEALED", "SQUEEZED",
+    "SQUEEZES", "SQUIRESS", "SQUIRREL", "STABBING", "STABLING", "STAGGERS", "STAGNANT", "STALKING",
+    "STALLION", "STALWART", "STAMFORD", "STAMMERS", "STAMPING", "STARCHED", "STARFISH", "STARLING",
+    "STARTLED", "STARTLES", "STARVING", "STATIONS", "STATUARY", "STAYLACE", "STEADIED", "STEADIER",
+    "STEADILY", "STEALING", "STEALTHY", "STEAMERS", "STEAMING", "STEENBOK", "STEEPEST", "STEEPLED",
+    "STEEPLES", "STEMMING", "STEPPING", "STERILIT", "STERLING", "STERNEST", "STICKING", "STICKLER",
+    "STIFFEST", "STIFLING", "STILLEST", "STIMULUS", "STINGILY", "STINGING", "STINGRAY", "STINKBUG",
+    "STINKETH", "STINKING", "STIRRING", "STIRRUPS", "STITCHED", "STITCHES", "STOCKADE", "STOCKILY",
+    "STOCKING", "STOLBUNS", "STOLIDLY", "STOMACHS", "STOOPING", "STOPPAGE", "STOPPING", "STORMILY",
+    "STORMING", "STOUTEST", "STOUTISH", "STRAGGLE", "STRAIGHT", "STRAINED", "STRANDED", "STRANGER",
+    "STRANGLE", "STRAPPED", "STRAYING", "STREAKED", "STREAMED", "STREAMER", "STRICKEN", "STRICTER",
+    "STRICTLY", "STRIDING", "STRIPPED", "STRIVING", "STROKING", "STROLLED", "STRONGER", "STRONGLY",
+    "STRUTHIO", "STUBBORN", "STUDENTS", "STUDIOUS", "STUDYING", "STUFFILY", "STUFFING", "STUMBLED",
+    "STUMBLES", "STUPIDER", "STUPIDLY", "STURDILY", "STURGEON", "STYLIFER", "SUBDUING", "SUBGROUP",
+    "SUBJECTS", "SUBORNED", "SUBSIDED", "SUBSIDES", "SUBTLETY", "SUCCEEDS", "SUCCINCT", "SUCCINEA",
+    "SUCKLING", "SUDDENLY", "SUFFERED", "SUFFERER", "SUFFICED", "SUFFRAGE", "SUFFUSED", "SUGGESTS",
+    "SUICIDAL", "SUICIDES", "SUITABLY", "SULLENLY", "SULPHATE", "SUMMONED", "SUNBURNT", "SUNDRIES",
+    "SUNLIGHT", "SUNSHINE", "SUNSHINY", "SUPERBLY", "SUPINELY", "SUPPLIED", "SUPPLIES", "SUPPORTS",
+    "SUPPOSES", "SUPPRESS", "SURFACES", "SURFBIRD", "SURGEONS", "SURICATE", "SURMISED", "SURMISES",
+    "SURMOUNT", "SURNAMES", "SURROUND", "SURVEYED", "SURVEYOR", "SURVIVED", "SURVIVOR", "SUSPECTS",
+    "SUSPENSE", "SWAINSON", "SWALLOWS", "SWARMING", "SWEARING", "SWEEPERS", "SWEETEST", "SWELLING",
+    "SWIFTEST", "SWIFTLET", "SWINDLED", "SWINDLER", "SWINGING", "SWIRLING", "SWITCHES", "SWOONING",
+    "SYENITIC", "SYLLABLE", "SYMMETRY", "SYMPTOMS", "TACITURN", "TAHITIAN", "TAINTING", "TALENTED",
+    "TALISMAN", "TAMANDUA", "TAMENESS", "TAMPERED", "TANGIBLE", "TANGIBLY", "TANTRUMS", "TAPACOLO",
+    "TAPERING", "TASMANIA", "TASTEFUL", "TATTERED", "TATTOOED", "TAUNTING", "TAXATION", "TEACHERS",
+    "TEARDROP", "TEARLESS", "TEASPOON", "TEKENIKA", "TEMPERED", "TEMPORAL", "TEMPTING", "TENACITY",
+    "TENANTED", "TENANTRY", "TENDENCY", "TENDERED", "TENDERER", "TENDERLY", "TENEMENT", "TENNYSON",
+    "TENSIONS", "TERRACES", "TERRAPIN", "TERRIBLY", "TERRIERS", "TERRIFIC", "TERTIARY", "TESTACEA",
+    "TESTATOR", "THANKFUL", "THANKING", "THATCHED", "THEATRES", "THEAYTER", "THEESELF", "THEODORA",
+    "THEODORE", "THEORIES", "THEORIZE", "THICKEST", "THICKETS", "THINNESS", "THINNING", "THISTLES",
+    "THOUGHTS", "THOUSING", "THRALDOM", "THRASHED", "THRASHER", "THREADED", "THREATEN", "THRESHED",
+    "THRILLED", "THRIVING", "THROBBED", "THRONGED", "THROWING", "THRUSHES", "THUMPING", "THUNDERY",
+    "THURSDAY", "THWARTED", "TICKETED", "TICKINGS", "TICKLING", "TICKLISH", "TIGERISH", "TILEFISH",
+    "TIMBERRY", "TIMELESS", "TIMIDITY", "TIMOROUS", "TINGLING", "TINKLING", "TIPSLARK", "TIRELESS",
+    "TIRESOME", "TITMOUSE", "TITTERED", "TOADFISH", "TOASTING", "TOGEZZER", "TOILETTE", "TOILSOME",
+    "TOLERANT", "TOLERATE", "TOMAHAWK", "TOMATOES", "TOOTHFUL", "TOPPLING", "TORMENTS", "TORPIDLY",
+    "TORRENTS", "TORTOISE", "TORTUOUS", "TORTURED", "TORTURER", "TORTURES", "TOSSINGS", "TOTTERED",
+    "TOUCHILY", "TOURISTS", "TOWERING", "TOWNSHIP", "TOWNSMAN", "TOWNSMEN", "TRADUCER", "TRAGICAL",
+    "TRAGOPAN", "TRAILING", "TRAITENT", "TRAITORS", "TRAMMELS", "TRAMPING", "TRAMPLED", "TRANQUIL",
+    "TRANSACT", "TRAVERSE", "TREACLED", "TREADING", "TREASURE", "TREATING", "TREATISE", "TREEFROG",
+    "TREMBLED", "TREMBLER", "TREMBLES", "TRENCHED", "TRENCHES", "TRESPASS", "TRICKERY", "TRICKLED",
+    "TRICKLES", "TRIFLING", "TRILLING", "TRIMMERS", "TRIMMING", "TRINKETS", "TRIPPING", "TRISTRAM",
+    "TRIUMPHS", "TROLLING", "TROLLOPE", "TROPHIES", "TROTTING", "TROUBLED", "TROUBLES", "TROUSERS",
+    "TRUDGING", "TRUMPERY", "TRUMPETS", "TRUNCATE", "TRUSTEES", "TRUSTFUL", "TRUSTING", "TRUTHFUL",
+    "TUCKERED", "TUCUTUCO", "TUMBLERS", "TUMBLING", "TUMBRILS", "TURGENEV", "TURGIDLY", "TURMOILS",
+    "TURNBULL", "TURNINGS", "TURNPIKE", "TURRETED", "TUSSOCKS", "TWEAKING", "TWENTIES", "TWILIGHT",
+    "TWINKLED", "TWIRLING", "TWISTING", "TWITCHED", "TWOPENCE", "TWOPENNY", "TYLERISH", "TYRANNUS",
+    "TYROLESE", "UGLINESS", "ULTERIOR", "UNABATED", "UNAWARES", "UNBIASED", "UNBIDDEN", "UNBOLTED",
+    "UNBROKEN", "UNBURIED", "UNCALLED", "UNCANDID", "UNCOMMON", "UNCORKED", "UNDERLIP", "UNDIMMED",
+    "UNEASILY", "UNERRING", "UNEVENLY", "UNFAIRLY", "UNFILLED", "UNFITTED", "UNFOLDED", "UNFORMED",
+    "UNFURLED", "UNGAINLY", "UNGENIAL", "UNHEALED", "UNHEEDED", "UNHINGED", "UNHORSED", "UNIFORMS",
+    "UNIQUELY", "UNJOYOUS", "UNJUSTLY", "UNKINDLY", "UNLAWFUL", "UNLEARNT", "UNLOADED", "UNLOCKED",
+    "UNLOOKED", "UNLOOSED", "UNLOVELY", "UNLOVING", "UNMANNED", "UNMARKED", "UNMASKED", "UNNERVED",
+    "UNOPENED", "UNPACKED", "UNPLACED", "UNPOLITE", "UNREASON", "UNROOFED", "UNSADDLE", "UNSCREWS",
+    "UNSEEMLY", "UNSETTLE", "UNSHAKEN", "UNSHAVED", "UNSHAVEN", "UNSOLVED", "UNSPOILT", "UNSPOKEN",
+    "UNSTABLE", "UNSTEADY", "UNSTRUNG", "UNSUITED", "UNTASTED", "UNTENDED", "UNTIDILY", "UNTIMELY",
+    "UNTIRING", "UNTOWARD", "UNTURNED", "UNVARIED", "UNWARILY", "UNWASHED", "UNWIELDY", "UNWISELY",
+    "UNWONTED", "UNWORTHY", "UPHEAVAL", "UPHEAVED", "UPLIFTED", "UPRAISED", "UPRISING", "UPSTAIRS",
+    "UPSTARTS", "UPTURNED", "UPWARDLY", "URBANELY", "URBANITY", "URGENTLY", "USEFULLY", "USHERING",
+    "UTENSILS", "UTILIZED", "UTTERING", "VACANTLY", "VACATION", "VAGABOND", "VAGARIES", "VAGRANTS",
+    "VALDIVIA", "VALIDITY", "VANELLUS", "VANIKORO", "VANISHED", "VANISHES", "VANITIES", "VAPIDITY",
+    "VAPOROUS", "VARIABLY", "VARIANCE", "VASTNESS", "VAUGHANS", "VAULTING", "VAUXHALL", "VEGETABL",
+    "VEGETATE", "VEHEMENT", "VEHICLES", "VELOCITY", "VENETIAN", "VENGEFUL", "VENOMOUS", "VENTURED",
+    "VENTURES", "VERACITY", "VERANDAH", "VERBALLY", "VERBENAS", "VERIFIED", "VERSIONS", "VESICLES",
+    "VESTIGES", "VESTIGIA", "VESUVIUS", "VEXATION", "VHOLESES", "VIBRATED", "VIBRATES", "VICARAGE",
+    "VICENCIO", "VICEROYS", "VICINITY", "VICTORIA", "VICTUALS", "VIGILANT", "VIGOROUS", "VILENESS",
+    "VILIFIED", "VILLAGES", "VILLAINS", "VILLAINY", "VILLOSUS", "VIOLATED", "VIOLATES", "VIRTUOUS",
+    "VISITING", "VISITORS", "VISUALLY", "VITALITY", "VITIATED", "VIVACITY", "VIXENISH", "VIZCACHA",
+    "VOCALIST", "VOCATION", "VOLCANIC", "VOLCANOS", "VOLUMNIA", "VOTARIES", "VOYAGERS", "VULGARLY",
+    "VULTURES", "WACKFORD", "WADDLING", "WAGGONER", "WAILINGS", "WAINSCOT", "WAITRESS", "WAKENING",
+    "WALLAROO", "WALTZING", "WANDERED", "WANDERER", "WANTONLY", "WARBLING", "WARDROBE", "WARHORSE",
+    "WARMOUTH", "WARNINGS", "WARRANTS", "WARRIORS", "WASHINGS", "WASTEFUL", "WATCHERS", "WATCHFUL",
+    "WATCHING", "WATCHMAN", "WATCHMEN", "WATERING", "WATERLOO", "WAVERING", "WAYFARER", "WEAKENED",
+    "WEARYING", "WEATHERS", "WEAZENED", "WEDDINGS", "WEIGHING", "WELCOMED", "WELCOMER", "WELCOMES",
+    "WEREWOLF", "WESTERLY", "WESTWARD", "WESTWOOD", "WHARTONS", "WHEATEAR", "WHEEDLED", "WHEEDLIN",
+    "WHEELING", "WHEEZILY", "WHEEZING", "WHIMBREL", "WHIMPERS", "WHIPCORD", "WHIPPING", "WHIPSTER",
+    "WHIRLING", "WHISKERS", "WHISKING", "WHISPERS", "WHISTLED", "WHISTLER", "WHISTLES", "WHITEFLY",
+    "WHITENED", "WHITFORD", "WHOOPING", "WICKEDLY", "WIGMAKER", "WILDFOWL", "WILDNESS", "WILFULLY",
+    "WILLIAMS", "WINDINGS", "WINDLASS", "WINDMILL", "WINDOWED", "WINDPIPE", "WINDWARD", "WINGLESS",
+    "WINNINGS", "WISITING", "WITCHERY", "WITHDREW", "WITHERED", "WITHHELD", "WITHHOLD", "WOEFULLY",
+    "WOLFFISH", "WOMANISH", "WONDERED", "WONDROUS", "WOODBINE", "WOODCOCK", "WOODENLY", "WOODWORK",
+    "WOOLSACK", "WOOLWICH", "WORKABLE", "WORKADAY", "WORKINGS", "WORKROOM", "WORMWOOD", "WORRITED",
+    "WORRYING", "WORSHIPS", "WORSTEDS", "WORTHIER", "WORTHIES", "WORTHILY", "WOULDEST", "WOUNDING",
+    "WRAPPERS", "WRAPPING", "WRATHFUL", "WREAKING", "WREATHED", "WREATHES", "WRENCHED", "WRESTING",
+    "WRETCHED", "WRETCHES", "WRINGING", "WRINKLED", "WRINKLES", "WRITHING", "WRITINGS", "WRONGING",
+    "WYELANDS", "YACHTING", "YATAGHAN", "YEARNING", "YELLOWED", "YELLOWER", "YIELDING", "YOUNGEST",
+    "YOUTHFUL", "ZARAISKY", "ZOOPHYTE", "ZORILLOS", "ZOSSIMOV",
+];
+
+pub static LETTERS: usize = 5; // letters of the Wordle words
+pub static MIN_LETTERS: usize = 4;
+pub static MAX_LETTERS: usize = 8;
+
+// Answers for a word length in the language played: for English the
+// Wordle words for five letters, a list of common words for the other lengths
+pub fn play_words(letters: usize) -> &'static [&'static str] {
+    language().answers(letters)
+}
+
+// Words accepted as guesses besides the answers, for English a dictionary
+// of each length
+pub fn guess_words(letters: usize) -> &'static [&'static str] {
+    language().guesses(letters)
+}
+
+// Accepted guesses, letters with a fold match the letter they fold to
+pub fn is_valid(word: &str) -> bool {
+    language().accepts(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_words_are_accepted() {
+        let common = [
+            "BIRD FISH TREE DOOR MILK RAIN SNOW BOOK HAND FROG CAKE LAMP",
+            "APPLE HOUSE WATER CHAIR TABLE BREAD HEART PIANO TIGER CLOUD",
+            "GARDEN WINDOW PENCIL ORANGE BUTTER FLOWER SILVER RABBIT CASTLE BRIDGE",
+            "EMERALD KITCHEN BLANKET CHICKEN DIAMOND FREEDOM CABINET DOLPHIN LIBRARY WEATHER",
+            "ALPHABET SANDWICH ELEPHANT MOUNTAIN HOSPITAL UMBRELLA BIRTHDAY DINOSAUR SQUIRREL CUCUMBER",
+        ];
+        for (letters, words) in (MIN_LETTERS..=MAX_LETTERS).zip(common) {
+            for word in words.split(' ') {
+                assert_eq!(word.len(), letters);
+                assert!(is_valid(word), "{} is rejected", word);
+            }
+        }
+    }
+
+    #[test]
+    fn made_up_words_are_rejected() {
+        for word in ["ABATEDLY", "ABUSEDLY", "ZIPPPIER", "CGRAI"] {
+            assert!(!is_valid(word), "{} is accepted", word);
+        }
+    }
+}